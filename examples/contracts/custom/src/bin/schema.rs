@@ -1,12 +1,3 @@
-use sylvia::cw_schema::write_api;
-
-use custom::contract::sv::{ContractExecMsg, ContractQueryMsg, InstantiateMsg};
-
 fn main() {
-    write_api! {
-        crate_name: sylvia::cw_schema,
-        instantiate: InstantiateMsg,
-        execute: ContractExecMsg,
-        query: ContractQueryMsg,
-    }
+    sylvia::schema::write_api(custom::contract::sv::generate_schema());
 }
@@ -1,12 +1,5 @@
-use cw20_base::contract::sv::{ContractExecMsg, ContractQueryMsg, InstantiateMsg};
-use sylvia::cw_schema::write_api;
 use sylvia::cw_std::Empty;
 
 fn main() {
-    write_api! {
-        crate_name: sylvia::cw_schema,
-        instantiate: InstantiateMsg,
-        execute: ContractExecMsg<Empty, Empty>,
-        query: ContractQueryMsg<Empty, Empty>,
-    }
+    sylvia::schema::write_api(cw20_base::contract::sv::generate_schema::<Empty, Empty>());
 }
@@ -81,7 +81,7 @@ pub struct Cw20Base<E, Q> {
 #[sv::error(ContractError)]
 #[sv::messages(cw20_allowances as Allowances)]
 #[sv::messages(cw20_marketing as Marketing)]
-#[sv::messages(cw20_minting as Minting)]
+#[sv::messages(cw20_minting as Minting: version = "^0.5")]
 #[sv::custom(msg=E, query=Q)]
 impl<E, Q> Cw20Base<E, Q>
 where
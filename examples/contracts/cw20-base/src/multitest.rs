@@ -2,5 +2,4 @@ mod allowances_tests;
 mod base_tests;
 mod marketing_tests;
 mod minting_tests;
-mod receiver;
 mod receiver_contract;
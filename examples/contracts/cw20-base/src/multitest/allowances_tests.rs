@@ -4,13 +4,14 @@ use cw20_allowances::responses::{
 };
 use cw_utils::Expiration;
 use sylvia::cw_multi_test::{next_block, IntoBech32};
-use sylvia::cw_std::{Binary, StdError, Timestamp, Uint128};
+use sylvia::cw_std::{to_json_binary, StdError, Timestamp, Uint128};
 use sylvia::multitest::App;
 
 use crate::contract::sv::mt::CodeId;
 use crate::contract::InstantiateMsgData;
 use crate::error::ContractError;
 use crate::multitest::receiver_contract::sv::mt::CodeId as ReceiverCodeId;
+use crate::multitest::receiver_contract::sv::ReceiveMsg;
 use crate::responses::Cw20Coin;
 
 use crate::contract::sv::mt::Cw20BaseProxy;
@@ -508,7 +509,7 @@ fn send_from_respects_limits() {
     let owner = "owner".into_bech32();
     let owner2 = "owner2".into_bech32();
     let spender = "spender".into_bech32();
-    let send_msg = Binary::from(r#"{"some":123}"#.as_bytes());
+    let send_msg = to_json_binary(&ReceiveMsg::deposit()).unwrap();
     let start_amount = Uint128::new(999999);
 
     let code_id = CodeId::store_code(&app);
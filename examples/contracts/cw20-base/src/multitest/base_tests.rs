@@ -1,13 +1,14 @@
 use cw20_allowances::responses::{AllAllowancesResponse, SpenderAllowanceInfo};
 use cw_utils::Expiration;
 use sylvia::cw_multi_test::IntoBech32;
-use sylvia::cw_std::{Binary, StdError, Uint128};
+use sylvia::cw_std::{to_json_binary, StdError, Uint128};
 use sylvia::multitest::App;
 
 use crate::contract::sv::mt::{CodeId, Cw20BaseProxy};
 use crate::contract::InstantiateMsgData;
 use crate::error::ContractError;
 use crate::multitest::receiver_contract::sv::mt::CodeId as ReceiverCodeId;
+use crate::multitest::receiver_contract::sv::ReceiveMsg;
 use crate::responses::{BalanceResponse, Cw20Coin, TokenInfoResponse};
 use cw20_allowances::sv::mt::Cw20AllowancesProxy;
 
@@ -313,7 +314,7 @@ fn send() {
     let amount = Uint128::from(12340000u128);
     let too_much = Uint128::from(12340321u128);
     let transfer = Uint128::from(76543u128);
-    let send_msg = Binary::from(r#"{"some":123}"#.as_bytes());
+    let send_msg = to_json_binary(&ReceiveMsg::deposit()).unwrap();
 
     let code_id = CodeId::store_code(&app);
 
@@ -1,18 +1,22 @@
 use sylvia::contract;
-use sylvia::ctx::InstantiateCtx;
+use sylvia::ctx::{Cw20ReceiveCtx, InstantiateCtx};
 use sylvia::cw_std::{Response, StdResult};
 
-use super::receiver;
 pub struct ReceiverContract {}
 
 #[contract]
-#[sv::messages(receiver as Receiver)]
 impl ReceiverContract {
     pub const fn new() -> Self {
         Self {}
     }
+
     #[sv::msg(instantiate)]
     pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
         Ok(Response::new())
     }
+
+    #[sv::msg(exec, cw20_receive)]
+    pub fn deposit(&self, _ctx: Cw20ReceiveCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
 }
@@ -1,18 +1,24 @@
 use communication::api::Api;
 use communication::enum_msg::EnumMessage;
 use communication::executor::Executor;
+use communication::ibc::Ibc;
 use communication::instantiate_builder::InstantiateBuilder;
 use communication::querier::Querier;
 use communication::reply::Reply;
+use communication::schema::Schema;
 use communication::struct_msg::StructMessage;
 use communication::wrapper_msg::GlueMessage;
 use mt::MtHelpers;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{GenericParam, ItemImpl};
+use syn::{GenericParam, ItemImpl, Type};
 
+use crate::crate_module;
+use crate::parser::attributes::access::validate_access_attrs;
+use crate::parser::attributes::extract::validate_extract_attrs;
 use crate::parser::attributes::features::SylviaFeatures;
 use crate::parser::attributes::msg::MsgType;
+use crate::parser::attributes::pausable::validate_pausable_attrs;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{
     assert_new_method_defined, ContractErrorAttr, Custom, OverrideEntryPoint,
@@ -21,7 +27,7 @@ use crate::parser::{
 use crate::types::interfaces::Interfaces;
 use crate::types::msg_variant::MsgVariants;
 
-mod communication;
+pub mod communication;
 mod mt;
 
 /// Preprocessed `contract` macro input for struct impl block.
@@ -33,6 +39,7 @@ mod mt;
 ///         - QueryMsg
 ///         - SudoMsg
 ///         - MigrateMsg
+///         - ReceiveMsg wrapped in the cw20 `Receive` message
 ///         - ContractExecMsg
 ///         - ContractQueryMsg
 ///         - ContractSudoMsg
@@ -40,6 +47,10 @@ mod mt;
 ///     - [Querier](https://cosmwasm-docs.vercel.app/cw-multi-test) trait implementation
 ///     - [Executor](https://cosmwasm-docs.vercel.app/cw-multi-test) trait implementation
 ///     - Api trait implementation
+///     - IBC dispatch functions for `ibc_*` handlers
+///     - `generate_schema` function describing the contract API
+///     - `events` function listing the types of the events emitted by the contract
+///     - `Introspection` interface implementation if the `introspection` feature is enabled
 pub struct ContractInput<'a> {
     item: &'a ItemImpl,
     generics: Vec<&'a GenericParam>,
@@ -47,12 +58,16 @@ pub struct ContractInput<'a> {
     custom: Custom,
     override_entry_points: Vec<OverrideEntryPoint>,
     interfaces: Interfaces,
-    _sv_features: SylviaFeatures,
+    sv_features: SylviaFeatures,
+    events: Vec<Type>,
 }
 
 impl<'a> ContractInput<'a> {
     pub fn new(item: &'a ItemImpl) -> Self {
         assert_new_method_defined(item);
+        validate_access_attrs(item.as_variants(), false);
+        validate_extract_attrs(item.as_variants(), false);
+        validate_pausable_attrs(item.as_variants(), false);
 
         let generics = item.generics.params.iter().collect();
         let parsed_attrs = ParsedSylviaAttributes::new(item.attrs.iter());
@@ -61,6 +76,11 @@ impl<'a> ContractInput<'a> {
         let sv_features = parsed_attrs.sv_features;
         let override_entry_points = parsed_attrs.override_entry_point_attrs;
         let interfaces = Interfaces::new(item);
+        let events = parsed_attrs
+            .events_attrs
+            .into_iter()
+            .flat_map(|attr| attr.events)
+            .collect();
 
         Self {
             item,
@@ -69,7 +89,8 @@ impl<'a> ContractInput<'a> {
             custom,
             override_entry_points,
             interfaces,
-            _sv_features: sv_features,
+            sv_features,
+            events,
         }
     }
 
@@ -87,7 +108,12 @@ impl<'a> ContractInput<'a> {
         let querier = self.emit_querier();
         let executor = self.emit_executor();
         let reply = self.emit_reply();
+        let ibc = Ibc::new(item, generics, &self.interfaces).emit();
         let instantiate_builder = self.emit_instantiate_builder_trait();
+        let schema = Schema::new(item, generics, &self.interfaces, &self.events).emit();
+        let events = self.emit_events();
+        let introspection = self.emit_introspection();
+        let version_checks = self.interfaces.emit_version_checks();
 
         quote! {
             pub mod sv {
@@ -103,19 +129,69 @@ impl<'a> ContractInput<'a> {
 
                 #reply
 
+                #ibc
+
                 #contract_api
 
                 #instantiate_builder
+
+                #schema
+
+                #events
+
+                #introspection
+
+                #(#version_checks)*
+            }
+        }
+    }
+
+    /// Implements the `sylvia::introspection::Introspection` interface reporting every
+    /// interface listed in the `sv::messages` attributes and the `Introspection` itself.
+    fn emit_introspection(&self) -> TokenStream {
+        if !self.sv_features.introspection {
+            return quote! {};
+        }
+
+        let sylvia = crate_module();
+        let contract = &self.item.self_ty;
+        let error = &self.error.error;
+        let (impl_generics, _, where_clause) = self.item.generics.split_for_impl();
+        let interface_infos = self.interfaces.emit_interface_infos();
+
+        quote! {
+            impl #impl_generics #sylvia ::introspection::Introspection for #contract #where_clause {
+                type Error = #error ;
+
+                fn interfaces(&self) -> Vec< #sylvia ::introspection::InterfaceInfo> {
+                    vec![ #(#interface_infos),* ]
+                }
+            }
+        }
+    }
+
+    /// Emits the `events` function returning the types of the events listed
+    /// in the `sv::events` attributes.
+    fn emit_events(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let events = &self.events;
+        let count = events.len();
+
+        quote! {
+            /// Returns the types of the events emitted by the contract.
+            pub const fn events() -> [&'static str; #count] {
+                [ #(< #events as #sylvia ::events::TypedEvent>::EVENT_TYPE),* ]
             }
         }
     }
 
     fn emit_messages(&self) -> TokenStream {
         let instantiate = self.emit_struct_msg(MsgType::Instantiate);
-        let migrate = self.emit_struct_msg(MsgType::Migrate);
+        let migrate = self.emit_migrate_msg();
         let exec_impl = self.emit_enum_msg(MsgType::Exec);
         let query_impl = self.emit_enum_msg(MsgType::Query);
         let sudo_impl = self.emit_enum_msg(MsgType::Sudo);
+        let receive_impl = self.emit_receive_msg();
         let exec = self.emit_glue_msg(MsgType::Exec);
         let query = self.emit_glue_msg(MsgType::Query);
         let sudo = self.emit_glue_msg(MsgType::Sudo);
@@ -129,6 +205,8 @@ impl<'a> ContractInput<'a> {
 
             #sudo_impl
 
+            #receive_impl
+
             #migrate
 
             #exec
@@ -144,6 +222,38 @@ impl<'a> ContractInput<'a> {
             .map_or(quote! {}, |msg| msg.emit())
     }
 
+    /// Emits `MigrateMsg` as a struct for a single migrate handler or as an enum
+    /// with a variant per migration path if the contract defines more than one.
+    fn emit_migrate_msg(&self) -> TokenStream {
+        let variants = MsgVariants::new(
+            self.item.as_variants(),
+            MsgType::Migrate,
+            &self.generics,
+            &self.item.generics.where_clause,
+        );
+
+        match variants.variants().count() > 1 {
+            true => self.emit_enum_msg(MsgType::Migrate),
+            false => self.emit_struct_msg(MsgType::Migrate),
+        }
+    }
+
+    /// Emits `ReceiveMsg` wrapped in the cw20 `Receive` message
+    /// if the contract defines any `cw20_receive` handler.
+    fn emit_receive_msg(&self) -> TokenStream {
+        let variants = MsgVariants::new(
+            self.item.as_variants(),
+            MsgType::Cw20Receive,
+            &self.generics,
+            &self.item.generics.where_clause,
+        );
+
+        match variants.variants().count() {
+            0 => quote! {},
+            _ => self.emit_enum_msg(MsgType::Cw20Receive),
+        }
+    }
+
     fn emit_enum_msg(&self, msg_ty: MsgType) -> TokenStream {
         EnumMessage::new(self.item, msg_ty, &self.generics, &self.error, &self.custom).emit()
     }
@@ -191,7 +301,7 @@ impl<'a> ContractInput<'a> {
     fn emit_reply(&self) -> TokenStream {
         let variants = MsgVariants::new(self.item.as_variants(), MsgType::Reply, &[], &None);
 
-        Reply::new(self.item, &self.generics, &variants).emit()
+        Reply::new(self.item, &self.generics, &variants, &self.interfaces).emit()
     }
 
     fn emit_instantiate_builder_trait(&self) -> TokenStream {
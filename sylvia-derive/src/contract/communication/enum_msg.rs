@@ -1,13 +1,15 @@
 use crate::crate_module;
+use crate::parser::attributes::middleware::{emit_middleware_dispatch, is_hooked, MiddlewareAttr};
 use crate::parser::attributes::MsgAttrForwarding;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{ContractErrorAttr, Custom, MsgType, ParsedSylviaAttributes};
 use crate::types::msg_variant::MsgVariants;
 use crate::utils::emit_bracketed_generics;
 use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{GenericParam, Ident, ItemImpl, Type, WhereClause};
+use syn::{GenericParam, Ident, ItemImpl, LitStr, Type, WhereClause};
 
 /// Representation of single enum message
 pub struct EnumMessage<'a> {
@@ -18,6 +20,11 @@ pub struct EnumMessage<'a> {
     custom: &'a Custom,
     where_clause: &'a Option<WhereClause>,
     msg_attrs_to_forward: Vec<MsgAttrForwarding>,
+    /// Marks if the `ExecMsg` wraps the cw20 `Receive` message.
+    cw20_receive: bool,
+    /// Middlewares wrapping the dispatch of the `MigrateMsg`.
+    /// Dispatch of the `ExecMsg` and `SudoMsg` is wrapped in the glue message.
+    middlewares: Vec<MiddlewareAttr>,
 }
 
 impl<'a> EnumMessage<'a> {
@@ -29,13 +36,42 @@ impl<'a> EnumMessage<'a> {
         custom: &'a Custom,
     ) -> Self {
         let where_clause = &source.generics.where_clause;
-        let variants = MsgVariants::new(source.as_variants(), msg_ty, generics, where_clause);
-        let msg_attrs_to_forward = ParsedSylviaAttributes::new(source.attrs.iter())
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let default_funds = match msg_ty {
+            MsgType::Exec => parsed_attrs.sv_features.default_exec_funds(),
+            _ => None,
+        };
+        let variants = MsgVariants::new(source.as_variants(), msg_ty, generics, where_clause)
+            .with_default_funds(default_funds);
+        if msg_ty == MsgType::Migrate {
+            validate_migrate_variants(&variants, parsed_attrs.sv_features.cw2);
+        }
+
+        let cw20_receive = msg_ty == MsgType::Exec
+            && MsgVariants::<GenericParam>::new(
+                source.as_variants(),
+                MsgType::Cw20Receive,
+                &[],
+                &None,
+            )
+            .variants()
+            .next()
+            .is_some();
+        if cw20_receive {
+            validate_receive_collision(&variants);
+        }
+
+        let msg_attrs_to_forward = parsed_attrs
             .msg_attrs_forward
             .into_iter()
             .filter(|attr| attr.msg_type == msg_ty)
             .collect();
 
+        let middlewares = match msg_ty {
+            MsgType::Migrate => parsed_attrs.middleware_attrs,
+            _ => vec![],
+        };
+
         Self {
             variants,
             msg_ty,
@@ -44,6 +80,8 @@ impl<'a> EnumMessage<'a> {
             custom,
             where_clause,
             msg_attrs_to_forward,
+            cw20_receive,
+            middlewares,
         }
     }
 
@@ -58,17 +96,22 @@ impl<'a> EnumMessage<'a> {
             custom,
             where_clause,
             msg_attrs_to_forward,
-            ..
+            cw20_receive,
+            middlewares,
         } = self;
 
         let enum_name = msg_ty.emit_msg_name();
         let match_arms = variants.emit_dispatch_legs();
+        let msg_name_arms = variants.emit_msg_name_arms();
         let unused_generics = variants.unused_generics();
         let bracketed_unused_generics = emit_bracketed_generics(unused_generics);
         let used_generics = variants.used_generics();
         let bracketed_used_generics = emit_bracketed_generics(used_generics);
 
         let mut variant_names = variants.as_names_snake_cased();
+        if *cw20_receive {
+            variant_names.push("receive".to_owned());
+        }
         variant_names.sort();
         let variants_cnt = variant_names.len();
         let variants_constructors = variants.emit_constructors();
@@ -89,6 +132,44 @@ impl<'a> EnumMessage<'a> {
         };
         let msg_attrs_to_forward = msg_attrs_to_forward.iter().map(|attr| &attr.attrs);
 
+        let dispatch_signature = match is_hooked(middlewares, *msg_ty) {
+            true => quote! { fn dispatch_without_middleware },
+            false => quote! { pub fn dispatch },
+        };
+        let middleware_dispatch = match is_hooked(middlewares, *msg_ty) {
+            true => {
+                let middleware_dispatch =
+                    emit_middleware_dispatch(middlewares, *msg_ty, contract, custom);
+                quote! {
+                    pub fn dispatch #bracketed_unused_generics (self, contract: &#contract, ctx: #ctx_type) -> #ret_type #where_clause {
+                        #middleware_dispatch
+                    }
+                }
+            }
+            false => quote! {},
+        };
+
+        let (receive_variant, receive_match_arm, receive_constructor, receive_name_arm) =
+            match cw20_receive {
+                true => (
+                    quote! { Receive(#sylvia ::types::Cw20ReceiveMsg), },
+                    quote! {
+                        Receive(#sylvia ::types::Cw20ReceiveMsg { sender, amount, msg }) => {
+                            let (deps, env, info) = ctx;
+                            let sender = deps.api.addr_validate(&sender)?;
+                            ReceiveMsg::dispatch(#sylvia ::cw_std::from_json(&msg)?, contract, (deps, env, info, sender, amount))
+                        },
+                    },
+                    quote! {
+                        pub fn receive(sender: String, amount: #sylvia ::cw_std::Uint128, msg: #sylvia ::cw_std::Binary) -> Self {
+                            Self::Receive(#sylvia ::types::Cw20ReceiveMsg { sender, amount, msg })
+                        }
+                    },
+                    quote! { Receive(_) => "receive", },
+                ),
+                false => (quote! {}, quote! {}, quote! {}, quote! {}),
+            };
+
         quote! {
             #[allow(clippy::derive_partial_eq_without_eq)]
             #derive_call
@@ -96,20 +177,36 @@ impl<'a> EnumMessage<'a> {
             #[serde(rename_all="snake_case")]
             pub enum #enum_name #bracketed_used_generics {
                 #(#variants,)*
+                #receive_variant
                 #phantom_variant
             }
 
             impl #bracketed_used_generics #enum_name #bracketed_used_generics {
-                pub fn dispatch #bracketed_unused_generics (self, contract: &#contract, ctx: #ctx_type) -> #ret_type #where_clause {
+                #middleware_dispatch
+
+                #dispatch_signature #bracketed_unused_generics (self, contract: &#contract, ctx: #ctx_type) -> #ret_type #where_clause {
                     use #enum_name::*;
 
                     match self {
                         #(#match_arms,)*
+                        #receive_match_arm
                         #phantom_match_arm
                     }
                 }
 
+                /// Name of the message as it appears in the JSON.
+                pub fn msg_name(&self) -> &'static str {
+                    use #enum_name::*;
+
+                    match *self {
+                        #(#msg_name_arms,)*
+                        #receive_name_arm
+                    }
+                }
+
                 #(#variants_constructors)*
+
+                #receive_constructor
             }
 
             pub const fn #messages_fn_name () -> [&'static str; #variants_cnt] {
@@ -118,3 +215,126 @@ impl<'a> EnumMessage<'a> {
         }
     }
 }
+
+/// Verifies that every migration path specifies the version it migrates from,
+/// that the contract tracks its version with `cw2` and that no two migration paths
+/// accept the same version.
+fn validate_migrate_variants(variants: &MsgVariants<GenericParam>, cw2: bool) {
+    let mut ranges: Vec<(&Ident, &LitStr, VersionRange)> = vec![];
+    variants.variants().for_each(|variant| {
+        let function_name = variant.function_name();
+        match variant.msg_attr().migrate_from() {
+            Some(migrate_from) if !cw2 => {
+                emit_error!(migrate_from.span(), "The `from` argument requires the `cw2` feature.";
+                    note = "Add `#[sv::features(cw2)]` attribute to the contract."
+                );
+            }
+            Some(migrate_from) => {
+                let Ok(req) = semver::VersionReq::parse(&migrate_from.value()) else {
+                    return;
+                };
+                let range = VersionRange::new(&req);
+                if let Some((other, other_from, _)) = ranges
+                    .iter()
+                    .find(|(_, _, other)| other.overlaps(&range))
+                {
+                    emit_error!(migrate_from.span(), "Overlapping `from` requirements.";
+                        note = "The `{}` handler migrates from `{}`, which overlaps with `{}` of the `{}` handler.", function_name, migrate_from.value(), other_from.value(), other;
+                        note = "Every stored version has to match the `from` requirement of at most one migrate handler."
+                    );
+                }
+                ranges.push((function_name, migrate_from, range));
+            }
+            None => {
+                emit_error!(function_name.span(), "Missing `from` argument.";
+                    note = "Every migrate handler has to specify the version it migrates from if the contract defines more than one migrate handler.";
+                    note = "Use `#[sv::msg(migrate, from = \">=1.0, <2.0\")]`."
+                );
+            }
+        }
+    });
+}
+
+/// Half-open range `[lower, upper)` of versions matching a semver requirement.
+/// `None` upper bound stands for no upper limit. Pre-release tags are ignored.
+struct VersionRange {
+    lower: (u64, u64, u64),
+    upper: Option<(u64, u64, u64)>,
+}
+
+impl VersionRange {
+    fn new(req: &semver::VersionReq) -> Self {
+        req.comparators.iter().map(Self::from_comparator).fold(
+            Self {
+                lower: (0, 0, 0),
+                upper: None,
+            },
+            |acc, range| Self {
+                lower: acc.lower.max(range.lower),
+                upper: match (acc.upper, range.upper) {
+                    (Some(lhs), Some(rhs)) => Some(lhs.min(rhs)),
+                    (lhs, rhs) => lhs.or(rhs),
+                },
+            },
+        )
+    }
+
+    fn from_comparator(comparator: &semver::Comparator) -> Self {
+        use semver::Op;
+
+        let semver::Comparator {
+            op,
+            major,
+            minor,
+            patch,
+            ..
+        } = comparator;
+        let major = *major;
+        let start = (major, minor.unwrap_or(0), patch.unwrap_or(0));
+        // First version past the ones matching the comparator with the missing parts as wildcards.
+        let next = match (minor, patch) {
+            (Some(minor), Some(patch)) => (major, *minor, patch + 1),
+            (Some(minor), None) => (major, minor + 1, 0),
+            (None, _) => (major + 1, 0, 0),
+        };
+        let (lower, upper) = match op {
+            Op::Greater => (next, None),
+            Op::GreaterEq => (start, None),
+            Op::Less => ((0, 0, 0), Some(start)),
+            Op::LessEq => ((0, 0, 0), Some(next)),
+            Op::Tilde => match minor {
+                Some(minor) => (start, Some((major, minor + 1, 0))),
+                None => (start, Some((major + 1, 0, 0))),
+            },
+            Op::Caret => match (major, minor, patch) {
+                (0, Some(0), Some(patch)) => (start, Some((0, 0, patch + 1))),
+                (0, Some(minor), _) => (start, Some((0, minor + 1, 0))),
+                _ => (start, Some((major + 1, 0, 0))),
+            },
+            _ => (start, Some(next)),
+        };
+        Self { lower, upper }
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        let lower = self.lower.max(other.lower);
+        [self.upper, other.upper]
+            .into_iter()
+            .flatten()
+            .all(|upper| lower < upper)
+    }
+}
+
+/// Verifies that the `ExecMsg::Receive` variant wrapping the cw20 message
+/// doesn't collide with the `receive` exec method.
+fn validate_receive_collision(variants: &MsgVariants<GenericParam>) {
+    if let Some(variant) = variants
+        .variants()
+        .find(|variant| variant.function_name() == "receive")
+    {
+        emit_error!(variant.function_name().span(), "The `receive` exec message collides with the cw20 `Receive` message.";
+            note = "The `ExecMsg::Receive` variant is generated for the `#[sv::msg(exec, cw20_receive)]` methods.";
+            note = "Rename the method or handle it with `#[sv::msg(exec, cw20_receive)]`."
+        );
+    }
+}
@@ -90,7 +90,7 @@ impl EmitExecutorMethod for MsgVariant<'_> {
         let sylvia = crate_module();
 
         let parameters = fields.iter().map(MsgField::emit_method_field_folded);
-        let fields_names = fields.iter().map(MsgField::name);
+        let fields_values = fields.iter().map(MsgField::emit_into_field);
         let variant_name = name.to_case(Case::Snake);
 
         quote! {
@@ -98,7 +98,7 @@ impl EmitExecutorMethod for MsgVariant<'_> {
                 Ok(#sylvia ::types::ExecutorBuilder::<#sylvia ::types::ReadyExecutorBuilderState>::new(
                     self.contract().to_owned(),
                     self.funds().to_owned(),
-                    #sylvia ::cw_std::to_json_binary( & #api_path :: #variant_name (#(#fields_names),*) )?,
+                    #sylvia ::cw_std::to_json_binary( & #api_path :: #variant_name (#(#fields_values),*) )?,
                 ))
             }
         }
@@ -0,0 +1,170 @@
+//! Module responsible for generating IBC related code.
+//!
+//! Based on methods marked with the `#[sv::msg(ibc_*)]` attributes and interfaces marked with
+//! `#[sv::messages(interface: ibc)]`, this module generates `dispatch_ibc_*` functions
+//! forwarding the IBC entry point call to the handler.
+//!
+//! Interfaces are tried first in the order of the `sv::messages` attributes. The contract handler
+//! is called if none of the interfaces handles the message. Interface handlers accepting every
+//! message of the type, i.e. all the channel handlers and the packet handlers without the `packet`
+//! type, can't be followed by other handlers of the same type. It is verified at compile time.
+
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::{format_ident, quote};
+use syn::{parse_quote, GenericParam, ItemImpl, Type};
+
+use crate::crate_module;
+use crate::parser::variant_descs::AsVariantDescs;
+use crate::parser::{MsgType, ParsedSylviaAttributes};
+use crate::types::interfaces::Interfaces;
+use crate::types::msg_variant::{MsgVariant, MsgVariants};
+
+/// Number of parameters, apart from the context, expected in the IBC handler.
+const NUMBER_OF_ALLOWED_IBC_FIELDS: usize = 1;
+
+/// Emits `dispatch_ibc_*` function for every IBC message type handled by the contract
+/// or its interfaces.
+pub struct Ibc<'a> {
+    source: &'a ItemImpl,
+    generics: &'a [&'a GenericParam],
+    error: Type,
+    interfaces: &'a Interfaces,
+}
+
+impl<'a> Ibc<'a> {
+    pub fn new(
+        source: &'a ItemImpl,
+        generics: &'a [&'a GenericParam],
+        interfaces: &'a Interfaces,
+    ) -> Self {
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let error = parsed_attrs.error_attrs.unwrap_or_default().error;
+
+        Self {
+            source,
+            generics,
+            error,
+            interfaces,
+        }
+    }
+
+    pub fn emit(&self) -> TokenStream {
+        let dispatchers = MsgType::IBC.into_iter().map(|msg_ty| {
+            let variants = MsgVariants::new(
+                self.source.as_variants(),
+                msg_ty,
+                self.generics,
+                &self.source.generics.where_clause,
+            );
+            let mut variants = variants.variants();
+            let variant = variants.next();
+
+            if let Some(variant) = variant {
+                if !cfg!(feature = "stargate") {
+                    emit_error!(variant.name().span(), "IBC message handlers require the `stargate` feature.";
+                        note = "Enable the `stargate` feature of the `sylvia` crate."
+                    );
+                }
+
+                variants.for_each(|duplicate| {
+                    emit_error!(duplicate.name().span(), "More than one IBC handler of the same type.";
+                        note = variant.name().span() => format!("Previous `{}` handler defined here.", msg_ty.emit_ep_name())
+                    );
+                });
+            }
+
+            if variant.is_none() && !self.interfaces.has_ibc() {
+                return quote! {};
+            }
+
+            let shadowing_assertions = self
+                .interfaces
+                .emit_ibc_shadowing_assertions(&msg_ty, variant.is_some());
+            let dispatch = self.emit_dispatch(msg_ty, variant);
+
+            quote! {
+                #(#shadowing_assertions)*
+
+                #dispatch
+            }
+        });
+        let declared_assertions = self.interfaces.emit_ibc_declared_assertions();
+
+        quote! {
+            #(#declared_assertions)*
+
+            #(#dispatchers)*
+        }
+    }
+
+    /// Generates `dispatch_ibc_*` function routing the IBC message to the interfaces
+    /// and the contract handler.
+    fn emit_dispatch(&self, msg_ty: MsgType, variant: Option<&MsgVariant>) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            source,
+            generics,
+            error,
+            interfaces,
+        } = self;
+
+        let contract = &source.self_ty;
+        let where_clause = &source.generics.where_clause;
+
+        let custom_query = parse_quote!( < #contract as #sylvia ::types::ContractApi>::CustomQuery);
+        let custom_msg = parse_quote!( < #contract as #sylvia ::types::ContractApi>::CustomMsg);
+        let ctx_params = msg_ty.emit_ctx_params(&custom_query);
+        let ctx_values = msg_ty.emit_ctx_values();
+        let ret_type = msg_ty.emit_result_type(&custom_msg, error);
+        let msg_name = msg_ty.emit_msg_name();
+        let ep_name = msg_ty.emit_ep_name();
+        let dispatch_name = format_ident!("dispatch_{}", ep_name);
+
+        let interfaces_dispatch = interfaces.emit_ibc_dispatch_attempts(&msg_ty);
+
+        let unsupported = quote! {
+            Err(#sylvia ::cw_std::StdError::generic_err("Unsupported IBC message received.")).map_err(Into::into)
+        };
+
+        let contract_dispatch = match variant {
+            Some(variant) => {
+                if variant.fields().len() != NUMBER_OF_ALLOWED_IBC_FIELDS {
+                    emit_error!(variant.name().span(), "Invalid number of IBC handler parameters.";
+                        note = format!("Expected exactly one parameter of type `{}` after the context.", msg_name)
+                    );
+                }
+
+                let function_name = variant.function_name();
+                let call = quote! {
+                    contract. #function_name (Into::into(( #ctx_values )), msg).map_err(Into::into)
+                };
+
+                match variant.msg_attr().packet() {
+                    Some(packet) => {
+                        let check = msg_ty.emit_ibc_packet_check(Some(packet));
+                        quote! {
+                            if #check {
+                                return #call;
+                            }
+                            #unsupported
+                        }
+                    }
+                    None => call,
+                }
+            }
+            None => quote! {
+                let _ = (contract, deps, env);
+                #unsupported
+            },
+        };
+
+        quote! {
+            pub fn #dispatch_name < #(#generics),* >( #ctx_params , msg: #sylvia ::cw_std:: #msg_name, contract: #contract ) -> #ret_type #where_clause {
+                #(#interfaces_dispatch)*
+
+                #contract_dispatch
+            }
+        }
+    }
+}
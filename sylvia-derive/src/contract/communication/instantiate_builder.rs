@@ -5,6 +5,7 @@ use syn::{GenericParam, Type, WhereClause};
 
 use crate::crate_module;
 use crate::parser::MsgType;
+use crate::types::msg_field::MsgField;
 use crate::types::msg_variant::MsgVariant;
 use crate::utils::{get_ident_from_type, SvCasing};
 
@@ -47,8 +48,9 @@ impl<'a> InstantiateBuilder<'a> {
             contract_name.span(),
         );
         let method_name = contract_name.to_case(Case::Snake);
-        let fields_names = instantiate_variant.as_fields_names();
-        let parameters = instantiate_variant.emit_method_field();
+        let fields = instantiate_variant.fields();
+        let fields_values = fields.iter().map(MsgField::emit_into_field);
+        let parameters: Vec<_> = fields.iter().map(MsgField::emit_helper_field).collect();
         let msg_name = msg_type.emit_msg_name();
 
         quote! {
@@ -58,7 +60,7 @@ impl<'a> InstantiateBuilder<'a> {
 
             impl #trait_name for #sylvia ::builder::instantiate::InstantiateBuilder {
                 fn #method_name < #(#used_generics),* > (code_id: u64, #(#parameters,)* ) -> #sylvia ::cw_std::StdResult< #sylvia ::builder::instantiate::InstantiateBuilder> #where_clause {
-                    let msg = #msg_name ::< #(#used_generics),* > ::new( #(#fields_names),* );
+                    let msg = #msg_name ::< #(#used_generics),* > ::new( #(#fields_values),* );
                     let msg = #sylvia ::cw_std::to_json_binary(&msg)?;
                     Ok( #sylvia ::builder::instantiate::InstantiateBuilder::new(msg, code_id))
                 }
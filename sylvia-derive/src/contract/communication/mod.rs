@@ -1,8 +1,10 @@
 pub mod api;
 pub mod enum_msg;
 pub mod executor;
+pub mod ibc;
 pub mod instantiate_builder;
 pub mod querier;
 pub mod reply;
+pub mod schema;
 pub mod struct_msg;
 pub mod wrapper_msg;
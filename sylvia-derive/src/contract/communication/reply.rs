@@ -1,22 +1,28 @@
 //! Module responsible for generating `Reply` related code.
 //!
 //! Based on methods marked with the `#[sv::msg(reply)]` attribute, this module generates:
-//!     - reply ids for every unique handler,
+//!     - reply ids for every unique handler, either explicitly assigned with the `id` parameter,
+//!       or derived from the position of the handler or, with the `hashed_reply_ids` feature,
+//!       from the handler name,
+//!     - `REPLY_IDS` table mapping handler names to their reply ids,
 //!     - dispatch method that matches over every generated `ReplyId` and dispatches depending on the `ReplyOn`,
-//!     - `SubMsgMethods` trait with method for every reply id.
+//!     - `SubMsgMethods` trait with method for every reply id,
+//!     - `replies` module with the `ReplyHandler` type for every reply id.
 
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use proc_macro_error::emit_error;
-use quote::quote;
-use syn::{parse_quote, GenericParam, Ident, ItemImpl, Type};
+use quote::{quote, ToTokens};
+use syn::{parse_quote, GenericParam, Generics, Ident, ItemImpl, Type};
 
 use crate::crate_module;
 use crate::parser::attributes::msg::ReplyOn;
+use crate::parser::check_generics::GetPath;
 use crate::parser::{MsgType, ParsedSylviaAttributes};
+use crate::types::interfaces::Interfaces;
 use crate::types::msg_field::MsgField;
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
-use crate::utils::emit_turbofish;
+use crate::utils::{emit_turbofish, fnv1a_hash, SvCasing};
 
 const NUMBER_OF_ALLOWED_DATA_FIELDS: usize = 1;
 const NUMBER_OF_ALLOWED_RAW_PAYLOAD_FIELDS: usize = 1;
@@ -54,6 +60,7 @@ pub struct Reply<'a> {
     generics: &'a [&'a GenericParam],
     reply_data: Vec<ReplyData<'a>>,
     error: Type,
+    interfaces: &'a Interfaces,
 }
 
 impl<'a> Reply<'a> {
@@ -61,9 +68,14 @@ impl<'a> Reply<'a> {
         source: &'a ItemImpl,
         generics: &'a [&'a GenericParam],
         variants: &'a MsgVariants<'a, GenericParam>,
+        interfaces: &'a Interfaces,
     ) -> Self {
-        let reply_data = variants.as_reply_data();
         let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let reply_ids = match parsed_attrs.sv_features.hashed_reply_ids {
+            true => ReplyIds::Hashed,
+            false => ReplyIds::Positional,
+        };
+        let reply_data = variants.as_reply_data(reply_ids);
         let error = parsed_attrs.error_attrs.unwrap_or_default().error;
 
         Self {
@@ -71,6 +83,7 @@ impl<'a> Reply<'a> {
             generics,
             reply_data,
             error,
+            interfaces,
         }
     }
 
@@ -78,6 +91,7 @@ impl<'a> Reply<'a> {
         let unique_handlers: Vec<_> = self.emit_reply_ids().collect();
         let dispatch = self.emit_dispatch();
         let sub_msg_trait = self.emit_sub_msg_trait();
+        let reply_handlers = self.emit_reply_handlers();
 
         quote! {
             #(#unique_handlers)*
@@ -85,11 +99,16 @@ impl<'a> Reply<'a> {
             #dispatch
 
             #sub_msg_trait
+
+            #reply_handlers
         }
     }
 
     /// Generates dispatch method that matches over every generated `ReplyId`
     /// and dispatches depending on the [`ReplyOn`](crate::parser::attributes::msg::ReplyOn).
+    ///
+    /// Replies with ids namespaced by the interfaces marked with the `reply` parameter
+    /// are forwarded to the respective interface.
     pub fn emit_dispatch(&self) -> TokenStream {
         let sylvia = crate_module();
         let Self {
@@ -97,7 +116,7 @@ impl<'a> Reply<'a> {
             generics,
             reply_data,
             error,
-            ..
+            interfaces,
         } = self;
 
         let msg_ty = MsgType::Reply;
@@ -109,12 +128,20 @@ impl<'a> Reply<'a> {
         let ctx_params = msg_ty.emit_ctx_params(&custom_query);
         let ret_type = msg_ty.emit_result_type(&custom_msg, error);
 
+        let contract_turbofish = emit_turbofish(contract, generics);
+        let receiver = quote! { #contract_turbofish ::new() };
         let match_arms = reply_data
             .iter()
-            .map(|data| data.emit_match_arms(contract, generics));
+            .map(|data| data.emit_match_arms(&receiver));
+        let interfaces_dispatch = interfaces.emit_reply_dispatch_attempts();
+        let namespace_assertions = interfaces.emit_reply_namespace_assertions();
 
         quote! {
+            #(#namespace_assertions)*
+
             pub fn dispatch_reply < #(#generics),* >( #ctx_params , msg: #sylvia ::cw_std::Reply, contract: #contract ) -> #ret_type #where_clause {
+                #(#interfaces_dispatch)*
+
                 let #sylvia ::cw_std::Reply {
                     id,
                     payload,
@@ -137,57 +164,140 @@ impl<'a> Reply<'a> {
     /// [`reply_handler`](crate::parser::attributes::msg::MsgAttr::reply_handlers) and
     /// [`function_name`](crate::types::msg_variant::MsgVariant::function_name) not anotated with
     /// the `#[sv::msg(..)]` attribute with the `handlers` parameter.
+    ///
+    /// Explicit ids have to fit in 32 bits, as the upper bits are reserved for
+    /// the `REPLY_NAMESPACE` of the interfaces.
     fn emit_reply_ids(&'a self) -> impl Iterator<Item = TokenStream> + 'a {
-        self.reply_data.iter().enumerate().map(|(id, data)| {
-            let id = id as u64;
+        check_unique_reply_ids(&self.reply_data);
+
+        let reply_ids = self.reply_data.iter().map(|data| {
+            let id = data.id();
+            if id > u32::MAX as u64 {
+                emit_error!(data.handler_id.span(), "Reply id out of range.";
+                    note = format!("Reply ids of the contract have to fit in 32 bits, the upper bits are reserved for the interface reply namespaces. Found `{}`.", id)
+                );
+            }
             let reply_id = &data.reply_id;
             quote! {
                 pub const #reply_id : u64 = #id ;
             }
-        })
+        });
+
+        reply_ids.chain(std::iter::once(emit_reply_ids_table(&self.reply_data)))
     }
 
     /// Generates `SubMsgMethods` trait with method for every reply id.
     fn emit_sub_msg_trait(&self) -> TokenStream {
-        let Self { reply_data, .. } = self;
+        emit_sub_msg_trait(&self.reply_data)
+    }
 
+    /// Generates `replies` module with the `ReplyHandler` type for every reply id.
+    /// Types are generic over the contract generics and send the contract's custom message.
+    fn emit_reply_handlers(&self) -> TokenStream {
         let sylvia = crate_module();
+        let contract = &self.source.self_ty;
+        let custom_msg = parse_quote!( < #contract as #sylvia ::types::ContractApi>::CustomMsg);
+
+        emit_reply_handlers(&self.reply_data, &self.source.generics, &custom_msg)
+    }
+}
 
-        let methods_declaration = reply_data.iter().map(ReplyData::emit_submsg_trait_method);
-        let submsg_reply_setters = reply_data.iter().map(ReplyData::emit_submsg_setter);
-        let submsg_converters: Vec<_> = reply_data
+/// Emits error for every reply id used by more than one handler.
+pub fn check_unique_reply_ids(reply_data: &[ReplyData]) {
+    reply_data.iter().enumerate().for_each(|(index, data)| {
+        reply_data[..index]
             .iter()
-            .map(ReplyData::emit_submsg_converter)
-            .collect();
+            .filter(|previous| previous.id() == data.id())
+            .for_each(|previous| {
+                emit_error!(data.handler_id.span(), "Duplicated reply id.";
+                    note = previous.handler_id.span() => format!("Reply id `{}` is already used by the `{}` handler.", data.id(), previous.handler_id)
+                )
+            })
+    });
+}
 
-        quote! {
-            pub trait SubMsgMethods<CustomMsgT> {
-                #(#methods_declaration)*
-            }
+/// Generates `REPLY_IDS` table mapping every handler name to its reply id.
+pub fn emit_reply_ids_table(reply_data: &[ReplyData]) -> TokenStream {
+    let entries = reply_data.iter().map(|data| {
+        let handler_name = data.handler_id.to_string();
+        let reply_id = &data.reply_id;
+        quote! { ( #handler_name , #reply_id ) }
+    });
 
-            impl<CustomMsgT> SubMsgMethods<CustomMsgT> for #sylvia ::cw_std::SubMsg<CustomMsgT> {
-                #(#submsg_reply_setters)*
-            }
+    quote! {
+        pub const REPLY_IDS: &[(&str, u64)] = &[ #(#entries),* ];
+    }
+}
 
-            impl<CustomMsgT> SubMsgMethods<CustomMsgT> for #sylvia ::cw_std::WasmMsg {
-                #(#submsg_converters)*
-            }
+/// Generates `SubMsgMethods` trait with method for every reply id.
+pub fn emit_sub_msg_trait(reply_data: &[ReplyData]) -> TokenStream {
+    let sylvia = crate_module();
 
-            impl<CustomMsgT> SubMsgMethods<CustomMsgT> for #sylvia ::cw_std::CosmosMsg<CustomMsgT> {
-                #(#submsg_converters)*
-            }
+    let methods_declaration = reply_data.iter().map(ReplyData::emit_submsg_trait_method);
+    let submsg_reply_setters = reply_data.iter().map(ReplyData::emit_submsg_setter);
+    let submsg_converters: Vec<_> = reply_data
+        .iter()
+        .map(ReplyData::emit_submsg_converter)
+        .collect();
+
+    quote! {
+        pub trait SubMsgMethods<CustomMsgT> {
+            #(#methods_declaration)*
+        }
+
+        impl<CustomMsgT> SubMsgMethods<CustomMsgT> for #sylvia ::cw_std::SubMsg<CustomMsgT> {
+            #(#submsg_reply_setters)*
+        }
+
+        impl<CustomMsgT> SubMsgMethods<CustomMsgT> for #sylvia ::cw_std::WasmMsg {
+            #(#submsg_converters)*
+        }
+
+        impl<CustomMsgT> SubMsgMethods<CustomMsgT> for #sylvia ::cw_std::CosmosMsg<CustomMsgT> {
+            #(#submsg_converters)*
         }
     }
 }
 
-trait ReplyVariants<'a> {
+/// Generates `replies` module with the `ReplyHandler` type for every reply id.
+pub fn emit_reply_handlers(
+    reply_data: &[ReplyData],
+    generics: &Generics,
+    custom_msg: &Type,
+) -> TokenStream {
+    let reply_handlers = reply_data
+        .iter()
+        .map(|data| data.emit_reply_handler(generics, custom_msg));
+
+    quote! {
+        pub mod replies {
+            use super::*;
+
+            #(#reply_handlers)*
+        }
+    }
+}
+
+/// Way of assigning the reply ids to the handlers without the explicit `id`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReplyIds {
+    /// Position of the handler in the contract, i.e. `0..n`.
+    Positional,
+    /// Hash of the handler name. Doesn't change when the handlers are reordered or added.
+    Hashed,
+}
+
+pub trait ReplyVariants<'a> {
     /// Maps to [Vec<ReplyData>].
     /// Validation for duplicates and overlaps should be done in this method.
-    fn as_reply_data(&self) -> Vec<ReplyData>;
+    fn as_reply_data(&self, reply_ids: ReplyIds) -> Vec<ReplyData<'_>>;
 }
 
-impl<'a> ReplyVariants<'a> for MsgVariants<'a, GenericParam> {
-    fn as_reply_data(&self) -> Vec<ReplyData> {
+impl<'a, Generic> ReplyVariants<'a> for MsgVariants<'a, Generic>
+where
+    Generic: GetPath + PartialEq + ToTokens,
+{
+    fn as_reply_data(&self, reply_ids: ReplyIds) -> Vec<ReplyData<'_>> {
         let mut reply_data: Vec<ReplyData> = vec![];
 
         self.variants()
@@ -216,7 +326,14 @@ impl<'a> ReplyVariants<'a> for MsgVariants<'a, GenericParam> {
                         )
                     }
                     Some(existing_data) => existing_data.merge(handler),
-                    None => reply_data.push(ReplyData::new(reply_id, handler,  handler_id)),
+                    None => {
+                        let position = reply_data.len() as u64;
+                        let default_id = match reply_ids {
+                            ReplyIds::Positional => position,
+                            ReplyIds::Hashed => fnv1a_hash(&handler_id.to_string()) as u64,
+                        };
+                        reply_data.push(ReplyData::new(reply_id, handler, handler_id, default_id))
+                    }
                 }
             });
 
@@ -225,11 +342,15 @@ impl<'a> ReplyVariants<'a> for MsgVariants<'a, GenericParam> {
 }
 
 /// Maps single reply id with its handlers.
-struct ReplyData<'a> {
+pub struct ReplyData<'a> {
     /// Unique identifier for the reply.
     pub reply_id: Ident,
     /// Unique name of the handler from which the [reply_id](ReplyData::reply_id) was constructed.
     pub handler_id: &'a Ident,
+    /// Reply id assigned with the `id` parameter of the `#[sv::msg(reply)]` attribute.
+    pub explicit_id: Option<u64>,
+    /// Reply id used if the [explicit_id](ReplyData::explicit_id) is not set.
+    pub default_id: u64,
     /// Methods handling the reply id for the associated reply on.
     pub handlers: Vec<(&'a Ident, ReplyOn)>,
     /// Data parameter associated with the handlers.
@@ -239,7 +360,12 @@ struct ReplyData<'a> {
 }
 
 impl<'a> ReplyData<'a> {
-    pub fn new(reply_id: Ident, variant: &'a MsgVariant<'a>, handler_id: &'a Ident) -> Self {
+    pub fn new(
+        reply_id: Ident,
+        variant: &'a MsgVariant<'a>,
+        handler_id: &'a Ident,
+        default_id: u64,
+    ) -> Self {
         let data = variant.as_data_field();
         variant.validate_fields_attributes();
         let payload = variant.fields().iter();
@@ -264,6 +390,8 @@ impl<'a> ReplyData<'a> {
         Self {
             reply_id,
             handler_id,
+            explicit_id: variant.msg_attr().reply_id(),
+            default_id,
             handlers: vec![(method_name, reply_on)],
             data,
             payload,
@@ -277,7 +405,12 @@ impl<'a> ReplyData<'a> {
             _ => return,
         };
 
-        let new_reply_data = ReplyData::new(self.reply_id.clone(), new_handler, self.handler_id);
+        let new_reply_data = ReplyData::new(
+            self.reply_id.clone(),
+            new_handler,
+            self.handler_id,
+            self.default_id,
+        );
 
         if self.payload.len() != new_reply_data.payload.len() {
             emit_error!(current_method_name.span(), "Mismatched quantity of method parameters.";
@@ -299,17 +432,33 @@ impl<'a> ReplyData<'a> {
             }
         });
 
+        match (self.explicit_id, new_reply_data.explicit_id) {
+            (Some(current_id), Some(new_id)) if current_id != new_id => {
+                emit_error!(new_handler.function_name().span(), "Mismatched reply ids.";
+                    note = format!("Handlers of the `{}` reply have to share the same id. Found `{}` and `{}`.", self.handler_id, current_id, new_id)
+                )
+            }
+            (None, new_id) => self.explicit_id = new_id,
+            _ => {}
+        }
+
         let new_function_name = new_handler.function_name();
         let new_reply_on = new_handler.msg_attr().reply_on();
         self.handlers.push((new_function_name, new_reply_on));
     }
 
+    /// Value of the reply id.
+    /// Explicitly assigned id if present, the [default_id](ReplyData::default_id) otherwise.
+    pub fn id(&self) -> u64 {
+        self.explicit_id.unwrap_or(self.default_id)
+    }
+
     /// Emits success and error match arms for a single `ReplyId`.
-    fn emit_match_arms(&self, contract: &Type, generics: &[&GenericParam]) -> TokenStream {
+    /// The `receiver` is the expression on which the handler methods are called.
+    pub fn emit_match_arms(&self, receiver: &TokenStream) -> TokenStream {
         let reply_id = &self.reply_id;
-        let contract_turbofish = emit_turbofish(contract, generics);
-        let success_match_arm = self.emit_success_match_arm(&contract_turbofish);
-        let error_match_arm = self.emit_error_match_arm(&contract_turbofish);
+        let success_match_arm = self.emit_success_match_arm(receiver);
+        let error_match_arm = self.emit_error_match_arm(receiver);
 
         quote! {
             #reply_id => {
@@ -360,10 +509,12 @@ impl<'a> ReplyData<'a> {
         let method_name = handler_id;
         let reply_on = self.emit_cw_reply_on();
         let payload_parameters = payload.iter().map(|field| field.emit_method_field());
+        let payload_collection = payload.emit_payload_collection();
         let payload_serialization = payload.emit_payload_serialization();
 
         quote! {
             fn #method_name (self, #(#payload_parameters),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::SubMsg<CustomMsgT>> {
+                #payload_collection
                 #payload_serialization
 
                 Ok( #sylvia ::cw_std::SubMsg {
@@ -389,10 +540,12 @@ impl<'a> ReplyData<'a> {
         let method_name = handler_id;
         let reply_on = self.emit_cw_reply_on();
         let payload_parameters = payload.iter().map(|field| field.emit_method_field());
+        let payload_collection = payload.emit_payload_collection();
         let payload_serialization = payload.emit_payload_serialization();
 
         quote! {
             fn #method_name (self, #(#payload_parameters),* ) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::SubMsg<CustomMsgT>> {
+                #payload_collection
                 #payload_serialization
 
                 Ok( #sylvia ::cw_std::SubMsg {
@@ -406,6 +559,60 @@ impl<'a> ReplyData<'a> {
         }
     }
 
+    /// Emits type implementing `sylvia::types::ReplyHandler` for the reply id.
+    /// The `generics` are not used by the handler itself, so they are stored in the `PhantomData`.
+    fn emit_reply_handler(&self, generics: &Generics, custom_msg: &Type) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            reply_id,
+            handler_id,
+            payload,
+            ..
+        } = self;
+
+        let name = handler_id.to_case(Case::UpperCamel);
+        let reply_on = self.emit_cw_reply_on();
+        let payload_type = payload.emit_payload_type();
+        let payload_serialization = payload.emit_payload_serialization();
+
+        let phantom_types = generics.params.iter().filter_map(|param| match param {
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                Some(quote! { #ident })
+            }
+            GenericParam::Lifetime(lt) => {
+                let lifetime = &lt.lifetime;
+                Some(quote! { & #lifetime () })
+            }
+            GenericParam::Const(_) => None,
+        });
+        let handler_type = match generics.params.is_empty() {
+            true => quote! { pub struct #name ; },
+            false => quote! {
+                pub struct #name #generics (std::marker::PhantomData<( #(#phantom_types,)* )>);
+            },
+        };
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            #handler_type
+
+            impl #impl_generics #sylvia ::types::ReplyHandler for #name #ty_generics #where_clause {
+                const REPLY_ID: u64 = #reply_id ;
+                const REPLY_ON: #sylvia ::cw_std::ReplyOn = #reply_on ;
+
+                type CustomMsg = #custom_msg ;
+                type Payload = #payload_type ;
+
+                fn payload(payload: Self::Payload) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::Binary> {
+                    #payload_serialization
+
+                    Ok(payload)
+                }
+            }
+        }
+    }
+
     fn emit_submsg_trait_method(&self) -> TokenStream {
         let sylvia = crate_module();
         let method_name = &self.handler_id;
@@ -419,7 +626,7 @@ impl<'a> ReplyData<'a> {
     /// Emits match arm for [ReplyOn::Success].
     /// In case neither [ReplyOn::Success] nor [ReplyOn::Always] is present, `Response::events`
     /// and `Response::data` are forwarded in the `Response`
-    fn emit_success_match_arm(&self, contract_turbofish: &Type) -> TokenStream {
+    fn emit_success_match_arm(&self, receiver: &TokenStream) -> TokenStream {
         let sylvia = crate_module();
 
         match self
@@ -440,7 +647,7 @@ impl<'a> ReplyData<'a> {
                         #payload_deserialization
                         #data_deserialization
 
-                        #contract_turbofish ::new(). #method_name ((deps, env, gas_used, events, msg_responses).into(), #data #(#payload_values),* )
+                        #receiver . #method_name ((deps, env, gas_used, events, msg_responses).into(), #data #(#payload_values),* )
                     }
                 }
             }
@@ -452,7 +659,7 @@ impl<'a> ReplyData<'a> {
                     #sylvia ::cw_std::SubMsgResult::Ok(_) => {
                         #payload_deserialization
 
-                        #contract_turbofish ::new(). #method_name ((deps, env, gas_used, vec![], vec![]).into(), result, #(#payload_values),* )
+                        #receiver . #method_name ((deps, env, gas_used, vec![], vec![]).into(), result, #(#payload_values),* )
                     }
                 }
             }
@@ -474,7 +681,7 @@ impl<'a> ReplyData<'a> {
     /// Emits match arm for [ReplyOn::Error].
     /// In case neither [ReplyOn::Error] nor [ReplyOn::Always] is present,
     /// the error is forwarded.
-    fn emit_error_match_arm(&self, contract_turbofish: &Type) -> TokenStream {
+    fn emit_error_match_arm(&self, receiver: &TokenStream) -> TokenStream {
         let sylvia = crate_module();
 
         match self
@@ -490,7 +697,7 @@ impl<'a> ReplyData<'a> {
                     #sylvia ::cw_std::SubMsgResult::Err(error) => {
                         #payload_deserialization
 
-                        #contract_turbofish ::new(). #method_name ((deps, env, gas_used, vec![], vec![]).into(), error, #(#payload_values),* )
+                        #receiver . #method_name ((deps, env, gas_used, vec![], vec![]).into(), error, #(#payload_values),* )
                     }
                 }
             }
@@ -502,7 +709,7 @@ impl<'a> ReplyData<'a> {
                     #sylvia ::cw_std::SubMsgResult::Err(_) => {
                         #payload_deserialization
 
-                        #contract_turbofish ::new(). #method_name ((deps, env, gas_used, vec![], vec![]).into(), result, #(#payload_values),* )
+                        #receiver . #method_name ((deps, env, gas_used, vec![], vec![]).into(), result, #(#payload_values),* )
                     }
                 }
             }
@@ -674,6 +881,8 @@ impl DataField for MsgField<'_> {
 pub trait PayloadFields {
     fn emit_payload_deserialization(&self) -> TokenStream;
     fn emit_payload_serialization(&self) -> TokenStream;
+    fn emit_payload_collection(&self) -> TokenStream;
+    fn emit_payload_type(&self) -> TokenStream;
     fn is_payload_marked(&self) -> bool;
 }
 
@@ -689,27 +898,47 @@ impl PayloadFields for Vec<&MsgField<'_>> {
         }
 
         let deserialized_payload_names = self.iter().map(|field| field.name());
+        let payload_type = self.emit_payload_type();
         quote! {
-            let ( #(#deserialized_payload_names),* ) = #sylvia ::cw_std::from_json(&payload)?;
+            let ( #(#deserialized_payload_names),* ): #payload_type = #sylvia ::cw_std::from_json(&payload)?;
         }
     }
 
+    /// Serializes the `payload` of the [payload type](PayloadFields::emit_payload_type).
     fn emit_payload_serialization(&self) -> TokenStream {
         let sylvia = crate_module();
         if self.is_payload_marked() {
-            // Safe to unwrap as we check if the payload exist.
-            let payload_value = self.first().unwrap().name();
-            return quote! {
-                let payload = #payload_value ;
-            };
+            return quote! {};
+        }
+
+        quote! {
+            let payload = #sylvia ::cw_std::to_json_binary(&payload)?;
         }
+    }
 
+    /// Collects the payload parameters into the `payload` of the
+    /// [payload type](PayloadFields::emit_payload_type).
+    fn emit_payload_collection(&self) -> TokenStream {
+        let payload_type = self.emit_payload_type();
         let payload_values = self.iter().map(|field| field.name());
         quote! {
-            let payload = #sylvia ::cw_std::to_json_binary(&( #(#payload_values),* ))?;
+            let payload: #payload_type = ( #(#payload_values),* );
         }
     }
 
+    /// Type of the payload shared by the reply handlers.
+    /// Tuple of the payload parameters types, or the raw payload type if marked with `sv::payload`.
+    fn emit_payload_type(&self) -> TokenStream {
+        if self.is_payload_marked() {
+            // Safe to unwrap as we check if the payload exist.
+            let payload_type = self.first().unwrap().ty();
+            return quote! { #payload_type };
+        }
+
+        let payload_types = self.iter().map(|field| field.ty());
+        quote! { ( #(#payload_types),* ) }
+    }
+
     fn is_payload_marked(&self) -> bool {
         self.iter().any(|field| {
             ParsedSylviaAttributes::new(field.attrs().iter())
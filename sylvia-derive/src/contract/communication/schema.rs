@@ -0,0 +1,140 @@
+use crate::crate_module;
+use crate::parser::variant_descs::AsVariantDescs;
+use crate::parser::MsgType;
+use crate::types::interfaces::Interfaces;
+use crate::types::msg_variant::MsgVariants;
+use crate::utils::emit_bracketed_generics;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{GenericParam, ItemImpl, Type};
+
+/// Emits the `generate_schema` function building the full API description of the contract.
+///
+/// The schema is generated from the glue messages, so it covers the interface messages,
+/// and includes separate sub-schemas of every interface implemented on the contract
+/// the schema of the `ReceiveMsg` if the contract handles the cw20 `Receive` message
+/// and the schemas of the events listed in the `sv::events` attributes.
+pub struct Schema<'a> {
+    source: &'a ItemImpl,
+    generics: &'a [&'a GenericParam],
+    interfaces: &'a Interfaces,
+    events: &'a [Type],
+}
+
+impl<'a> Schema<'a> {
+    pub fn new(
+        source: &'a ItemImpl,
+        generics: &'a [&'a GenericParam],
+        interfaces: &'a Interfaces,
+        events: &'a [Type],
+    ) -> Self {
+        Self {
+            source,
+            generics,
+            interfaces,
+            events,
+        }
+    }
+
+    pub fn emit(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            source,
+            generics,
+            interfaces,
+            events,
+        } = self;
+
+        let contract = &source.self_ty;
+        let bracketed_generics = emit_bracketed_generics(generics);
+        let api = quote! { < #contract as #sylvia ::types::ContractApi> };
+
+        let has_migrate =
+            MsgVariants::<GenericParam>::new(source.as_variants(), MsgType::Migrate, &[], &None)
+                .get_only_variant()
+                .is_some();
+        let migrate = match has_migrate {
+            true => quote! { Some(#sylvia ::schemars::schema_for!( #api ::Migrate )) },
+            false => quote! { None },
+        };
+
+        let receive_variants = MsgVariants::new(
+            source.as_variants(),
+            MsgType::Cw20Receive,
+            generics,
+            &source.generics.where_clause,
+        );
+        let receive_msg = receive_variants.variants().next().map(|_| {
+            let receive_generics = emit_bracketed_generics(receive_variants.used_generics());
+            quote! { ReceiveMsg #receive_generics }
+        });
+        let cw20_receive = match &receive_msg {
+            Some(receive_msg) => quote! { Some(#sylvia ::schemars::schema_for!( #receive_msg )) },
+            None => quote! { None },
+        };
+
+        let mut predicates: Vec<_> = source
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|clause| clause.predicates.iter())
+            .map(|predicate| quote! { #predicate })
+            .collect();
+        predicates.extend([
+            quote! { #api ::Instantiate: #sylvia ::schemars::JsonSchema },
+            quote! { #api ::ContractExec: #sylvia ::schemars::JsonSchema },
+            quote! { #api ::ContractQuery: #sylvia ::cw_schema::QueryResponses },
+            quote! { #api ::ContractSudo: #sylvia ::schemars::JsonSchema },
+            quote! { #api ::Migrate: #sylvia ::schemars::JsonSchema },
+        ]);
+        predicates.extend(
+            receive_msg
+                .iter()
+                .map(|receive_msg| quote! { #receive_msg: #sylvia ::schemars::JsonSchema }),
+        );
+        predicates.extend(interfaces.emit_schema_predicates(contract));
+        predicates.extend(
+            events
+                .iter()
+                .map(|event| quote! { #event: #sylvia ::schemars::JsonSchema }),
+        );
+
+        let interface_apis = interfaces.emit_interface_apis(contract);
+        let event_schemas = events.iter().map(|event| {
+            quote! {
+                (
+                    < #event as #sylvia ::events::TypedEvent>::EVENT_TYPE.to_owned(),
+                    #sylvia ::events::event_schema::< #event >(),
+                )
+            }
+        });
+
+        quote! {
+            /// Generates the full API description of the contract.
+            ///
+            /// The contract name and version are taken from the crate the contract is defined in.
+            pub fn generate_schema #bracketed_generics () -> #sylvia ::schema::ContractSchema
+            where #(#predicates,)*
+            {
+                let contract_name = env!("CARGO_PKG_NAME");
+                let contract_version = env!("CARGO_PKG_VERSION");
+
+                #sylvia ::schema::ContractSchema {
+                    api: #sylvia ::cw_schema::Api {
+                        contract_name: contract_name.to_owned(),
+                        contract_version: contract_version.to_owned(),
+                        instantiate: Some(#sylvia ::schemars::schema_for!( #api ::Instantiate )),
+                        execute: Some(#sylvia ::schemars::schema_for!( #api ::ContractExec )),
+                        query: Some(#sylvia ::schemars::schema_for!( #api ::ContractQuery )),
+                        migrate: #migrate,
+                        sudo: Some(#sylvia ::schemars::schema_for!( #api ::ContractSudo )),
+                        responses: Some(< #api ::ContractQuery as #sylvia ::cw_schema::QueryResponses>::response_schemas_impl()),
+                    },
+                    interfaces: std::collections::BTreeMap::from([ #(#interface_apis,)* ]),
+                    cw20_receive: #cw20_receive,
+                    events: std::collections::BTreeMap::from([ #(#event_schemas,)* ]),
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,10 @@
+use crate::crate_module;
+use crate::parser::attributes::middleware::{emit_middleware_dispatch, is_hooked, MiddlewareAttr};
 use crate::parser::attributes::MsgAttrForwarding;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{ContractErrorAttr, Custom, MsgType, ParsedSylviaAttributes};
 use crate::types::msg_field::MsgField;
-use crate::types::msg_variant::MsgVariants;
+use crate::types::msg_variant::{MsgVariant, MsgVariants};
 use crate::utils::{as_where_clause, emit_bracketed_generics, filter_wheres};
 use proc_macro2::TokenStream;
 use proc_macro_error::emit_error;
@@ -19,6 +21,8 @@ pub struct StructMessage<'a> {
     error: &'a ContractErrorAttr,
     custom: &'a Custom,
     msg_attrs_to_forward: Vec<MsgAttrForwarding>,
+    cw2: bool,
+    middlewares: Vec<MiddlewareAttr>,
 }
 
 impl<'a> StructMessage<'a> {
@@ -55,11 +59,14 @@ impl<'a> StructMessage<'a> {
             return None;
         }
 
-        let msg_attrs_to_forward = ParsedSylviaAttributes::new(source.attrs.iter())
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let cw2 = parsed_attrs.sv_features.cw2;
+        let msg_attrs_to_forward = parsed_attrs
             .msg_attrs_forward
             .into_iter()
             .filter(|attr| attr.msg_type == msg_ty)
             .collect();
+        let middlewares = parsed_attrs.middleware_attrs;
 
         Some(Self {
             source,
@@ -69,6 +76,8 @@ impl<'a> StructMessage<'a> {
             error,
             custom,
             msg_attrs_to_forward,
+            cw2,
+            middlewares,
         })
     }
 
@@ -81,6 +90,8 @@ impl<'a> StructMessage<'a> {
             error,
             custom,
             msg_attrs_to_forward,
+            middlewares,
+            ..
         } = self;
 
         let Some(variant) = variants.get_only_variant() else {
@@ -114,10 +125,33 @@ impl<'a> StructMessage<'a> {
 
         let msg_attrs_to_forward = msg_attrs_to_forward.iter().map(|attr| &attr.attrs);
         let derive_call = variant.msg_attr().msg_type().emit_derive_call();
+        let dispatch = self.emit_dispatch(variant);
+        let funds_doc = variant.emit_funds_doc();
+        let function_name_str = function_name.to_string();
+
+        let msg_ty = variant.msg_attr().msg_type();
+        let dispatch_signature = match is_hooked(middlewares, msg_ty) {
+            true => quote! { fn dispatch_without_middleware },
+            false => quote! { pub fn dispatch },
+        };
+        let middleware_dispatch = match is_hooked(middlewares, msg_ty) {
+            true => {
+                let middleware_dispatch =
+                    emit_middleware_dispatch(middlewares, msg_ty, contract_type, custom);
+                quote! {
+                    pub fn dispatch #bracketed_unused_generics (self, contract: &#contract_type, ctx: #ctx_type) -> #ret_type #full_where
+                    {
+                        #middleware_dispatch
+                    }
+                }
+            }
+            false => quote! {},
+        };
 
         quote! {
             #[allow(clippy::derive_partial_eq_without_eq)]
             #derive_call
+            #funds_doc
             #( #[ #msg_attrs_to_forward ] )*
             #[serde(rename_all="snake_case")]
             pub struct #name #bracketed_used_generics {
@@ -129,12 +163,70 @@ impl<'a> StructMessage<'a> {
                     Self { #(#fields_names,)* }
                 }
 
-                pub fn dispatch #bracketed_unused_generics (self, contract: &#contract_type, ctx: #ctx_type) -> #ret_type #full_where
+                #middleware_dispatch
+
+                #dispatch_signature #bracketed_unused_generics (self, contract: &#contract_type, ctx: #ctx_type) -> #ret_type #full_where
                 {
                     let Self { #(#fields_names,)* } = self;
-                    contract.#function_name(Into::into(ctx), #(#fields_names,)*).map_err(Into::into)
+                    #dispatch
                 }
+
+                /// Name of the message handler.
+                pub fn msg_name(&self) -> &'static str {
+                    #function_name_str
+                }
+            }
+        }
+    }
+
+    /// Emits call to the message handler.
+    ///
+    /// With the `cw2` feature enabled, the contract version is recorded on instantiation.
+    /// On migration, the stored version is verified before calling the handler and updated
+    /// after the handler succeeds.
+    fn emit_dispatch(&self, variant: &MsgVariant) -> TokenStream {
+        let sylvia = crate_module();
+        let function_name = variant.function_name();
+        let fields_names = variant.fields().iter().map(MsgField::name);
+        let msg_ty = variant.msg_attr().msg_type();
+        let migrate_from = variant.msg_attr().migrate_from();
+
+        if let (Some(migrate_from), false) = (migrate_from, self.cw2) {
+            emit_error!(migrate_from.span(), "The `from` argument requires the `cw2` feature.";
+                note = "Add `#[sv::features(cw2)]` attribute to the contract."
+            );
+        }
+
+        if self.cw2 && msg_ty == MsgType::Instantiate && !cfg!(feature = "cw2") {
+            emit_error!(function_name.span(), "The `cw2` contract feature requires the `cw2` feature of the `sylvia` crate.";
+                note = "Enable the `cw2` feature of the `sylvia` crate."
+            );
+        }
+
+        let name = quote! { env!("CARGO_PKG_NAME") };
+        let version = quote! { env!("CARGO_PKG_VERSION") };
+        let funds_check = variant.emit_funds_check();
+        let validations = variant.emit_validations(variant.fields().iter().map(MsgField::name));
+
+        let call = match msg_ty {
+            MsgType::Instantiate if self.cw2 => quote! {
+                let (mut deps, env, info) = ctx;
+                #sylvia ::versioning::set_contract_version(deps.storage, #name, #version)?;
+                contract.#function_name(Into::into((deps, env, info)), #(#fields_names,)*).map_err(Into::into)
+            },
+            MsgType::Migrate if self.cw2 => {
+                let fields_names: Vec<_> = fields_names.collect();
+                variant.emit_versioned_migrate_call(&fields_names)
             }
+            _ => quote! {
+                contract.#function_name(Into::into(ctx), #(#fields_names,)*).map_err(Into::into)
+            },
+        };
+
+        quote! {
+            #funds_check
+            #validations
+            #call
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::crate_module;
 use crate::fold::StripGenerics;
-use crate::parser::{ContractErrorAttr, Custom, MsgType};
+use crate::parser::attributes::middleware::{emit_middleware_dispatch, is_hooked, MiddlewareAttr};
+use crate::parser::{ContractErrorAttr, Custom, MsgType, ParsedSylviaAttributes};
 use crate::types::interfaces::Interfaces;
 use crate::utils::emit_bracketed_generics;
 use proc_macro2::TokenStream;
@@ -22,6 +23,7 @@ pub struct GlueMessage<'a> {
     error: &'a ContractErrorAttr,
     custom: &'a Custom,
     interfaces: &'a Interfaces,
+    middlewares: Vec<MiddlewareAttr>,
 }
 
 impl<'a> GlueMessage<'a> {
@@ -32,6 +34,8 @@ impl<'a> GlueMessage<'a> {
         custom: &'a Custom,
         interfaces: &'a Interfaces,
     ) -> Self {
+        let middlewares = ParsedSylviaAttributes::new(source.attrs.iter()).middleware_attrs;
+
         GlueMessage {
             source,
             contract: &source.self_ty,
@@ -39,6 +43,7 @@ impl<'a> GlueMessage<'a> {
             error,
             custom,
             interfaces,
+            middlewares,
         }
     }
 
@@ -51,7 +56,7 @@ impl<'a> GlueMessage<'a> {
             error,
             custom,
             interfaces,
-            ..
+            middlewares,
         } = self;
 
         let generics: Vec<_> = source.generics.params.iter().collect();
@@ -78,6 +83,9 @@ impl<'a> GlueMessage<'a> {
         let dispatch_arm =
             quote! {#contract_enum_name :: #contract_name (msg) => msg.dispatch(contract, ctx)};
 
+        let interfaces_names = interfaces.variants_names();
+        let msg_name_arm = quote! {#contract_enum_name :: #contract_name (msg) => msg.msg_name()};
+
         let interfaces_deserialization_attempts = interfaces.emit_deserialization_attempts(msg_ty);
 
         let contract_deserialization_attempt = quote! {
@@ -114,6 +122,55 @@ impl<'a> GlueMessage<'a> {
             }
         };
 
+        let dispatch_checks = quote! {
+            const _: () = {
+                let msgs: [&[&str]; #variants_cnt] = [#(#messages_call),*];
+                #sylvia ::utils::assert_no_intersection(msgs);
+            };
+        };
+
+        let dispatch = match is_hooked(middlewares, *msg_ty) {
+            true => {
+                let middleware_dispatch =
+                    emit_middleware_dispatch(middlewares, *msg_ty, contract, custom);
+                quote! {
+                    pub fn dispatch (
+                        self,
+                        contract: &#contract,
+                        ctx: #ctx_type,
+                    ) -> #ret_type #full_where_clause {
+                        #dispatch_checks
+                        #middleware_dispatch
+                    }
+
+                    fn dispatch_without_middleware (
+                        self,
+                        contract: &#contract,
+                        ctx: #ctx_type,
+                    ) -> #ret_type #full_where_clause {
+                        match self {
+                            #(#dispatch_arms,)*
+                            #dispatch_arm
+                        }
+                    }
+                }
+            }
+            false => quote! {
+                pub fn dispatch (
+                    self,
+                    contract: &#contract,
+                    ctx: #ctx_type,
+                ) -> #ret_type #full_where_clause {
+                    #dispatch_checks
+
+                    match self {
+                        #(#dispatch_arms,)*
+                        #dispatch_arm
+                    }
+                }
+            },
+        };
+
         let modules_names = interfaces.variants_modules();
         let variants_names = interfaces.variants_names();
         let serde = quote! { #sylvia:: serde }.to_string();
@@ -169,19 +226,13 @@ impl<'a> GlueMessage<'a> {
             }
 
             impl #bracketed_wrapper_generics #contract_enum_name #bracketed_wrapper_generics #full_where_clause {
-                pub fn dispatch (
-                    self,
-                    contract: &#contract,
-                    ctx: #ctx_type,
-                ) -> #ret_type #full_where_clause {
-                    const _: () = {
-                        let msgs: [&[&str]; #variants_cnt] = [#(#messages_call),*];
-                        #sylvia ::utils::assert_no_intersection(msgs);
-                    };
+                #dispatch
 
+                /// Name of the message as it appears in the JSON.
+                pub fn msg_name(&self) -> &'static str {
                     match self {
-                        #(#dispatch_arms,)*
-                        #dispatch_arm
+                        #(#contract_enum_name :: #interfaces_names (msg) => msg.msg_name(),)*
+                        #msg_name_arm
                     }
                 }
             }
@@ -216,7 +267,8 @@ impl<'a> GlueMessage<'a> {
                         // It might be better to forward the error or serialization, but we just
                         // deserialized it from JSON, not reason to expect error here.
                         format!(
-                            "Unsupported message received: {}. Messages supported by this contract: ",
+                            "{}: {}. Messages supported by this contract: ",
+                            #sylvia ::types::UNSUPPORTED_MESSAGE,
                             #sylvia ::cw_std::to_json_string(&val).unwrap_or_else(|_| String::new())
                         ),
                         |mut acc, message| acc + message + ", ",
@@ -1,6 +1,6 @@
 use convert_case::Case;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse_quote, GenericParam, ItemImpl, Type};
 
 use crate::crate_module;
@@ -9,6 +9,8 @@ use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{
     Custom, FilteredOverrideEntryPoints, OverrideEntryPoint, ParsedSylviaAttributes,
 };
+use crate::types::interfaces::Interfaces;
+use crate::types::msg_field::MsgField;
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
 use crate::utils::{emit_bracketed_generics, get_ident_from_type, SvCasing};
 
@@ -45,12 +47,14 @@ impl<'a> MtHelpers<'a> {
             generic_params,
             where_clause,
         );
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
         let exec_variants = MsgVariants::new(
             source.as_variants(),
             MsgType::Exec,
             generic_params,
             where_clause,
-        );
+        )
+        .with_default_funds(parsed_attrs.sv_features.default_exec_funds());
         let query_variants = MsgVariants::new(
             source.as_variants(),
             MsgType::Query,
@@ -76,7 +80,6 @@ impl<'a> MtHelpers<'a> {
             where_clause,
         );
 
-        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
         let error_type = parsed_attrs.error_attrs.unwrap_or_default().error;
         let error_type = parse_quote! { #error_type };
 
@@ -147,8 +150,20 @@ impl<'a> MtHelpers<'a> {
         let sudo_methods = sudo_variants.variants().map(|variant| {
             variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, &api)
         });
+        // `MigrateMsg` is an enum if the contract defines more than one migrate handler.
+        let migrate_enum = migrate_variants.variants().count() > 1;
         let migrate_methods = migrate_variants.variants().map(|variant| {
-            variant.emit_mt_method_definition(&custom_msg, &mt_app, error_type, &api)
+            let constructor = match migrate_enum {
+                true => variant.name().to_case(Case::Snake),
+                false => Ident::new("new", variant.name().span()),
+            };
+            variant.emit_mt_migrate_method_definition(
+                &custom_msg,
+                &mt_app,
+                error_type,
+                &api,
+                &constructor,
+            )
         });
 
         let exec_methods_declarations = exec_variants
@@ -169,6 +184,7 @@ impl<'a> MtHelpers<'a> {
             .map(|where_clause| &where_clause.predicates);
 
         let impl_contract = self.emit_impl_contract();
+        let impl_ibc_contract = self.emit_impl_ibc_contract();
         let code_id = self.emit_code_id();
         let instantiate_proxy = self.emit_instantiate_proxy(&contract);
 
@@ -212,6 +228,8 @@ impl<'a> MtHelpers<'a> {
 
                 #impl_contract
 
+                #impl_ibc_contract
+
                 #code_id
 
                 #instantiate_proxy
@@ -238,15 +256,17 @@ impl<'a> MtHelpers<'a> {
                 }
             });
 
-        let fields_names = instantiate_variant
+        let instantiate_fields = instantiate_variant
             .get_only_variant()
-            .map(MsgVariant::as_fields_names)
-            .unwrap_or(vec![]);
-
-        let fields = instantiate_variant
+            .map(MsgVariant::fields)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let fields_names = instantiate_fields.iter().map(MsgField::name);
+        let fields_values = instantiate_fields.iter().map(MsgField::emit_into_field);
+        let fields = instantiate_fields.iter().map(MsgField::emit_helper_field);
+        let funds_doc = instantiate_variant
             .get_only_variant()
-            .map(MsgVariant::emit_method_field)
-            .unwrap_or(vec![]);
+            .map(MsgVariant::emit_funds_doc);
 
         let used_generics = instantiate_variant.used_generics();
 
@@ -327,10 +347,11 @@ impl<'a> MtHelpers<'a> {
 
                 #code_info
 
+                #funds_doc
                 pub fn instantiate(
                     &self, #(#fields,)*
                 ) -> InstantiateProxy<'_, 'app, #(#generic_params,)* #mt_app > {
-                    let msg = #instantiate_msg {#(#fields_names,)*};
+                    let msg = #instantiate_msg {#(#fields_names: #fields_values,)*};
                     InstantiateProxy::<'_, 'app, #(#generic_params,)* _> {
                         code_id: self,
                         funds: &[],
@@ -516,27 +537,22 @@ impl<'a> MtHelpers<'a> {
 
         let reply_body = match override_entry_points.get_entry_point(MsgType::Reply) {
             Some(entry_point) => entry_point.emit_multitest_dispatch(),
-            None => reply_variants
-                .get_only_variant()
-                .as_ref()
-                .map(|_reply| {
-                    let contract_ident = get_ident_from_type(contract_name);
-                    let contract_turbofish = if !generic_params.is_empty() {
-                        quote! { #contract_ident ::< #(#generic_params,)* > }
-                    } else {
-                        quote! { #contract_ident }
-                    };
+            None if reply_variants.get_only_variant().is_some()
+                || Interfaces::new(source).has_reply() =>
+            {
+                let contract_ident = get_ident_from_type(contract_name);
+                let contract_turbofish = if !generic_params.is_empty() {
+                    quote! { #contract_ident ::< #(#generic_params,)* > }
+                } else {
+                    quote! { #contract_ident }
+                };
 
-                    quote! {
-                        let contract = #contract_turbofish ::new();
-                        dispatch_reply(deps, env, msg, contract).map_err(Into::into)
-                    }
-                })
-                .unwrap_or_else(|| {
-                    quote! {
-                        #sylvia ::anyhow::bail!("reply not implemented for contract")
-                    }
-                }),
+                quote! {
+                    let contract = #contract_turbofish ::new();
+                    dispatch_reply(deps, env, msg, contract).map_err(Into::into)
+                }
+            }
+            None => quote! { #sylvia ::anyhow::bail!("reply not implemented for contract") },
         };
 
         let custom_msg = custom.msg_or_default();
@@ -602,6 +618,75 @@ impl<'a> MtHelpers<'a> {
             }
         }
     }
+
+    fn emit_impl_ibc_contract(&self) -> TokenStream {
+        if !cfg!(feature = "stargate") {
+            return quote! {};
+        }
+
+        let Self {
+            source,
+            contract_name,
+            custom,
+            generic_params,
+            where_clause,
+            ..
+        } = self;
+        let sylvia = crate_module();
+
+        let bracketed_generics = emit_bracketed_generics(generic_params);
+        let full_where_clause = &source.generics.where_clause;
+
+        let custom_msg = custom.msg_or_default();
+        let custom_query = custom.query_or_default();
+
+        let contract_ident = get_ident_from_type(contract_name);
+        let contract_turbofish = if !generic_params.is_empty() {
+            quote! { #contract_ident ::< #(#generic_params,)* > }
+        } else {
+            quote! { #contract_ident }
+        };
+
+        let has_ibc_interfaces = Interfaces::new(source).has_ibc();
+        let methods = MsgType::IBC.into_iter().map(|msg_ty| {
+            let ep_name = msg_ty.emit_ep_name();
+            let msg_name = msg_ty.emit_msg_name();
+            let result =
+                msg_ty.emit_result_type(&custom_msg, &parse_quote! { #sylvia ::anyhow::Error });
+            let is_defined = has_ibc_interfaces
+                || MsgVariants::new(source.as_variants(), msg_ty, generic_params, where_clause)
+                    .get_only_variant()
+                    .is_some();
+
+            let body = if is_defined {
+                let dispatch_name = format_ident!("dispatch_{}", ep_name);
+                quote! {
+                    let contract = #contract_turbofish ::new();
+                    #dispatch_name (deps, env, msg, contract).map_err(Into::into)
+                }
+            } else {
+                let err_msg = format!("{} not implemented for contract", ep_name);
+                quote! { #sylvia ::anyhow::bail!( #err_msg ) }
+            };
+
+            quote! {
+                fn #ep_name (
+                    &self,
+                    deps: #sylvia ::cw_std::DepsMut< #custom_query >,
+                    env: #sylvia ::cw_std::Env,
+                    msg: #sylvia ::cw_std:: #msg_name,
+                ) -> #result {
+                    #body
+                }
+            }
+        });
+
+        quote! {
+            impl #bracketed_generics #sylvia ::multitest::IbcContract<#custom_msg, #custom_query> for #contract_name #full_where_clause {
+                #(#methods)*
+            }
+        }
+    }
 }
 
 fn emit_default_dispatch(msg_ty: &MsgType, contract_name: &Type) -> TokenStream {
@@ -627,6 +712,15 @@ trait EmitMethods {
         api: &TokenStream,
     ) -> TokenStream;
 
+    fn emit_mt_migrate_method_definition(
+        &self,
+        custom_msg: &Type,
+        mt_app: &Type,
+        error_type: &Type,
+        api: &TokenStream,
+        constructor: &Ident,
+    ) -> TokenStream;
+
     fn emit_mt_method_declaration(
         &self,
         custom_msg: &Type,
@@ -653,7 +747,11 @@ impl EmitMethods for MsgVariant<'_> {
             .iter()
             .map(|field| field.emit_method_field_folded())
             .collect();
-        let arguments = self.as_fields_names();
+        let arguments: Vec<_> = self
+            .fields()
+            .iter()
+            .map(MsgField::emit_into_field)
+            .collect();
         let type_name = self.msg_attr().msg_type().as_accessor_name();
         let name = name.to_case(Case::Snake);
 
@@ -667,6 +765,21 @@ impl EmitMethods for MsgVariant<'_> {
                 }
             },
             MsgType::Query => {
+                let page_iter = self.page_item().map(|page_item| {
+                    let fields = self.fields_without_pagination();
+                    let params = fields.iter().map(|field| field.emit_method_field_folded());
+                    let owned_fields = fields.iter().map(MsgField::emit_to_owned);
+                    let arguments = fields.iter().map(MsgField::emit_owned_arg);
+                    let iter_name = format_ident!("{}_iter", name);
+
+                    quote! {
+                        fn #iter_name (&self, #(#params,)* ) -> #sylvia ::pagination::PageIter<'_, #page_item, #error_type> {
+                            #(#owned_fields)*
+                            #sylvia ::pagination::PageIter::new(move |start_after| self.#name( #(#arguments,)* start_after, None))
+                        }
+                    }
+                });
+
                 quote! {
                     fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type> {
                         let msg = #api :: #type_name :: #name ( #(#arguments),* );
@@ -676,6 +789,8 @@ impl EmitMethods for MsgVariant<'_> {
                             .query_wasm_smart(self.contract_addr.clone(), &msg)
                             .map_err(Into::into)
                     }
+
+                    #page_iter
                 }
             }
             MsgType::Sudo => quote! {
@@ -688,18 +803,53 @@ impl EmitMethods for MsgVariant<'_> {
                         .map_err(|err| err.downcast().unwrap())
                 }
             },
-            MsgType::Migrate => quote! {
-                #[track_caller]
-                fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::MigrateProxy::< #error_type, #api :: #type_name , #mt_app, #custom_msg> {
-                    let msg = #api :: #type_name ::new( #(#arguments),* );
-
-                    #sylvia ::multitest::MigrateProxy::new(&self.contract_addr, msg, &self.app)
-                }
-            },
+            MsgType::Migrate => {
+                let constructor = Ident::new("new", name.span());
+                self.emit_mt_migrate_method_definition(
+                    custom_msg,
+                    mt_app,
+                    error_type,
+                    api,
+                    &constructor,
+                )
+            }
             _ => quote! {},
         }
     }
 
+    fn emit_mt_migrate_method_definition(
+        &self,
+        custom_msg: &Type,
+        mt_app: &Type,
+        error_type: &Type,
+        api: &TokenStream,
+        constructor: &Ident,
+    ) -> TokenStream {
+        let sylvia = crate_module();
+
+        let params: Vec<_> = self
+            .fields()
+            .iter()
+            .map(|field| field.emit_method_field_folded())
+            .collect();
+        let arguments: Vec<_> = self
+            .fields()
+            .iter()
+            .map(MsgField::emit_into_field)
+            .collect();
+        let type_name = self.msg_attr().msg_type().as_accessor_name();
+        let name = self.name().to_case(Case::Snake);
+
+        quote! {
+            #[track_caller]
+            fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::MigrateProxy::< #error_type, #api :: #type_name , #mt_app, #custom_msg> {
+                let msg = #api :: #type_name :: #constructor ( #(#arguments),* );
+
+                #sylvia ::multitest::MigrateProxy::new(&self.contract_addr, msg, &self.app)
+            }
+        }
+    }
+
     fn emit_mt_method_declaration(
         &self,
         custom_msg: &Type,
@@ -719,13 +869,31 @@ impl EmitMethods for MsgVariant<'_> {
         let type_name = self.msg_attr().msg_type().as_accessor_name();
         let name = name.to_case(Case::Snake);
 
+        let funds_doc = self.emit_funds_doc();
+
         match self.msg_attr().msg_type() {
             MsgType::Exec => quote! {
+                #funds_doc
                 fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api:: #type_name, MtApp, #custom_msg>;
             },
-            MsgType::Query => quote! {
-                fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type>;
-            },
+            MsgType::Query => {
+                let page_iter = self.page_item().map(|page_item| {
+                    let params = self
+                        .fields_without_pagination()
+                        .iter()
+                        .map(|field| field.emit_method_field_folded());
+                    let iter_name = format_ident!("{}_iter", name);
+
+                    quote! {
+                        fn #iter_name (&self, #(#params,)* ) -> #sylvia ::pagination::PageIter<'_, #page_item, #error_type>;
+                    }
+                });
+
+                quote! {
+                    fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type>;
+                    #page_iter
+                }
+            }
             MsgType::Sudo => quote! {
                 fn #name (&self, #(#params,)* ) -> Result< #sylvia ::cw_multi_test::AppResponse, #error_type>;
             },
@@ -1,6 +1,6 @@
 use proc_macro2::{Span, TokenStream};
 use proc_macro_error::emit_error;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::fold::Fold;
 use syn::{parse_quote, GenericParam, Ident, ItemImpl, Type, WhereClause};
 
@@ -12,6 +12,7 @@ use crate::parser::variant_descs::AsVariantDescs;
 use crate::parser::{
     EntryPointArgs, FilteredOverrideEntryPoints, OverrideEntryPoint, ParsedSylviaAttributes,
 };
+use crate::types::interfaces::Interfaces;
 use crate::types::msg_variant::MsgVariants;
 
 /// Preprocessed [`entry_points`](crate::entry_points) macro input.
@@ -19,6 +20,8 @@ use crate::types::msg_variant::MsgVariants;
 /// Generates `entry_points` module containing:
 ///     - instantiate, execute, query and sudo entry points by default
 ///     - migrate and reply entry points if respective messages are defined
+///     - IBC entry points for every defined `ibc_*` handler or if any interface handles IBC messages
+///     - `schema` function generating the schema of the contract
 pub struct EntryPointInput<'a> {
     item: &'a ItemImpl,
     args: EntryPointArgs,
@@ -60,6 +63,8 @@ impl<'a> EntryPointInput<'a> {
 ///
 /// By default generates entry points for `instantiate`, `execute`, `query` and `sudo` messages.
 /// Generates `reply` and `migrate` entry points if respective messages are defined.
+/// The `reply` entry point is also generated if any interface is marked with the `reply` parameter.
+/// Generates IBC entry points for every defined `ibc_*` handler.
 pub struct EntryPoints<'a> {
     source: &'a ItemImpl,
     name: Type,
@@ -150,13 +155,26 @@ impl<'a> EntryPoints<'a> {
             .get_entry_point(MsgType::Reply)
             .map(|_| quote! {})
             .unwrap_or_else(|| {
-                if reply.is_some() {
+                if reply.is_some() || Interfaces::new(source).has_reply() {
                     self.emit_default_entry_point(MsgType::Reply)
                 } else {
                     quote! {}
                 }
             });
 
+        let has_ibc_interfaces = Interfaces::new(source).has_ibc();
+        let ibc_entry_points = MsgType::IBC
+            .into_iter()
+            .filter(|msg_ty| {
+                has_ibc_interfaces
+                    || MsgVariants::new(source.as_variants(), *msg_ty, generics, where_clause)
+                        .get_only_variant()
+                        .is_some()
+            })
+            .map(|msg_ty| self.emit_default_entry_point(msg_ty));
+
+        let schema = self.emit_schema();
+
         quote! {
             pub mod entry_points {
                 use super::*;
@@ -166,6 +184,29 @@ impl<'a> EntryPoints<'a> {
                 #migrate
 
                 #reply_ep
+
+                #(#ibc_entry_points)*
+
+                #schema
+            }
+        }
+    }
+
+    /// Emits the `schema` function generating the schema of the contract
+    /// with the concrete types provided in `#[entry_points(generics<...>)]`.
+    fn emit_schema(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let attr_generics = &self.attrs.generics;
+        let generate_schema = match attr_generics.is_empty() {
+            true => quote! { sv::generate_schema },
+            false => quote! { sv::generate_schema::< #attr_generics > },
+        };
+
+        quote! {
+            /// Generates the full API description of the contract.
+            #[cfg(not(target_arch = "wasm32"))]
+            pub fn schema() -> #sylvia ::schema::ContractSchema {
+                #generate_schema ()
             }
         }
     }
@@ -198,6 +239,10 @@ impl<'a> EntryPoints<'a> {
         let associated_name = msg_ty.as_accessor_wrapper_name();
         let msg = match msg_ty {
             MsgType::Reply => quote! { msg: #sylvia ::cw_std::Reply },
+            _ if msg_ty.is_ibc() => {
+                let msg_name = msg_ty.emit_msg_name();
+                quote! { msg: #sylvia ::cw_std:: #msg_name }
+            }
             _ => quote! { msg: < #contract as #sylvia ::types::ContractApi> :: #associated_name },
         };
         let dispatch = match msg_ty {
@@ -205,6 +250,13 @@ impl<'a> EntryPoints<'a> {
                 let contract = #contract_turbofish ::new();
                 sv::dispatch_reply(deps, env, msg, contract).map_err(Into::into)
             },
+            _ if msg_ty.is_ibc() => {
+                let dispatch_name = format_ident!("dispatch_{}", ep_name);
+                quote! {
+                    let contract = #contract_turbofish ::new();
+                    sv:: #dispatch_name (deps, env, msg, contract).map_err(Into::into)
+                }
+            }
             _ => quote! {
                 msg.dispatch(& #contract_turbofish ::new() , ( #values )).map_err(Into::into)
             },
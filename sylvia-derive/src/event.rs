@@ -0,0 +1,81 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Fields, ItemStruct, LitStr};
+
+use crate::crate_module;
+use crate::parser::EventArgs;
+
+/// Preprocessed [`event`](crate::event) macro input.
+///
+/// Generates the `sylvia::events::TypedEvent` implementation converting the struct
+/// into the `cosmwasm_std::Event` with an attribute per field and back.
+pub struct EventInput<'a> {
+    item: &'a ItemStruct,
+    name: LitStr,
+}
+
+impl<'a> EventInput<'a> {
+    pub fn new(item: &'a ItemStruct, args: EventArgs) -> Self {
+        if !item.generics.params.is_empty() {
+            emit_error!(
+                item.generics.span(), "Generic events are not supported.";
+                note = "Events have to be serialized with the same attributes in every contract."
+            );
+        }
+
+        if let Fields::Unnamed(fields) = &item.fields {
+            emit_error!(
+                fields.span(), "Tuple structs can't be used as events.";
+                note = "Fields of the struct are used as the attribute keys of the event."
+            );
+        }
+
+        let name = args.name.unwrap_or_else(|| {
+            let name = item.ident.to_string().to_case(Case::Snake);
+            LitStr::new(&name, item.ident.span())
+        });
+
+        Self { item, name }
+    }
+
+    /// Processes the input and generates the `TypedEvent` implementation.
+    pub fn process(&self) -> TokenStream {
+        let sylvia = crate_module();
+        let Self { item, name } = self;
+
+        let ident = &item.ident;
+        let fields: Vec<_> = item
+            .fields
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .collect();
+        let keys = fields.iter().map(|field| field.to_string());
+        let parse_keys = keys.clone();
+
+        quote! {
+            impl #sylvia ::events::TypedEvent for #ident {
+                const EVENT_TYPE: &'static str = #name;
+
+                fn to_event(&self) -> #sylvia ::cw_std::StdResult< #sylvia ::cw_std::Event> {
+                    let mut event = #sylvia ::cw_std::Event::new(Self::EVENT_TYPE);
+                    #(
+                        if let Some(value) = #sylvia ::events::attribute_value(&self. #fields)? {
+                            event = event.add_attribute(#keys, value);
+                        }
+                    )*
+                    Ok(event)
+                }
+
+                fn from_event(event: & #sylvia ::cw_std::Event) -> #sylvia ::cw_std::StdResult<Self> {
+                    #sylvia ::events::ensure_event_type(event, Self::EVENT_TYPE)?;
+                    Ok(Self {
+                        #( #fields: #sylvia ::events::parse_attribute(event, #parse_keys)?, )*
+                    })
+                }
+            }
+        }
+    }
+}
@@ -1,16 +1,23 @@
 use communication::api::Api;
 use communication::enum_msg::EnumMessage;
 use communication::executor::Executor;
+use communication::ibc::Ibc;
 use communication::querier::Querier;
+use communication::reply::Reply;
 use mt::MtHelpers;
 use proc_macro2::TokenStream;
 use proc_macro_error::{emit_error, emit_warning};
 use quote::quote;
 use syn::{Ident, ItemTrait, TraitItem};
 
+use crate::parser::attributes::access::validate_access_attrs;
+use crate::parser::attributes::events::validate_events_attrs_in_interface;
+use crate::parser::attributes::extract::validate_extract_attrs;
+use crate::parser::attributes::middleware::validate_middleware_attrs_in_interface;
 use crate::parser::attributes::msg::MsgType;
+use crate::parser::attributes::pausable::validate_pausable_attrs;
 use crate::parser::variant_descs::AsVariantDescs;
-use crate::parser::{Custom, ParsedSylviaAttributes};
+use crate::parser::{Custom, InterfaceArgs, ParsedSylviaAttributes};
 use crate::types::associated_types::{AssociatedTypes, ItemType, EXEC_TYPE, QUERY_TYPE};
 use crate::types::msg_variant::MsgVariants;
 
@@ -28,14 +35,17 @@ mod mt;
 ///     - [Querier](https://cosmwasm-docs.vercel.app/cw-multi-test) trait implementation
 ///     - [Executor](https://cosmwasm-docs.vercel.app/cw-multi-test) trait implementation
 ///     - Api trait implementation
+///     - IBC dispatch functions for `ibc_*` handlers
+///     - Reply ids and dispatch function for `reply` handlers
 pub struct InterfaceInput<'a> {
     item: &'a ItemTrait,
+    args: InterfaceArgs,
     custom: Custom,
     associated_types: AssociatedTypes<'a>,
 }
 
 impl<'a> InterfaceInput<'a> {
-    pub fn new(item: &'a ItemTrait) -> Self {
+    pub fn new(item: &'a ItemTrait, args: InterfaceArgs) -> Self {
         if !item.generics.params.is_empty() {
             emit_error!(
                 item.ident.span(), "Generics on traits are not supported. Use associated types instead.";
@@ -55,6 +65,25 @@ impl<'a> InterfaceInput<'a> {
             );
         }
 
+        validate_access_attrs(item.as_variants(), true);
+        validate_extract_attrs(item.as_variants(), true);
+        validate_pausable_attrs(item.as_variants(), true);
+        validate_middleware_attrs_in_interface(&item.attrs);
+        validate_events_attrs_in_interface(&item.attrs);
+
+        item.as_variants()
+            .filter(|variant| {
+                variant
+                    .attr_msg()
+                    .is_some_and(|msg_attr| msg_attr == MsgType::Cw20Receive)
+            })
+            .for_each(|variant| {
+                emit_error!(
+                    variant.into_sig().ident.span(), "The `cw20_receive` messages are not supported in interfaces.";
+                    note = "Handle the cw20 `Receive` message in the contract."
+                );
+            });
+
         let custom = ParsedSylviaAttributes::new(item.attrs.iter())
             .custom_attr
             .unwrap_or_default();
@@ -86,6 +115,7 @@ impl<'a> InterfaceInput<'a> {
 
         Self {
             item,
+            args,
             custom,
             associated_types,
         }
@@ -115,11 +145,18 @@ impl<'a> InterfaceInput<'a> {
 
         let interface_messages = Api::new(item, associated_types).emit();
 
+        let ibc = Ibc::new(item, &self.custom, associated_types).emit();
+
+        let reply = Reply::new(item, &self.custom, associated_types).emit();
+
         let multitest_helpers = self.emit_multitest_helpers();
+        let interface_info = self.emit_interface_info();
 
         quote! {
             pub mod sv {
                 use super::*;
+                #interface_info
+
                 #messages
 
                 #querier
@@ -128,11 +165,33 @@ impl<'a> InterfaceInput<'a> {
 
                 #interface_messages
 
+                #ibc
+
+                #reply
+
                 #multitest_helpers
             }
         }
     }
 
+    /// Emits the name, the ID and the version of the interface reported by the introspection
+    /// queries and checked against the `sv::messages` version requirements.
+    /// The ID is built from the name of the crate defining the interface and the interface name.
+    /// The version defaults to the version of the crate defining the interface.
+    fn emit_interface_info(&self) -> TokenStream {
+        let name = self.item.ident.to_string();
+        let version = match &self.args.version {
+            Some(version) => quote! { #version },
+            None => quote! { env!("CARGO_PKG_VERSION") },
+        };
+
+        quote! {
+            pub const INTERFACE_NAME: &str = #name ;
+            pub const INTERFACE_ID: &str = concat!(env!("CARGO_CRATE_NAME"), "::", #name);
+            pub const INTERFACE_VERSION: &str = #version ;
+        }
+    }
+
     fn emit_messages(&self) -> TokenStream {
         let exec = self.emit_msg(MsgType::Exec);
         let query = self.emit_msg(MsgType::Query);
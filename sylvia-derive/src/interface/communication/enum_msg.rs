@@ -78,6 +78,7 @@ impl<'a> EnumMessage<'a> {
             Ident::new(&format!("{}{}", trait_name, enum_name), enum_name.span());
 
         let match_arms = variants.emit_dispatch_legs();
+        let msg_name_arms = variants.emit_msg_name_arms();
         let mut msgs = variants.as_names_snake_cased();
         msgs.sort();
         let msgs_cnt = msgs.len();
@@ -129,6 +130,16 @@ impl<'a> EnumMessage<'a> {
                         #phatom_match_arm
                     }
                 }
+
+                /// Name of the message as it appears in the JSON.
+                pub fn msg_name(&self) -> &'static str {
+                    use #unique_enum_name::*;
+
+                    match *self {
+                        #(#msg_name_arms,)*
+                    }
+                }
+
                 #(#variants_constructors)*
             }
 
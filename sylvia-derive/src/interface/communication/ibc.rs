@@ -0,0 +1,201 @@
+//! Module responsible for generating IBC related code for interfaces.
+//!
+//! Based on methods marked with the `#[sv::msg(ibc_*)]` attributes, this module generates for
+//! every IBC message type:
+//!     - `handles_ibc_*` function checking if the interface handles the received message,
+//!     - `dispatch_ibc_*` function forwarding the message to the interface method,
+//!     - `IBC_*_HANDLER` constant describing the kind of the handler.
+//!
+//! Contracts implementing the interface with `#[sv::messages(interface: ibc)]` route
+//! the IBC messages through these functions.
+
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Ident, ItemTrait, Type};
+
+use crate::crate_module;
+use crate::parser::variant_descs::AsVariantDescs;
+use crate::parser::{Custom, MsgType};
+use crate::types::associated_types::{AssociatedTypes, ItemType, EXEC_TYPE, QUERY_TYPE};
+use crate::types::msg_variant::{MsgVariant, MsgVariants};
+
+/// Emits `handles_ibc_*` and `dispatch_ibc_*` functions and `IBC_*_HANDLER` constants
+/// for the interface.
+pub struct Ibc<'a> {
+    source: &'a ItemTrait,
+    associated_types: &'a AssociatedTypes<'a>,
+    resp_type: Type,
+    query_type: Type,
+}
+
+impl<'a> Ibc<'a> {
+    pub fn new(
+        source: &'a ItemTrait,
+        custom: &'a Custom,
+        associated_types: &'a AssociatedTypes<'a>,
+    ) -> Self {
+        let trait_name = &source.ident;
+        let associated_exec =
+            associated_types.emit_contract_custom_type_accessor(trait_name, EXEC_TYPE);
+        let associated_query =
+            associated_types.emit_contract_custom_type_accessor(trait_name, QUERY_TYPE);
+
+        let resp_type = custom
+            .msg
+            .clone()
+            .or(associated_exec)
+            .unwrap_or_else(Custom::default_type);
+
+        let query_type = custom
+            .query
+            .clone()
+            .or(associated_query)
+            .unwrap_or_else(Custom::default_type);
+
+        Self {
+            source,
+            associated_types,
+            resp_type,
+            query_type,
+        }
+    }
+
+    pub fn emit(&self) -> TokenStream {
+        let variants: Vec<_> = MsgType::IBC
+            .into_iter()
+            .map(|msg_ty| {
+                MsgVariants::new(self.source.as_variants(), msg_ty, &[] as &[&Ident], &None)
+            })
+            .collect();
+
+        let handlers = MsgType::IBC
+            .into_iter()
+            .zip(variants.iter())
+            .map(|(msg_ty, variants)| Self::emit_handler_const(msg_ty, variants.variants().next()));
+
+        // Functions are emitted with the `stargate` feature even if the interface doesn't declare
+        // any IBC handler, so the contract reports the misuse of the `ibc` parameter
+        // with the assertion on the `IBC_*_HANDLER` constants.
+        if !cfg!(feature = "stargate")
+            && variants
+                .iter()
+                .all(|variants| variants.get_only_variant().is_none())
+        {
+            return quote! {
+                #(#handlers)*
+            };
+        }
+
+        let functions = MsgType::IBC
+            .into_iter()
+            .zip(variants.iter())
+            .map(|(msg_ty, variants)| {
+                let mut variants = variants.variants();
+                let variant = variants.next();
+
+                if let Some(variant) = variant {
+                    if !cfg!(feature = "stargate") {
+                        emit_error!(variant.name().span(), "IBC message handlers require the `stargate` feature.";
+                            note = "Enable the `stargate` feature of the `sylvia` crate."
+                        );
+                    }
+
+                    variants.for_each(|duplicate| {
+                        emit_error!(duplicate.name().span(), "More than one IBC handler of the same type.";
+                            note = variant.name().span() => format!("Previous `{}` handler defined here.", msg_ty.emit_ep_name())
+                        );
+                    });
+                }
+
+                self.emit_functions(msg_ty, variant)
+            });
+
+        quote! {
+            #(#handlers)*
+
+            #(#functions)*
+        }
+    }
+
+    /// Generates the `IBC_*_HANDLER` constant describing the handler of the message type.
+    fn emit_handler_const(msg_ty: MsgType, variant: Option<&MsgVariant>) -> TokenStream {
+        let sylvia = crate_module();
+        let const_name = msg_ty.emit_ibc_handler_const_name();
+        let kind = match variant.map(|variant| variant.msg_attr().packet()) {
+            None => quote! { None },
+            Some(Some(_)) => quote! { Packet },
+            Some(None) => quote! { All },
+        };
+
+        quote! {
+            pub const #const_name: #sylvia ::types::IbcHandler = #sylvia ::types::IbcHandler:: #kind;
+        }
+    }
+
+    fn emit_functions(&self, msg_ty: MsgType, variant: Option<&MsgVariant>) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            source,
+            associated_types,
+            resp_type,
+            query_type,
+        } = self;
+
+        let trait_name = &source.ident;
+        let ep_name = msg_ty.emit_ep_name();
+        let msg_name = msg_ty.emit_msg_name();
+        let handles_name = format_ident!("handles_{}", ep_name);
+        let dispatch_name = format_ident!("dispatch_{}", ep_name);
+
+        let ctx_type = msg_ty.emit_ctx_type(query_type);
+        let generics = associated_types.without_error().map(ItemType::as_name);
+        let where_predicates = associated_types
+            .without_error()
+            .map(ItemType::as_where_predicate);
+        let contract_predicate = associated_types.emit_contract_predicate(trait_name);
+
+        // Without the handler the error is returned as `StdError`, so interfaces not handling
+        // the message don't require `From<StdError>` from the contract error.
+        let (check, ret_type, dispatch) = match variant {
+            Some(variant) => {
+                let function_name = variant.function_name();
+                let check = msg_ty.emit_ibc_packet_check(variant.msg_attr().packet());
+                let ret_type = msg_ty.emit_result_type(resp_type, &parse_quote!(ContractT::Error));
+                let dispatch = quote! {
+                    contract. #function_name (Into::into(ctx), msg).map_err(Into::into)
+                };
+                (check, ret_type, dispatch)
+            }
+            None => {
+                let err_msg = format!(
+                    "`{}` is not supported by the `{}` interface.",
+                    ep_name, trait_name
+                );
+                let ret_type =
+                    msg_ty.emit_result_type(resp_type, &parse_quote!(#sylvia ::cw_std::StdError));
+                let dispatch = quote! {
+                    let _ = (contract, ctx, msg);
+                    Err(#sylvia ::cw_std::StdError::generic_err( #err_msg ))
+                };
+                (quote! { false }, ret_type, dispatch)
+            }
+        };
+
+        quote! {
+            #[allow(unused_variables)]
+            pub fn #handles_name (msg: & #sylvia ::cw_std:: #msg_name) -> bool {
+                #check
+            }
+
+            pub fn #dispatch_name <ContractT, #(#generics,)* >(contract: &ContractT, ctx: #ctx_type, msg: #sylvia ::cw_std:: #msg_name)
+                -> #ret_type
+            where
+                #(#where_predicates,)*
+                #contract_predicate
+            {
+                #dispatch
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub mod api;
 pub mod enum_msg;
 pub mod executor;
+pub mod ibc;
 pub mod querier;
+pub mod reply;
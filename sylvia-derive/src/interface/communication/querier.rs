@@ -1,6 +1,6 @@
 use convert_case::Case;
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::Ident;
 
 use crate::crate_module;
@@ -99,14 +99,31 @@ impl EmitQuerierMethod for MsgVariant<'_> {
         let return_type = self.return_type();
 
         let parameters = fields.iter().map(MsgField::emit_method_field_folded);
-        let fields_names = fields.iter().map(MsgField::name);
+        let fields_values = fields.iter().map(MsgField::emit_into_field);
         let variant_name = name.to_case(Case::Snake);
 
+        let page_iter = self.page_item().map(|page_item| {
+            let fields = self.fields_without_pagination();
+            let parameters = fields.iter().map(MsgField::emit_method_field_folded);
+            let owned_fields = fields.iter().map(MsgField::emit_to_owned);
+            let arguments = fields.iter().map(MsgField::emit_owned_arg);
+            let iter_name = format_ident!("{}_iter", variant_name);
+
+            quote! {
+                fn #iter_name(&self, #(#parameters),*) -> #sylvia ::pagination::PageIter<'_, #page_item, #sylvia ::types::RemoteQueryError> {
+                    #(#owned_fields)*
+                    #sylvia ::pagination::PageIter::new(move |start_after| self.#variant_name(#(#arguments,)* start_after, None))
+                }
+            }
+        });
+
         quote! {
-            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia:: cw_std::StdError> {
-                let query = #api_path :: #variant_name (#(#fields_names),*);
-                self.querier().query_wasm_smart(self.contract(), &query)
+            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia ::types::RemoteQueryError> {
+                let query = #api_path :: #variant_name (#(#fields_values),*);
+                #sylvia ::types::query_wasm_smart(self.querier(), self.contract(), &query)
             }
+
+            #page_iter
         }
     }
 
@@ -121,8 +138,23 @@ impl EmitQuerierMethod for MsgVariant<'_> {
             .map(|field| field.emit_method_field_folded());
         let variant_name = name.to_case(Case::Snake);
 
+        let page_iter = self.page_item().map(|page_item| {
+            let parameters = self
+                .fields_without_pagination()
+                .iter()
+                .map(MsgField::emit_method_field_folded);
+            let iter_name = format_ident!("{}_iter", variant_name);
+
+            quote! {
+                /// Iterates over the items of all the pages of the paginated query.
+                fn #iter_name(&self, #(#parameters),*) -> #sylvia ::pagination::PageIter<'_, #page_item, #sylvia ::types::RemoteQueryError>;
+            }
+        });
+
         quote! {
-            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia:: cw_std::StdError>;
+            fn #variant_name(&self, #(#parameters),*) -> Result< #return_type, #sylvia ::types::RemoteQueryError>;
+
+            #page_iter
         }
     }
 }
@@ -0,0 +1,206 @@
+//! Module responsible for generating `Reply` related code for interfaces.
+//!
+//! Based on methods marked with the `#[sv::msg(reply)]` attribute, this module generates:
+//!     - `REPLY_NAMESPACE` constant unique for the interface,
+//!     - reply ids for every unique handler prefixed with the `REPLY_NAMESPACE`,
+//!     - `REPLY_IDS` table mapping handler names to their reply ids,
+//!     - `handles_reply` function checking if the reply id belongs to the interface,
+//!     - `dispatch_reply` function forwarding the reply to the interface handler,
+//!     - `SubMsgMethods` trait with method for every reply id,
+//!     - `replies` module with the `ReplyHandler` type for every reply id.
+//!
+//! Contracts implementing the interface with `#[sv::messages(interface: reply)]` route
+//! the replies through these functions.
+
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::{parse_quote, Generics, Ident, ItemTrait, Type};
+
+use crate::contract::communication::reply::{
+    check_unique_reply_ids, emit_reply_handlers, emit_reply_ids_table, emit_sub_msg_trait,
+    ReplyData, ReplyIds, ReplyVariants,
+};
+use crate::crate_module;
+use crate::parser::variant_descs::AsVariantDescs;
+use crate::parser::{Custom, MsgType};
+use crate::types::associated_types::{AssociatedTypes, ItemType, EXEC_TYPE, QUERY_TYPE};
+use crate::types::msg_variant::MsgVariants;
+
+/// Emits reply ids, `handles_reply` and `dispatch_reply` functions for the interface.
+pub struct Reply<'a> {
+    source: &'a ItemTrait,
+    associated_types: &'a AssociatedTypes<'a>,
+    resp_type: Type,
+    query_type: Type,
+    custom: &'a Custom,
+}
+
+impl<'a> Reply<'a> {
+    pub fn new(
+        source: &'a ItemTrait,
+        custom: &'a Custom,
+        associated_types: &'a AssociatedTypes<'a>,
+    ) -> Self {
+        let trait_name = &source.ident;
+        let associated_exec =
+            associated_types.emit_contract_custom_type_accessor(trait_name, EXEC_TYPE);
+        let associated_query =
+            associated_types.emit_contract_custom_type_accessor(trait_name, QUERY_TYPE);
+
+        let resp_type = custom
+            .msg
+            .clone()
+            .or(associated_exec)
+            .unwrap_or_else(Custom::default_type);
+
+        let query_type = custom
+            .query
+            .clone()
+            .or(associated_query)
+            .unwrap_or_else(Custom::default_type);
+
+        Self {
+            source,
+            associated_types,
+            resp_type,
+            query_type,
+            custom,
+        }
+    }
+
+    pub fn emit(&self) -> TokenStream {
+        let variants = MsgVariants::new(
+            self.source.as_variants(),
+            MsgType::Reply,
+            &[] as &[&Ident],
+            &None,
+        );
+        let reply_data = variants.as_reply_data(ReplyIds::Hashed);
+
+        if reply_data.is_empty() {
+            return quote! {};
+        }
+
+        let reply_ids = self.emit_reply_ids(&reply_data);
+        let dispatch = self.emit_dispatch(&reply_data);
+        let sub_msg_trait = emit_sub_msg_trait(&reply_data);
+        let reply_handlers = self.emit_reply_handlers(&reply_data);
+
+        quote! {
+            #reply_ids
+
+            #dispatch
+
+            #sub_msg_trait
+
+            #reply_handlers
+        }
+    }
+
+    /// Generates `replies` module with the `ReplyHandler` type for every reply id.
+    ///
+    /// If the custom message is not fixed with the `sv::custom` attribute, the types are generic
+    /// over the custom message of the contract, defaulting to `Empty`.
+    fn emit_reply_handlers(&self, reply_data: &[ReplyData]) -> TokenStream {
+        let sylvia = crate_module();
+        let (generics, custom_msg): (Generics, Type) = match &self.custom.msg {
+            Some(msg) => (Generics::default(), msg.clone()),
+            None => (
+                parse_quote! { <CustomMsgT = #sylvia ::cw_std::Empty> },
+                parse_quote! { CustomMsgT },
+            ),
+        };
+
+        emit_reply_handlers(reply_data, &generics, &custom_msg)
+    }
+
+    /// Generates the `REPLY_NAMESPACE` and `ReplyId`s for every unique handler.
+    ///
+    /// The namespace is derived from the `INTERFACE_ID`, i.e. the crate and the interface name,
+    /// and occupies the upper 32 bits of the reply id, so the ids don't clash with the ones
+    /// of the contract and other interfaces.
+    fn emit_reply_ids(&self, reply_data: &[ReplyData]) -> TokenStream {
+        let sylvia = crate_module();
+
+        check_unique_reply_ids(reply_data);
+        let reply_ids_table = emit_reply_ids_table(reply_data);
+
+        let reply_ids = reply_data.iter().map(|data| {
+            let id = data.id();
+            if id > u32::MAX as u64 {
+                emit_error!(data.handler_id.span(), "Reply id out of range.";
+                    note = format!("Reply ids of interfaces have to fit in 32 bits. Found `{}`.", id)
+                );
+            }
+            let reply_id = &data.reply_id;
+            quote! {
+                pub const #reply_id : u64 = (REPLY_NAMESPACE << 32) | #id ;
+            }
+        });
+
+        quote! {
+            pub const REPLY_NAMESPACE: u64 = #sylvia ::utils::reply_namespace(INTERFACE_ID);
+
+            #(#reply_ids)*
+
+            #reply_ids_table
+
+            pub const fn handles_reply(id: u64) -> bool {
+                id >> 32 == REPLY_NAMESPACE
+            }
+        }
+    }
+
+    /// Generates `dispatch_reply` function matching over every generated `ReplyId`.
+    fn emit_dispatch(&self, reply_data: &[ReplyData]) -> TokenStream {
+        let sylvia = crate_module();
+        let Self {
+            source,
+            associated_types,
+            resp_type,
+            query_type,
+            ..
+        } = self;
+
+        let trait_name = &source.ident;
+        let generics = associated_types.without_error().map(ItemType::as_name);
+        let where_predicates = associated_types
+            .without_error()
+            .map(ItemType::as_where_predicate);
+        let contract_predicate = associated_types.emit_contract_predicate(trait_name);
+
+        let receiver = quote! { contract };
+        let match_arms = reply_data
+            .iter()
+            .map(|data| data.emit_match_arms(&receiver));
+
+        quote! {
+            pub fn dispatch_reply <ContractT, #(#generics,)* >(
+                contract: &ContractT,
+                deps: #sylvia ::cw_std::DepsMut< #query_type >,
+                env: #sylvia ::cw_std::Env,
+                msg: #sylvia ::cw_std::Reply,
+            ) -> Result< #sylvia ::cw_std::Response< #resp_type >, ContractT::Error>
+            where
+                #(#where_predicates,)*
+                #contract_predicate
+            {
+                let #sylvia ::cw_std::Reply {
+                    id,
+                    payload,
+                    gas_used,
+                    result,
+                } = msg;
+
+                match id {
+                    #(#match_arms,)*
+                    _ => {
+                        let err_msg = format!("Unknown reply id: {}.", id);
+                        Err( #sylvia ::cw_std::StdError::generic_err(err_msg)).map_err(Into::into)
+                    }
+                }
+            }
+        }
+    }
+}
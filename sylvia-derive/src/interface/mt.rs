@@ -1,12 +1,13 @@
 use convert_case::Case;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse_quote, GenericParam, ItemTrait, TraitItem, Type};
 
 use crate::crate_module;
 use crate::parser::attributes::msg::MsgType;
 use crate::parser::variant_descs::AsVariantDescs;
 use crate::types::associated_types::AssociatedTypes;
+use crate::types::msg_field::MsgField;
 use crate::types::msg_variant::{MsgVariant, MsgVariants};
 use crate::utils::SvCasing;
 
@@ -198,7 +199,11 @@ impl EmitMethods for MsgVariant<'_> {
             .iter()
             .map(|field| field.emit_method_field_folded())
             .collect();
-        let arguments = self.as_fields_names();
+        let arguments: Vec<_> = self
+            .fields()
+            .iter()
+            .map(MsgField::emit_into_field)
+            .collect();
         let type_name = self.msg_attr().msg_type().as_accessor_name();
         let name = name.to_case(Case::Snake);
 
@@ -212,6 +217,21 @@ impl EmitMethods for MsgVariant<'_> {
                 }
             },
             MsgType::Query => {
+                let page_iter = self.page_item().map(|page_item| {
+                    let fields = self.fields_without_pagination();
+                    let params = fields.iter().map(|field| field.emit_method_field_folded());
+                    let owned_fields = fields.iter().map(MsgField::emit_to_owned);
+                    let arguments = fields.iter().map(MsgField::emit_owned_arg);
+                    let iter_name = format_ident!("{}_iter", name);
+
+                    quote! {
+                        fn #iter_name (&self, #(#params,)* ) -> #sylvia ::pagination::PageIter<'_, #page_item, #error_type> {
+                            #(#owned_fields)*
+                            #sylvia ::pagination::PageIter::new(move |start_after| self.#name( #(#arguments,)* start_after, None))
+                        }
+                    }
+                });
+
                 quote! {
                     fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type> {
                         let msg = #api :: #type_name :: #name ( #(#arguments),* );
@@ -221,6 +241,8 @@ impl EmitMethods for MsgVariant<'_> {
                             .query_wasm_smart(self.contract_addr.clone(), &msg)
                             .map_err(Into::into)
                     }
+
+                    #page_iter
                 }
             }
             MsgType::Sudo => quote! {
@@ -264,13 +286,31 @@ impl EmitMethods for MsgVariant<'_> {
         let type_name = self.msg_attr().msg_type().as_accessor_name();
         let name = name.to_case(Case::Snake);
 
+        let funds_doc = self.emit_funds_doc();
+
         match self.msg_attr().msg_type() {
             MsgType::Exec => quote! {
+                #funds_doc
                 fn #name (&self, #(#params,)* ) -> #sylvia ::multitest::ExecProxy::< #error_type, #api:: #type_name, MtApp, #custom_msg>;
             },
-            MsgType::Query => quote! {
-                fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type>;
-            },
+            MsgType::Query => {
+                let page_iter = self.page_item().map(|page_item| {
+                    let params = self
+                        .fields_without_pagination()
+                        .iter()
+                        .map(|field| field.emit_method_field_folded());
+                    let iter_name = format_ident!("{}_iter", name);
+
+                    quote! {
+                        fn #iter_name (&self, #(#params,)* ) -> #sylvia ::pagination::PageIter<'_, #page_item, #error_type>;
+                    }
+                });
+
+                quote! {
+                    fn #name (&self, #(#params,)* ) -> Result<#return_type, #error_type>;
+                    #page_iter
+                }
+            }
             MsgType::Sudo => quote! {
                 fn #name (&self, #(#params,)* ) -> Result< #sylvia ::cw_multi_test::AppResponse, #error_type>;
             },
@@ -2,9 +2,10 @@
 //!
 //! Please refer to the [Sylvia-book](https://cosmwasm.github.io/sylvia-book/index.html) on how to use these macros.
 
-use crate::parser::EntryPointArgs;
+use crate::parser::{EntryPointArgs, EventArgs, InterfaceArgs};
 use contract::ContractInput;
 use entry_points::EntryPointInput;
+use event::EventInput;
 use fold::StripInput;
 use interface::InterfaceInput;
 use proc_macro::TokenStream;
@@ -13,10 +14,11 @@ use proc_macro_error::proc_macro_error;
 use quote::quote;
 use syn::fold::Fold;
 use syn::spanned::Spanned;
-use syn::{parse2, parse_quote, ItemImpl, ItemTrait, Path};
+use syn::{parse2, parse_quote, ItemImpl, ItemStruct, ItemTrait, Path};
 
 mod contract;
 mod entry_points;
+mod event;
 mod fold;
 mod interface;
 mod parser;
@@ -111,6 +113,26 @@ pub(crate) fn crate_module() -> Path {
 ///
 /// Similarly for `Query` and `Sudo` enum messages.
 ///
+/// ## Arguments
+///
+/// * `version = "1.2.0"` - version of the interface. Defaults to the version of the crate
+///   defining the interface.
+///
+/// The `sv` module of the interface contains the `INTERFACE_NAME`, `INTERFACE_VERSION` and
+/// the `INTERFACE_ID` built from the crate name, as used in the paths, and the interface name,
+/// e.g. `cw20_minting::Cw20Minting` for the interface defined in the `cw20-minting` package. The ID stays the same between the versions
+/// of the interface.
+///
+/// ```rust
+/// # use sylvia::cw_std::StdError;
+/// #
+/// ##[sylvia::interface(version = "1.2.0")]
+/// pub trait SvInterface {
+///    type Error: From<StdError>;
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ## Associated types
 ///
 /// Generics are not supported by the `interface` macro and won't be. Instead, you can define
@@ -144,6 +166,35 @@ pub(crate) fn crate_module() -> Path {
 /// If however you want to restrict the interface to use specific custom message and query types,
 /// you can do so using `#[sv::custom(msg=..., query=...)]` attribute explained below.
 ///
+/// ## Default implementations
+///
+/// Message methods can provide default implementations. Methods without the `sv::msg` attribute
+/// are not part of the generated messages, so they can be used as required accessors to
+/// the contract state. The contract implementing the interface gets the working handlers
+/// by implementing just the accessors and can still override any of the defaults.
+///
+/// ```rust
+/// # use cw_storage_plus::Map;
+/// # use sylvia::cw_std::{Addr, Empty, Response, StdError};
+/// # use sylvia::ctx::ExecCtx;
+/// #
+/// ##[sylvia::interface]
+/// ##[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+/// pub trait Whitelist {
+///    type Error: From<StdError>;
+///
+///    fn admins(&self) -> &Map<&'static Addr, Empty>;
+///
+///    #[sv::msg(exec)]
+///    fn add_admin(&self, ctx: ExecCtx, admin: String) -> Result<Response, Self::Error> {
+///        let admin = ctx.deps.api.addr_validate(&admin)?;
+///        self.admins().save(ctx.deps.storage, &admin, &Empty {})?;
+///        Ok(Response::new())
+///    }
+/// }
+/// # fn main() {}
+/// ```
+///
 /// ## Attributes
 ///
 /// `Interface` macro supports multiple attributes to customize the behavior of generated messages.
@@ -153,9 +204,20 @@ pub(crate) fn crate_module() -> Path {
 /// Messages structures are generated basing on interface trait method attributed with
 /// `#[sv::msg(msg_type)]`. Msg attribute takes as its first argument type of message it is
 /// supposed to handle:
-///   * `exec` - execute message variant
-///   * `query` - query message variant
+///   * `exec` - execute message variant. The funds sent with the message can be restricted with
+///     the `payable = ["denom", ...]` or `nonpayable` arguments, as in the contract methods.
+///     The `Error` of such interface has to implement `From<cw_utils::PaymentError>`.
+///   * `query` - query message variant. Queries marked with `#[sv::msg(query, paginated)]` get
+///     the `start_after` and `limit` fields and have to return the `sylvia::pagination::Page`.
 ///   * `sudo` - sudo message variant
+///   * `ibc_*` - IBC handlers, routed to the interface by the contracts implementing it with
+///     `#[sv::messages(interface: ibc)]`. Packet handlers can be restricted to a single packet
+///     type with `#[sv::msg(ibc_packet_receive, packet = PacketType)]`.
+///   * `reply` - reply handler, routed to the interface by the contracts implementing it with
+///     `#[sv::messages(interface: reply)]`. Supports the same parameters as the contract
+///     reply handlers. Generated reply ids are prefixed with the `REPLY_NAMESPACE` derived from
+///     the `INTERFACE_ID`, so they don't clash with the contract reply ids. Contracts verify
+///     at compile time that the namespaces of their `reply` interfaces are distinct.
 ///
 /// In the case of a query, it is possible to pass a second argument which is its `ResponseType`.
 /// This is required in case of aliased results wrapping their `ResponseType` to properly
@@ -239,11 +301,12 @@ pub fn interface(attr: TokenStream, item: TokenStream) -> TokenStream {
     interface_impl(attr.into(), item.into()).into()
 }
 
-fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
-    fn inner(item: TokenStream2) -> syn::Result<TokenStream2> {
+fn interface_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
+    fn inner(attr: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
         let input: ItemTrait = parse2(item)?;
+        let args = InterfaceArgs::new(&attr)?;
 
-        let expanded = InterfaceInput::new(&input).process();
+        let expanded = InterfaceInput::new(&input, args).process();
         let input = StripInput.fold_item_trait(input);
 
         Ok(quote! {
@@ -253,7 +316,7 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
         })
     }
 
-    inner(item).unwrap_or_else(syn::Error::into_compile_error)
+    inner(attr, item).unwrap_or_else(syn::Error::into_compile_error)
 }
 
 /// Procedural macro generating messages from contract impl block.
@@ -425,11 +488,49 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// `#[sv::msg(msg_type)]`. Msg attribute takes as its first argument type of message it is
 /// supposed to handle:
 /// * `instantiate` - instantiation message handler. There should be always exactly one
-/// * `exec` - execute message variant
-/// * `query` - query message variant
-/// * `migrate` - migrate message variant
-/// * `reply` - reply message variant
+/// * `exec` - execute message variant. Methods marked with `#[sv::msg(exec, cw20_receive)]` handle
+///   the message sent by the cw20 token contract. They are gathered in the `ReceiveMsg` enum
+///   and the `ExecMsg` gets the `Receive(sylvia::types::Cw20ReceiveMsg)` variant instead.
+///   Dispatch deserializes the wrapped `ReceiveMsg` and calls the method with
+///   the `sylvia::ctx::Cw20ReceiveCtx` carrying the validated `sender` and the received `amount`.
+///
+///   The `exec` and `instantiate` methods declare the funds they accept with the `payable`
+///   and `nonpayable` arguments, verified by the dispatch before the method is called:
+///     * `#[sv::msg(exec, nonpayable)]` - the message rejects any funds,
+///     * `#[sv::msg(exec, payable)]` - the message accepts funds in any denom,
+///     * `#[sv::msg(exec, payable = ["uatom", "ujuno"])]` - the message accepts funds only
+///       in the listed denoms. Sending no funds is allowed, the handler can require them
+///       with the `cw_utils::must_pay`.
+///
+///   Failed checks return the `cw_utils::PaymentError`, so the contract error has to implement
+///   `From<PaymentError>`. The requirement is described in the schema of the message and in the documentation
+///   of the multitest proxy. The `nonpayable` contract feature makes the `exec` messages
+///   not marked as `payable` reject funds (see `sv::features` below).
+/// * `query` - query message variant. Methods marked with `#[sv::msg(query, paginated)]` get
+///   the `start_after: Option<String>` and `limit: Option<u32>` fields in the message variant,
+///   passed to the method as the `ctx.pagination`. They have to return
+///   the `sylvia::pagination::Page` and the generated query helpers and multitest proxies
+///   get the additional `<query>_iter` methods iterating over all the pages.
+/// * `migrate` - migrate message variant. A contract can define several migrate handlers,
+///   one per migration path, each tagged with the version range it migrates from, i.e.
+///   `#[sv::msg(migrate, from = ">=1.0, <2.0")]`. In such case `MigrateMsg` is generated as an
+///   enum with a variant per handler and requires the `cw2` feature
+///   (see `sv::features` below). The `from` requirements can't overlap, so every stored
+///   version is migrated by at most one handler.
+/// * `reply` - reply message variant. Reply ids are assigned in the order of the handlers,
+///   starting from `0`. With `#[sv::features(hashed_reply_ids)]` they are derived from the handler
+///   names instead, so they don't change when handlers are reordered or added. The id can be
+///   assigned explicitly with `#[sv::msg(reply, id = 42)]` and has to fit in 32 bits.
+///   Duplicated ids result in a compile error. The generated `sv::REPLY_IDS` table maps every
+///   handler to its reply id.
+///   The payload parameters form the payload type of the handler, exported as the `Payload` of
+///   the `sv::replies` handler type. The `sv::SubMsgMethods` method of the handler takes
+///   the payload parameters, e.g. `.handler(42, "note".to_owned())`, and serializes them into
+///   the payload type.
 /// * `sudo` - sudo message variant
+/// * `ibc_channel_open`, `ibc_channel_connect`, `ibc_channel_close`, `ibc_packet_receive`,
+///   `ibc_packet_ack`, `ibc_packet_timeout` - IBC handlers. At most one per type, taking
+///   the respective `sylvia::ctx::Ibc*Ctx` and `cosmwasm_std::Ibc*Msg`. Requires the `stargate` feature.
 ///
 /// In the case of a query, it is possible to pass a second argument which is its `ResponseType`.
 /// This is required in case of aliased results wrapping their `ResponseType` to properly
@@ -608,6 +709,26 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// # fn main() {}
 /// ```
 ///
+/// Interfaces declaring `#[sv::msg(ibc_*)]` handlers have to be marked with the `ibc`
+/// parameter, i.e. `#[sv::messages(interface: ibc)]`, for the contract to route IBC messages
+/// to them. Interfaces are tried in the order of the attributes before the contract handlers.
+/// Packet handlers declared with the `packet = PacketType` parameter are called only if
+/// the packet data deserializes into the `PacketType`. The channel handlers and the packet handlers
+/// without the `packet` type accept every message, so they can't be followed by a handler
+/// of the same type in the interfaces listed later or in the contract. Such shadowing, as well as
+/// marking an interface without any `ibc_*` handler with `ibc`, is a compile time error.
+///
+/// Similarly, interfaces declaring `#[sv::msg(reply)]` handlers have to be marked with the `reply`
+/// parameter, i.e. `#[sv::messages(interface: reply)]`. Replies with ids from the interface
+/// `REPLY_NAMESPACE` are forwarded to the interface handlers. Parameters can be combined,
+/// e.g. `#[sv::messages(interface: custom(msg), ibc, reply)]`.
+///
+/// The `version` parameter requires the `INTERFACE_VERSION` of the interface to match
+/// the [semver requirement](https://docs.rs/semver/latest/semver/struct.VersionReq.html),
+/// i.e. `#[sv::messages(interface: version = ">=1.2, <2.0")]`. The requirement is checked
+/// at compile time, so updating the interface crate to an incompatible version fails the build
+/// of the contract.
+///
 /// ### `sv::msg_attr(msg_type, {...})`
 ///
 /// This attribute can be used for the whole `impl Contract {}` block and
@@ -627,7 +748,342 @@ fn interface_impl(_attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// Enables additional features for the contract. Allows user to use features that
 /// are considered breaking before the major release.
 ///
-/// No currently supported features.
+/// Supported features:
+///
+/// * `cw2` - tracks the contract version in the [cw2](https://docs.rs/cw2) format.
+///   Requires the `cw2` feature of the `sylvia` crate. The contract name and version are taken
+///   from the `CARGO_PKG_NAME` and `CARGO_PKG_VERSION` of the contract crate.
+///     * `InstantiateMsg::dispatch` records the initial version.
+///     * `MigrateMsg::dispatch` rejects the migration if the stored contract name differs or
+///       the stored version is newer than the current one. The stored version is updated after
+///       the migrate handler succeeds.
+///     * `#[sv::msg(migrate, from = ">=1.2, <2.0")]` additionally requires the stored version
+///       to match the [semver requirement](https://docs.rs/semver/latest/semver/struct.VersionReq.html).
+/// * `introspection` - implements the [Introspection](https://docs.rs/sylvia/latest/sylvia/introspection/trait.Introspection.html)
+///   interface on the contract and registers it next to the `sv::messages` interfaces.
+///   The `supported_interfaces` query lists the name, version and messages of every interface
+///   and the `supports_interface` query checks a single interface by its name.
+/// * `nonpayable` - the contract `exec` messages not marked as `payable` reject funds.
+///   Messages of the implemented interfaces keep their own requirements.
+/// * `hashed_reply_ids` - derives the reply ids from the handler names instead of their
+///   positions. Changes the ids of existing handlers, so it shouldn't be enabled while
+///   submessages sent with the positional ids may still be replied to.
+///
+/// ```rust
+/// # use sylvia::contract;
+/// # use sylvia::ctx::{InstantiateCtx, MigrateCtx};
+/// # use sylvia::cw_std::{Response, StdResult};
+/// #
+/// # pub struct Contract;
+/// #
+/// #[contract]
+/// #[sv::features(cw2)]
+/// impl Contract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(migrate, from = ">=1.2, <2.0")]
+///     fn migrate(&self, ctx: MigrateCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### `sv::access(...)`
+///
+/// Used next to the `exec` method. Verifies the sender in the `ExecMsg::dispatch` before
+/// the method is called. If the verification fails, the [Unauthorized](https://docs.rs/sylvia/latest/sylvia/access/struct.Unauthorized.html)
+/// error is returned, converted into the contract error type.
+///
+/// * `owner` - the sender has to be the owner returned by the
+///     [Owned](https://docs.rs/sylvia/latest/sylvia/access/trait.Owned.html) trait implemented on the contract.
+/// * `check = path::to::function` - the sender is verified by the function with
+///     the `fn(&Contract, &ExecCtx<Q>) -> Result<bool, E>` signature.
+///     `Self::method` calls the method of the contract.
+///
+/// Multiple checks can be provided and all of them have to pass.
+///
+/// ```rust
+/// # use sylvia::access::{Owned, Unauthorized};
+/// # use sylvia::contract;
+/// # use sylvia::ctx::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{Addr, Response, StdResult, Storage};
+/// #
+/// # pub struct Contract;
+/// #
+/// #[contract]
+/// impl Contract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     fn is_admin(&self, ctx: &ExecCtx) -> StdResult<bool> {
+///         Ok(ctx.info.sender.as_str() == "admin")
+///     }
+///
+///     #[sv::msg(exec)]
+///     #[sv::access(owner)]
+///     fn owner_only(&self, ctx: ExecCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+///
+///     #[sv::msg(exec)]
+///     #[sv::access(check = Self::is_admin)]
+///     fn admin_only(&self, ctx: ExecCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+///
+/// impl Owned for Contract {
+///     fn owner(&self, storage: &dyn Storage) -> StdResult<Option<Addr>> {
+///         Ok(Some(Addr::unchecked("owner")))
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### `sv::pausable`
+///
+/// Used next to the `exec` method of the contract. The `ExecMsg::dispatch` rejects the message
+/// with the [PauseError::Paused](https://docs.rs/sylvia/latest/sylvia/pausable/enum.PauseError.html)
+/// error, converted into the contract error type, while the contract is paused with the
+/// [Pausable](https://docs.rs/sylvia/latest/sylvia/pausable/trait.Pausable.html) interface.
+/// Methods without the attribute are exempt.
+///
+/// ```rust
+/// # use sylvia::contract;
+/// # use sylvia::ctx::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{Response, StdError, StdResult};
+/// # use sylvia::pausable::Pausable;
+/// #
+/// # pub struct Contract;
+/// #
+/// #[contract]
+/// #[sv::messages(sylvia::pausable)]
+/// impl Contract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(exec)]
+///     #[sv::pausable]
+///     fn deposit(&self, ctx: ExecCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+///
+/// impl Pausable for Contract {
+///     type Error = StdError;
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### `sv::validate(...)`
+///
+/// Used next to the message method argument. Validates the field in the `dispatch` before
+/// the method is called. Failures are converted into the contract error type.
+///
+/// * `addr` - the field is sent as `String` and validated with the `Api::addr_validate`.
+///     The method receives the validated `Addr`. The `Addr`, `Option<Addr>` and `Vec<Addr>`
+///     arguments are validated even without the attribute, while the generated
+///     query helpers, executors and multitest proxies keep taking them by reference.
+///     Only the `cosmwasm_std::Addr` is recognized, written as `Addr`, `cosmwasm_std::Addr`
+///     or `sylvia::cw_std::Addr`.
+/// * `with = path::to::function` - the field is verified by the function with
+///     the `fn(&T) -> Result<(), E>` signature, where `E` converts into the contract error.
+/// * `range = start..end` - the field has to be contained in the range.
+///
+/// Validations are supported for the `instantiate`, `exec`, `query`, `sudo` and `migrate`
+/// messages of both contracts and interfaces.
+///
+/// ```rust
+/// # use sylvia::contract;
+/// # use sylvia::ctx::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{Addr, Response, StdError, StdResult};
+/// #
+/// # pub struct Contract;
+/// #
+/// fn validate_symbol(symbol: &str) -> StdResult<()> {
+///     match symbol.chars().all(|c| c.is_ascii_uppercase()) {
+///         true => Ok(()),
+///         false => Err(StdError::generic_err("Invalid symbol")),
+///     }
+/// }
+///
+/// #[contract]
+/// impl Contract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+///     #[sv::msg(instantiate)]
+///     fn instantiate(
+///         &self,
+///         ctx: InstantiateCtx,
+///         #[sv::validate(addr)] admin: Addr,
+///         #[sv::validate(with = validate_symbol)] symbol: String,
+///         #[sv::validate(range = 0..=18)] decimals: u8,
+///     ) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### `sv::extract`
+///
+/// Used next to the `exec` method argument of the contract. The argument is not a part of
+/// the message. Instead, the `dispatch` fills it with the
+/// [FromExecCtx](https://docs.rs/sylvia/latest/sylvia/extract/trait.FromExecCtx.html)
+/// implementation of its type before the method is called. The extractor errors are converted
+/// into the contract error type.
+///
+/// ```rust
+/// # use sylvia::contract;
+/// # use sylvia::ctx::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{Addr, CustomQuery, Response, StdError, StdResult};
+/// # use sylvia::extract::FromExecCtx;
+/// #
+/// # pub struct Contract;
+/// #
+/// pub struct Sender(Addr);
+///
+/// impl<Q: CustomQuery> FromExecCtx<Contract, Q> for Sender {
+///     type Error = StdError;
+///
+///     fn from_exec_ctx(_contract: &Contract, ctx: &ExecCtx<Q>) -> StdResult<Self> {
+///         Ok(Sender(ctx.info.sender.clone()))
+///     }
+/// }
+///
+/// #[contract]
+/// impl Contract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(exec)]
+///     fn ping(&self, ctx: ExecCtx, #[sv::extract] sender: Sender) -> StdResult<Response> {
+///         Ok(Response::new().add_attribute("sender", sender.0))
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### `sv::middleware(Type)`
+///
+/// Wraps the dispatch of the `exec`, `sudo` and `migrate` messages, including the interface
+/// messages, in the hooks of the
+/// [Middleware](https://docs.rs/sylvia/latest/sylvia/middleware/trait.Middleware.html)
+/// implementation of the type. The `before_*` hooks receive the name of the message and are
+/// called in the order of the attributes, the `after_*` hooks receive the response and are
+/// called in the reverse order. The hook errors are converted into the contract error type.
+///
+/// ```rust
+/// # use sylvia::contract;
+/// # use sylvia::ctx::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_std::{CustomQuery, Response, StdError, StdResult};
+/// # use sylvia::middleware::Middleware;
+/// #
+/// # pub struct Contract;
+/// #
+/// pub struct Audit;
+///
+/// impl<Q: CustomQuery> Middleware<Contract, Q> for Audit {
+///     type Error = StdError;
+///
+///     fn after_exec(_contract: &Contract, _ctx: &mut ExecCtx<Q>, resp: &mut Response) -> StdResult<()> {
+///         resp.attributes.push(("audited", "true").into());
+///         Ok(())
+///     }
+/// }
+///
+/// #[contract]
+/// #[sv::middleware(Audit)]
+/// impl Contract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(exec)]
+///     fn ping(&self, ctx: ExecCtx) -> StdResult<Response> {
+///         Ok(Response::new())
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### `sv::events(...)`
+///
+/// Lists the types of the events emitted by the contract. The types have to be declared with
+/// the [event] macro. The generated `sv::events` function returns the event types and
+/// the `sv::generate_schema` includes the schemas of the events. The schemas describe
+/// the attributes as strings, with the schema of the stored value as the `contentSchema`.
+///
+/// ```rust
+/// # use sylvia::contract;
+/// # use sylvia::ctx::{ExecCtx, InstantiateCtx};
+/// # use sylvia::cw_schema::cw_serde;
+/// # use sylvia::cw_std::{Response, StdResult};
+/// # use sylvia::events::TypedEvent;
+/// #
+/// # pub struct Contract;
+/// #
+/// #[cw_serde]
+/// #[sylvia::event]
+/// pub struct Pinged {
+///     pub count: u32,
+/// }
+///
+/// #[contract]
+/// #[sv::events(Pinged)]
+/// impl Contract {
+/// #    pub const fn new() -> Self {
+/// #        Self
+/// #    }
+/// #
+/// #    #[sv::msg(instantiate)]
+/// #    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+/// #        Ok(Response::new())
+/// #    }
+/// #
+///     #[sv::msg(exec)]
+///     fn ping(&self, ctx: ExecCtx) -> StdResult<Response> {
+///         Ok(Response::new().add_event(Pinged { count: 1 }.to_event()?))
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_eq!(sv::events(), ["pinged"]);
+/// # }
+/// ```
 ///
 /// ### `sv::payload(raw)`
 ///
@@ -714,6 +1170,7 @@ fn contract_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 
 /// Procedural macro generating cosmwasm entry points from contract impl block.
 /// By default generates `execute`, `instantiate`, `sudo`, `query` entry points.
+/// `migrate`, `reply` and IBC entry points are generated if respective handlers are defined.
 ///
 /// ## Example usage
 /// ```rust
@@ -806,6 +1263,19 @@ fn contract_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
 /// # fn main() {}
 /// ```
 ///
+/// ## Schema
+///
+/// Besides the entry points, the `entry_points` module contains the `schema` function
+/// returning the [ContractSchema](https://docs.rs/sylvia/latest/sylvia/schema/struct.ContractSchema.html)
+/// of the contract with the concrete types provided in the `generics` attribute.
+/// It is meant to be used in the `src/bin/schema.rs` of the contract.
+///
+/// ```rust,ignore
+/// fn main() {
+///     sylvia::schema::write_api(my_contract::contract::entry_points::schema());
+/// }
+/// ```
+///
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn entry_points(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -828,6 +1298,58 @@ fn entry_points_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
     inner(attr, item).unwrap_or_else(syn::Error::into_compile_error)
 }
 
+/// Procedural macro implementing the
+/// [TypedEvent](https://docs.rs/sylvia/latest/sylvia/events/trait.TypedEvent.html)
+/// trait for the struct.
+///
+/// The struct is converted into the `cosmwasm_std::Event` with an attribute per field
+/// and parsed back from it. The type of the event is the snake cased name of the struct
+/// unless provided with the `name` argument.
+///
+/// ## Example usage
+///
+/// ```rust
+/// # use sylvia::cw_schema::cw_serde;
+/// # use sylvia::cw_std::{Addr, Response, StdResult, Uint128};
+/// # use sylvia::events::TypedEvent;
+/// #
+/// #[cw_serde]
+/// #[sylvia::event(name = "token_transfer")]
+/// pub struct Transfer {
+///     pub recipient: Addr,
+///     pub amount: Uint128,
+/// }
+///
+/// fn transfer(recipient: Addr, amount: Uint128) -> StdResult<Response> {
+///     let event = Transfer { recipient, amount }.to_event()?;
+///     Ok(Response::new().add_event(event))
+/// }
+/// ```
+///
+/// The contract lists the emitted events with the `sv::events(...)` attribute
+/// described in the [contract] macro documentation.
+#[proc_macro_error]
+#[proc_macro_attribute]
+pub fn event(attr: TokenStream, item: TokenStream) -> TokenStream {
+    event_impl(attr.into(), item.into()).into()
+}
+
+fn event_impl(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
+    fn inner(attr: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+        let input: ItemStruct = parse2(item)?;
+        let args = EventArgs::new(&attr)?;
+        let expanded = EventInput::new(&input, args).process();
+
+        Ok(quote! {
+            #input
+
+            #expanded
+        })
+    }
+
+    inner(attr, item).unwrap_or_else(syn::Error::into_compile_error)
+}
+
 #[cfg(test)]
 mod test {
     use std::{env, fs};
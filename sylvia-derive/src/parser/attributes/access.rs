@@ -0,0 +1,132 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{Error, Ident, MetaList, Path, Result, Token};
+
+use crate::crate_module;
+use crate::parser::variant_descs::VariantDesc;
+use crate::parser::MsgType;
+
+/// Single check parsed from `sv::access` attribute.
+#[derive(Clone, Debug)]
+pub enum AccessCheck {
+    /// Sender has to be the owner of the contract returned by `sylvia::access::Owned`.
+    Owner,
+    /// Sender is verified by the user defined function.
+    Check(Path),
+}
+
+impl AccessCheck {
+    /// Emits the check returning `Unauthorized` error from the dispatch if it fails.
+    /// Expects `contract` and `ctx` of `ExecCtx` type to be in scope.
+    pub fn emit(&self, method: &Ident) -> TokenStream {
+        let sylvia = crate_module();
+        let method = method.to_string();
+
+        let is_authorized = match self {
+            AccessCheck::Owner => quote! {
+                #sylvia ::access::is_owner(contract, ctx.deps.storage, &ctx.info.sender)?
+            },
+            AccessCheck::Check(path) => match path.segments.first() {
+                Some(segment) if segment.ident == "Self" && path.segments.len() == 2 => {
+                    let check = &path.segments[1].ident;
+                    quote! { contract. #check (&ctx)? }
+                }
+                _ => quote! { #path (contract, &ctx)? },
+            },
+        };
+
+        quote! {
+            if !#is_authorized {
+                return Err(Into::into(#sylvia ::access::Unauthorized::new(#method, ctx.info.sender.clone())));
+            }
+        }
+    }
+}
+
+/// Type wrapping data parsed from `sv::access` attribute.
+#[derive(Clone, Debug)]
+pub struct AccessAttr {
+    pub checks: Vec<AccessCheck>,
+    pub span: Span,
+}
+
+impl AccessAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        let checks = AccessChecks::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })?;
+
+        if checks.0.is_empty() {
+            emit_error!(
+                attr.span(), "Missing access check.";
+                note = "Expected `owner` or `check = path::to::function`."
+            );
+        }
+
+        Ok(Self {
+            checks: checks.0,
+            span: attr.span(),
+        })
+    }
+}
+
+struct AccessChecks(Vec<AccessCheck>);
+
+impl Parse for AccessChecks {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut checks = vec![];
+
+        while !input.is_empty() {
+            let check: Ident = input.parse()?;
+            match check.to_string().as_str() {
+                "owner" => checks.push(AccessCheck::Owner),
+                "check" => {
+                    let _: Token![=] = input.parse()?;
+                    checks.push(AccessCheck::Check(input.parse()?));
+                }
+                _ => {
+                    return Err(Error::new(
+                        check.span(),
+                        "Invalid access check.\n= note: Expected `owner` or `check = path::to::function`.\n",
+                    ))
+                }
+            }
+
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+
+        Ok(Self(checks))
+    }
+}
+
+/// Verifies that the `sv::access` attribute is used only on the `exec` methods of the contract.
+pub fn validate_access_attrs<'a>(
+    variants: impl Iterator<Item = VariantDesc<'a>>,
+    in_interface: bool,
+) {
+    for variant in variants {
+        let Some(attr) = variant.access_attrs().first() else {
+            continue;
+        };
+
+        if in_interface {
+            emit_error!(
+                attr.span, "The `sv::access` attribute is not supported in interfaces.";
+                note = "Use the attribute on the `exec` methods of the contract."
+            );
+        } else if variant.attr_msg().map(|msg_attr| msg_attr.msg_type()) != Some(MsgType::Exec) {
+            emit_error!(
+                attr.span, "The `sv::access` attribute can be used only on `exec` methods.";
+                note = "Mark the method with `#[sv::msg(exec)]` attribute."
+            );
+        }
+    }
+}
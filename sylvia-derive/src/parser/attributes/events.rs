@@ -0,0 +1,47 @@
+use proc_macro_error::emit_error;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, MetaList, Result, Token, Type};
+
+use crate::parser::attributes::SylviaAttribute;
+
+/// Type wrapping data parsed from `sv::events` attribute.
+#[derive(Debug)]
+pub struct EventsAttr {
+    pub events: Vec<Type>,
+}
+
+impl EventsAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        EventsAttr::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+}
+
+impl Parse for EventsAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let events = Punctuated::<Type, Token![,]>::parse_terminated(input)?;
+        Ok(Self {
+            events: events.into_iter().collect(),
+        })
+    }
+}
+
+/// Verifies that the `sv::events` attribute is not used on the interface.
+/// Events are listed by the contract emitting them.
+pub fn validate_events_attrs_in_interface(attrs: &[Attribute]) {
+    attrs
+        .iter()
+        .filter(|attr| SylviaAttribute::new(attr) == Some(SylviaAttribute::Events))
+        .for_each(|attr| {
+            emit_error!(
+                attr.span(), "The `sv::events` attribute is not supported in interfaces.";
+                note = "Use the attribute on the contract emitting the events."
+            )
+        });
+}
@@ -0,0 +1,70 @@
+use proc_macro_error::emit_error;
+use syn::spanned::Spanned;
+use syn::{Attribute, FnArg, PatType, Signature};
+
+use crate::parser::attributes::SylviaAttribute;
+use crate::parser::variant_descs::VariantDesc;
+use crate::parser::MsgType;
+
+/// Checks if the method parameter is marked with the `sv::extract` attribute.
+pub fn is_extracted(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| SylviaAttribute::new(attr) == Some(SylviaAttribute::Extract))
+}
+
+/// Returns the parameters of the method filled by the `FromExecCtx` extractors
+/// together with their positions among the message parameters.
+pub fn extracted_params(sig: &Signature) -> Vec<(usize, &PatType)> {
+    sig.inputs
+        .iter()
+        .skip(2)
+        .enumerate()
+        .filter_map(|(position, arg)| match arg {
+            FnArg::Typed(item) if is_extracted(&item.attrs) => Some((position, item)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Verifies that the `sv::extract` attribute is used without parameters and only on
+/// the parameters of the `exec` methods of the contract.
+pub fn validate_extract_attrs<'a>(
+    variants: impl Iterator<Item = VariantDesc<'a>>,
+    in_interface: bool,
+) {
+    for variant in variants {
+        let msg_type = variant.attr_msg().map(|msg_attr| msg_attr.msg_type());
+        let extract_attrs: Vec<_> = extracted_params(variant.into_sig())
+            .into_iter()
+            .flat_map(|(_, param)| &param.attrs)
+            .filter(|attr| SylviaAttribute::new(attr) == Some(SylviaAttribute::Extract))
+            .collect();
+
+        extract_attrs
+            .iter()
+            .filter(|attr| attr.meta.require_path_only().is_err())
+            .for_each(|attr| {
+                emit_error!(
+                    attr.span(), "The `sv::extract` attribute doesn't take parameters.";
+                    note = "Expected `#[sv::extract]`."
+                )
+            });
+
+        let Some(attr) = extract_attrs.first() else {
+            continue;
+        };
+
+        if in_interface {
+            emit_error!(
+                attr.span(), "The `sv::extract` attribute is not supported in interfaces.";
+                note = "Use the attribute on the `exec` methods of the contract."
+            );
+        } else if msg_type != Some(MsgType::Exec) {
+            emit_error!(
+                attr.span(), "The `sv::extract` attribute can be used only on `exec` methods.";
+                note = "Mark the method with `#[sv::msg(exec)]` attribute."
+            );
+        }
+    }
+}
@@ -1,10 +1,21 @@
 use proc_macro_error::emit_error;
 use syn::parse::{Parse, ParseStream, Parser};
-use syn::{Error, Ident, MetaList, Result};
+use syn::{Error, Ident, MetaList, Result, Token};
+
+use super::msg::Funds;
 
 /// Type wrapping data parsed from `sv::features` attribute.
 #[derive(Debug, Default)]
-pub struct SylviaFeatures {}
+pub struct SylviaFeatures {
+    /// Tracks the contract version in the `cw2` format on instantiation and migration.
+    pub cw2: bool,
+    /// Implements the `sylvia::introspection::Introspection` interface on the contract.
+    pub introspection: bool,
+    /// Rejects funds sent with the `exec` messages of the contract not marked as `payable`.
+    pub nonpayable: bool,
+    /// Derives the reply ids from the handler names instead of their positions.
+    pub hashed_reply_ids: bool,
+}
 
 impl SylviaFeatures {
     pub fn new(attr: &MetaList) -> Result<Self> {
@@ -15,18 +26,35 @@ impl SylviaFeatures {
                 err
             })
     }
+
+    /// Funds requirement of the contract `exec` messages not marked as `payable` or `nonpayable`.
+    pub fn default_exec_funds(&self) -> Option<Funds> {
+        self.nonpayable.then_some(Funds::Nonpayable)
+    }
 }
 
 impl Parse for SylviaFeatures {
     fn parse(input: ParseStream) -> Result<Self> {
-        let features = Self::default();
+        let mut features = Self::default();
 
-        if !input.is_empty() {
+        while !input.is_empty() {
             let feature: Ident = input.parse()?;
-            return Err(Error::new(
-                feature.span(),
-                "Invalid feature.\n= note: No features supported currently.\n",
-            ));
+            match feature.to_string().as_str() {
+                "cw2" => features.cw2 = true,
+                "introspection" => features.introspection = true,
+                "nonpayable" => features.nonpayable = true,
+                "hashed_reply_ids" => features.hashed_reply_ids = true,
+                _ => {
+                    return Err(Error::new(
+                        feature.span(),
+                        "Invalid feature.\n= note: Supported features: `cw2`, `introspection`, `nonpayable`, `hashed_reply_ids`.\n",
+                    ))
+                }
+            }
+
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
         }
 
         Ok(features)
@@ -1,13 +1,16 @@
 use convert_case::{Case, Casing};
+use proc_macro2::Span;
 use proc_macro_error::emit_warning;
 use syn::fold::Fold;
 use syn::parse::{Parse, ParseStream, Parser};
 use syn::spanned::Spanned;
-use syn::{parenthesized, Error, Ident, MetaList, Path, Result, Token};
+use syn::{parenthesized, parse_quote, Error, Ident, LitStr, MetaList, Path, Result, Token};
 
 use proc_macro_error::emit_error;
 
+use crate::crate_module;
 use crate::fold::StripGenerics;
+use crate::parser::Custom;
 
 /// Type wrapping data parsed from `sv::message` attribute.
 #[derive(Debug)]
@@ -15,6 +18,12 @@ pub struct ContractMessageAttr {
     pub module: Path,
     pub variant: Ident,
     pub customs: Customs,
+    /// Marks if the interface handles IBC messages.
+    pub ibc: bool,
+    /// Marks if the interface handles replies.
+    pub reply: bool,
+    /// Semver requirement the version of the interface has to satisfy.
+    pub version: Option<LitStr>,
 }
 
 impl ContractMessageAttr {
@@ -28,6 +37,29 @@ impl ContractMessageAttr {
     }
 }
 
+impl ContractMessageAttr {
+    /// The `sylvia::introspection` interface implemented on contracts with
+    /// the `introspection` feature enabled.
+    ///
+    /// The interface uses `Empty` in place of the custom types, so they are mapped
+    /// if the contract defines its own.
+    pub fn introspection(custom: &Custom) -> Self {
+        let sylvia = crate_module();
+
+        Self {
+            module: parse_quote! { #sylvia ::introspection },
+            variant: Ident::new("Introspection", Span::call_site()),
+            customs: Customs {
+                has_msg: custom.msg.is_some(),
+                has_query: custom.query.is_some(),
+            },
+            ibc: false,
+            reply: false,
+            version: None,
+        }
+    }
+}
+
 /// The `Customs` struct is used to represent the presence of `: custom(msg, query)` parameter
 /// within a `sv::message` attribute.
 ///
@@ -39,21 +71,64 @@ pub struct Customs {
     pub has_query: bool,
 }
 
-fn interface_has_custom(content: ParseStream) -> Result<Customs> {
-    let mut customs = Customs {
-        has_msg: false,
-        has_query: false,
+/// Parameters following `:` in the `sv::messages` attribute.
+struct InterfaceParams {
+    customs: Customs,
+    ibc: bool,
+    reply: bool,
+    version: Option<LitStr>,
+}
+
+fn interface_params(content: ParseStream) -> Result<InterfaceParams> {
+    let mut params = InterfaceParams {
+        customs: Customs {
+            has_msg: false,
+            has_query: false,
+        },
+        ibc: false,
+        reply: false,
+        version: None,
     };
 
     if !content.peek(Token![:]) {
-        return Ok(customs);
+        return Ok(params);
     }
 
     let _: Token![:] = content.parse()?;
-    let attr: Ident = content.parse()?;
-    if attr != "custom" {
-        return Ok(customs);
+    loop {
+        let attr: Ident = content.parse()?;
+        if attr == "ibc" {
+            params.ibc = true;
+        } else if attr == "reply" {
+            params.reply = true;
+        } else if attr == "custom" {
+            params.customs = interface_has_custom(content)?;
+        } else if attr == "version" {
+            let _: Token![=] = content.parse()?;
+            let version: LitStr = content.parse()?;
+            if let Err(err) = semver::VersionReq::parse(&version.value()) {
+                return Err(Error::new(
+                    version.span(),
+                    format!("Invalid version requirement: {err}."),
+                ));
+            }
+            params.version = Some(version);
+        } else {
+            return Ok(params);
+        }
+
+        if !content.peek(Token![,]) {
+            return Ok(params);
+        }
+        let _: Token![,] = content.parse()?;
     }
+}
+
+fn interface_has_custom(content: ParseStream) -> Result<Customs> {
+    let mut customs = Customs {
+        has_msg: false,
+        has_query: false,
+    };
 
     let custom_content;
     parenthesized!(custom_content in content);
@@ -105,17 +180,25 @@ impl Parse for ContractMessageAttr {
         } else {
             Ident::new("", module.span())
         };
-        let customs = interface_has_custom(input)?;
+        let InterfaceParams {
+            customs,
+            ibc,
+            reply,
+            version,
+        } = interface_params(input)?;
         if !input.is_empty() {
             return Err(Error::new(input.span(),
                 "Unexpected tokens inside `sv::messages` attribtue.\n
-  = note: Maximal supported form of attribute: `#[sv::messages(interface::path as InterfaceName: custom(msg, query))]`.\n"
+  = note: Maximal supported form of attribute: `#[sv::messages(interface::path as InterfaceName: custom(msg, query), ibc, reply, version = \"...\")]`.\n"
             ));
         }
         Ok(Self {
             module,
             variant,
             customs,
+            ibc,
+            reply,
+            version,
         })
     }
 }
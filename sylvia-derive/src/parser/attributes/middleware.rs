@@ -0,0 +1,105 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::quote;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{Attribute, MetaList, Result, Type};
+
+use crate::crate_module;
+use crate::parser::attributes::SylviaAttribute;
+use crate::parser::{Custom, MsgType};
+
+/// Type wrapping data parsed from `sv::middleware` attribute.
+#[derive(Debug)]
+pub struct MiddlewareAttr {
+    pub middleware: Type,
+}
+
+impl MiddlewareAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        MiddlewareAttr::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })
+    }
+}
+
+impl Parse for MiddlewareAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse().map(|middleware| Self { middleware })
+    }
+}
+
+/// Verifies that the `sv::middleware` attribute is not used on the interface.
+/// Middlewares of the contract wrap the dispatch of the interface messages.
+pub fn validate_middleware_attrs_in_interface(attrs: &[Attribute]) {
+    attrs
+        .iter()
+        .filter(|attr| SylviaAttribute::new(attr) == Some(SylviaAttribute::Middleware))
+        .for_each(|attr| {
+            emit_error!(
+                attr.span(), "The `sv::middleware` attribute is not supported in interfaces.";
+                note = "Use the attribute on the contract implementing the interface."
+            )
+        });
+}
+
+/// Checks if the dispatch of the message type is wrapped in the middleware hooks.
+pub fn is_hooked(middlewares: &[MiddlewareAttr], msg_ty: MsgType) -> bool {
+    !middlewares.is_empty() && matches!(msg_ty, MsgType::Exec | MsgType::Sudo | MsgType::Migrate)
+}
+
+/// Emits the body of `dispatch` calling the `before_*` hooks, the `dispatch_without_middleware`
+/// and the `after_*` hooks in the reverse order.
+/// Expects `self`, `contract` and `ctx` tuple to be in scope.
+pub fn emit_middleware_dispatch(
+    middlewares: &[MiddlewareAttr],
+    msg_ty: MsgType,
+    contract: &Type,
+    custom: &Custom,
+) -> TokenStream {
+    let sylvia = crate_module();
+    let query_type = custom.query_or_default();
+    let msg_type = custom.msg_or_default();
+
+    let (before, after, ctx_type, ctx_values) = match msg_ty {
+        MsgType::Exec => (
+            quote! { before_exec },
+            quote! { after_exec },
+            quote! { #sylvia ::ctx::ExecCtx },
+            quote! { (ctx.deps.branch(), ctx.env.clone(), ctx.info.clone()) },
+        ),
+        MsgType::Sudo => (
+            quote! { before_sudo },
+            quote! { after_sudo },
+            quote! { #sylvia ::ctx::SudoCtx },
+            quote! { (ctx.deps.branch(), ctx.env.clone()) },
+        ),
+        _ => (
+            quote! { before_migrate },
+            quote! { after_migrate },
+            quote! { #sylvia ::ctx::MigrateCtx },
+            quote! { (ctx.deps.branch(), ctx.env.clone()) },
+        ),
+    };
+
+    let middlewares: Vec<_> = middlewares
+        .iter()
+        .map(|attr| {
+            let middleware = &attr.middleware;
+            quote! { <#middleware as #sylvia ::middleware::Middleware<#contract, #query_type, #msg_type>> }
+        })
+        .collect();
+    let reversed_middlewares = middlewares.iter().rev();
+
+    quote! {
+        let msg_name = self.msg_name();
+        let mut ctx: #ctx_type < #query_type > = Into::into(ctx);
+        #( #middlewares :: #before (contract, &mut ctx, msg_name)?; )*
+        let mut resp = self.dispatch_without_middleware(contract, #ctx_values)?;
+        #( #reversed_middlewares :: #after (contract, &mut ctx, &mut resp)?; )*
+        Ok(resp)
+    }
+}
@@ -1,22 +1,32 @@
 //! Module defining parsing of Sylvia attributes.
 //! Every Sylvia attribute should be prefixed with `sv::`
 
+use access::AccessAttr;
 use data::DataFieldParams;
+use events::EventsAttr;
 use features::SylviaFeatures;
+use middleware::MiddlewareAttr;
+use pausable::PausableAttr;
 use payload::PayloadFieldParam;
 use proc_macro_error::emit_error;
 use syn::spanned::Spanned;
 use syn::{Attribute, MetaList, PathSegment};
 
+pub mod access;
 pub mod attr;
 pub mod custom;
 pub mod data;
 pub mod error;
+pub mod events;
+pub mod extract;
 pub mod features;
 pub mod messages;
+pub mod middleware;
 pub mod msg;
 pub mod override_entry_point;
+pub mod pausable;
 pub mod payload;
+pub mod validate;
 
 pub use attr::{MsgAttrForwarding, VariantAttrForwarding};
 pub use custom::Custom;
@@ -24,6 +34,7 @@ pub use error::ContractErrorAttr;
 pub use messages::{ContractMessageAttr, Customs};
 pub use msg::{MsgAttr, MsgType};
 pub use override_entry_point::{FilteredOverrideEntryPoints, OverrideEntryPoint};
+pub use validate::{ValidateAttr, Validation};
 
 /// This struct represents all possible attributes that
 /// are parsed and utilized by sylvia.
@@ -39,6 +50,12 @@ pub enum SylviaAttribute {
     Payload,
     Data,
     Features,
+    Access,
+    Validate,
+    Extract,
+    Middleware,
+    Pausable,
+    Events,
 }
 
 impl SylviaAttribute {
@@ -63,6 +80,12 @@ impl SylviaAttribute {
             "payload" => Some(Self::Payload),
             "data" => Some(Self::Data),
             "features" => Some(Self::Features),
+            "access" => Some(Self::Access),
+            "validate" => Some(Self::Validate),
+            "extract" => Some(Self::Extract),
+            "middleware" => Some(Self::Middleware),
+            "pausable" => Some(Self::Pausable),
+            "events" => Some(Self::Events),
             _ => None,
         }
     }
@@ -82,6 +105,11 @@ pub struct ParsedSylviaAttributes {
     pub sv_features: SylviaFeatures,
     pub data: Option<DataFieldParams>,
     pub payload: Option<PayloadFieldParam>,
+    pub access_attrs: Vec<AccessAttr>,
+    pub validate_attrs: Vec<ValidateAttr>,
+    pub middleware_attrs: Vec<MiddlewareAttr>,
+    pub pausable_attr: Option<PausableAttr>,
+    pub events_attrs: Vec<EventsAttr>,
 }
 
 impl ParsedSylviaAttributes {
@@ -101,6 +129,16 @@ impl ParsedSylviaAttributes {
                     attr.span(), "Missing parameters for `sv::payload`";
                     note = "Expected `#[sv::payload(raw)]`"
                 );
+            } else if sylvia_attr == Some(SylviaAttribute::Pausable) {
+                // The `sv::pausable` attribute is used without parameters.
+                result.pausable_attr = Some(PausableAttr { span: attr.span() });
+            } else if sylvia_attr == Some(SylviaAttribute::Extract) {
+                // The `sv::extract` attribute is used without parameters.
+            } else if sylvia_attr == Some(SylviaAttribute::Validate) {
+                emit_error!(
+                    attr.span(), "Missing parameters for `sv::validate`";
+                    note = "Expected `addr`, `with = path::to::function` or `range = start..end`."
+                );
             }
         }
 
@@ -197,6 +235,34 @@ impl ParsedSylviaAttributes {
                     self.sv_features = features;
                 }
             }
+            SylviaAttribute::Access => {
+                if let Ok(access) = AccessAttr::new(attr) {
+                    self.access_attrs.push(access);
+                }
+            }
+            SylviaAttribute::Validate => {
+                if let Ok(validate) = ValidateAttr::new(attr) {
+                    self.validate_attrs.push(validate);
+                }
+            }
+            SylviaAttribute::Middleware => {
+                if let Ok(middleware) = MiddlewareAttr::new(attr) {
+                    self.middleware_attrs.push(middleware);
+                }
+            }
+            SylviaAttribute::Pausable => {
+                emit_error!(
+                    attr.span(), "The `sv::pausable` attribute doesn't take parameters.";
+                    note = "Expected `#[sv::pausable]`."
+                );
+            }
+            SylviaAttribute::Events => {
+                if let Ok(events) = EventsAttr::new(attr) {
+                    self.events_attrs.push(events);
+                }
+            }
+            // Verified by the `validate_extract_attrs`.
+            SylviaAttribute::Extract => (),
         }
     }
 }
@@ -1,6 +1,10 @@
+use proc_macro2::TokenStream;
 use proc_macro_error::emit_error;
+use quote::quote;
 use syn::parse::{Error, Parse, ParseStream, Parser};
-use syn::{bracketed, Ident, MetaList, Result, Token};
+use syn::{bracketed, Ident, LitInt, LitStr, MetaList, Result, Token, Type};
+
+use crate::crate_module;
 
 /// Supported message types.
 /// Representation of the first parameter in `#[sv::msg(..)] attribute.
@@ -12,6 +16,14 @@ pub enum MsgType {
     Migrate,
     Reply,
     Sudo,
+    IbcChannelOpen,
+    IbcChannelConnect,
+    IbcChannelClose,
+    IbcPacketReceive,
+    IbcPacketAck,
+    IbcPacketTimeout,
+    /// `exec` handler of the message wrapped in the cw20 `Receive` envelope.
+    Cw20Receive,
 }
 
 /// ArgumentParser holds `resp` parameter parsed from `sv::msg` attribute.
@@ -20,6 +32,13 @@ struct ArgumentParser {
     pub query_resp_type: Option<Ident>,
     pub reply_handlers: Vec<Ident>,
     pub reply_on: Option<ReplyOn>,
+    pub packet: Option<Type>,
+    pub reply_id: Option<u64>,
+    pub migrate_from: Option<LitStr>,
+    pub cw20_receive: Option<Ident>,
+    pub paginated: Option<Ident>,
+    pub payable: Option<(Ident, Vec<LitStr>)>,
+    pub nonpayable: Option<Ident>,
 }
 
 impl Parse for ArgumentParser {
@@ -55,10 +74,59 @@ impl Parse for ArgumentParser {
                     let reply_on = ReplyOn::new(reply_on)?;
                     result.reply_on = Some(reply_on);
                 }
+                "packet" => {
+                    let _: Token![=] = input.parse()?;
+                    let packet: Type = input.parse()?;
+                    result.packet = Some(packet);
+                }
+                "id" => {
+                    let _: Token![=] = input.parse()?;
+                    let reply_id: LitInt = input.parse()?;
+                    result.reply_id = Some(reply_id.base10_parse()?);
+                }
+                "from" => {
+                    let _: Token![=] = input.parse()?;
+                    let migrate_from: LitStr = input.parse()?;
+                    if let Err(err) = semver::VersionReq::parse(&migrate_from.value()) {
+                        return Err(Error::new(
+                            migrate_from.span(),
+                            format!("Invalid version requirement: {err}."),
+                        ));
+                    }
+                    result.migrate_from = Some(migrate_from);
+                }
+                "cw20_receive" => {
+                    result.cw20_receive = Some(arg_type);
+                }
+                "paginated" => {
+                    result.paginated = Some(arg_type);
+                }
+                "payable" => {
+                    let mut denoms = vec![];
+                    if input.peek(Token![=]) {
+                        let _: Token![=] = input.parse()?;
+                        let denoms_content;
+                        let brackets = bracketed!(denoms_content in input);
+                        denoms = denoms_content
+                            .parse_terminated(<LitStr as Parse>::parse, Token![,])?
+                            .into_iter()
+                            .collect();
+                        if denoms.is_empty() {
+                            return Err(Error::new(
+                                brackets.span.join(),
+                                "Expected at least one denom, i.e. `payable = [\"uatom\"]`.",
+                            ));
+                        }
+                    }
+                    result.payable = Some((arg_type, denoms));
+                }
+                "nonpayable" => {
+                    result.nonpayable = Some(arg_type);
+                }
                 _ => {
                     return Err(Error::new(
                         arg_type.span(),
-                        "Invalid argument type, expected `resp`, `handlers`, `reply_on` or no argument.",
+                        "Invalid argument type, expected `resp`, `handlers`, `reply_on`, `packet`, `id`, `from`, `cw20_receive`, `paginated`, `payable`, `nonpayable` or no argument.",
                     ))
                 }
             }
@@ -109,6 +177,54 @@ impl ReplyOn {
     }
 }
 
+/// Funds accepted by the `exec` and `instantiate` messages.
+/// Representation of the `payable` and `nonpayable` parameters in `#[sv::msg(...)]` attribute.
+#[derive(Debug, Clone)]
+pub enum Funds {
+    /// Message rejects any funds sent with it.
+    Nonpayable,
+    /// Message accepts funds only in the listed denoms or in any denom if the list is empty.
+    Payable(Vec<LitStr>),
+}
+
+impl Funds {
+    /// Emits the check of the funds sent with the message.
+    /// Expects the `ctx` tuple with the `MessageInfo` as the third element to be in scope.
+    pub fn emit_check(&self) -> TokenStream {
+        let sylvia = crate_module();
+
+        match self {
+            Funds::Nonpayable => quote! {
+                #sylvia ::cw_utils::nonpayable(&ctx.2)?;
+            },
+            Funds::Payable(denoms) if denoms.is_empty() => quote! {},
+            Funds::Payable(denoms) => quote! {
+                #sylvia ::funds::payable_in(&ctx.2, &[#(#denoms),*])?;
+            },
+        }
+    }
+
+    /// Describes the requirement in the documentation of the message and the multitest proxy.
+    pub fn description(&self) -> String {
+        match self {
+            Funds::Nonpayable => "Nonpayable: the message rejects any funds.".to_owned(),
+            Funds::Payable(denoms) if denoms.is_empty() => {
+                "Payable: the message accepts funds in any denom.".to_owned()
+            }
+            Funds::Payable(denoms) => {
+                let denoms: Vec<_> = denoms
+                    .iter()
+                    .map(|denom| format!("`{}`", denom.value()))
+                    .collect();
+                format!(
+                    "Payable: the message accepts funds only in {}.",
+                    denoms.join(", ")
+                )
+            }
+        }
+    }
+}
+
 /// Parsed representation of `#[sv::msg(...)]` attribute.
 #[derive(Debug, Clone)]
 pub struct MsgAttr {
@@ -116,6 +232,11 @@ pub struct MsgAttr {
     query_resp_type: Option<Ident>,
     reply_handlers: Vec<Ident>,
     reply_on: ReplyOn,
+    packet: Option<Type>,
+    reply_id: Option<u64>,
+    migrate_from: Option<LitStr>,
+    paginated: bool,
+    funds: Option<Funds>,
 }
 
 impl MsgAttr {
@@ -141,6 +262,31 @@ impl MsgAttr {
     pub fn reply_on(&self) -> ReplyOn {
         self.reply_on
     }
+
+    /// Type of the IBC packet data the handler accepts.
+    pub fn packet(&self) -> Option<&Type> {
+        self.packet.as_ref()
+    }
+
+    /// Explicitly assigned reply id.
+    pub fn reply_id(&self) -> Option<u64> {
+        self.reply_id
+    }
+
+    /// Requirement the stored contract version has to match for the migration to be allowed.
+    pub fn migrate_from(&self) -> Option<&LitStr> {
+        self.migrate_from.as_ref()
+    }
+
+    /// Marks if the query is extended with the pagination parameters.
+    pub fn paginated(&self) -> bool {
+        self.paginated
+    }
+
+    /// Funds requirement set with the `payable` or `nonpayable` argument.
+    pub fn funds(&self) -> Option<&Funds> {
+        self.funds.as_ref()
+    }
 }
 
 impl PartialEq<MsgType> for MsgAttr {
@@ -151,19 +297,98 @@ impl PartialEq<MsgType> for MsgAttr {
 
 impl Parse for MsgAttr {
     fn parse(input: ParseStream) -> Result<Self> {
-        let msg_type: Ident = input.parse()?;
-        let msg_type = MsgType::new(&msg_type)?;
+        let msg_type_ident: Ident = input.parse()?;
+        let mut msg_type = MsgType::new(&msg_type_ident)?;
         let ArgumentParser {
             query_resp_type,
             reply_handlers,
             reply_on,
+            packet,
+            reply_id,
+            migrate_from,
+            cw20_receive,
+            paginated,
+            payable,
+            nonpayable,
         } = ArgumentParser::parse(input)?;
 
+        if let Some(cw20_receive) = &cw20_receive {
+            if msg_type != MsgType::Exec {
+                return Err(Error::new(
+                    cw20_receive.span(),
+                    "The `cw20_receive` argument is supported only for `exec` messages.",
+                ));
+            }
+            msg_type = MsgType::Cw20Receive;
+        }
+
+        if let Some(packet) = &packet {
+            if !matches!(
+                msg_type,
+                MsgType::IbcPacketReceive | MsgType::IbcPacketAck | MsgType::IbcPacketTimeout
+            ) {
+                return Err(Error::new_spanned(
+                    packet,
+                    "The `packet` argument is supported only for `ibc_packet_receive`, `ibc_packet_ack` and `ibc_packet_timeout` messages.",
+                ));
+            }
+        }
+
+        if reply_id.is_some() && msg_type != MsgType::Reply {
+            return Err(Error::new(
+                msg_type_ident.span(),
+                "The `id` argument is supported only for `reply` messages.",
+            ));
+        }
+
+        if let Some(migrate_from) = &migrate_from {
+            if msg_type != MsgType::Migrate {
+                return Err(Error::new(
+                    migrate_from.span(),
+                    "The `from` argument is supported only for `migrate` messages.",
+                ));
+            }
+        }
+
+        if let Some(paginated) = &paginated {
+            if msg_type != MsgType::Query {
+                return Err(Error::new(
+                    paginated.span(),
+                    "The `paginated` argument is supported only for `query` messages.",
+                ));
+            }
+        }
+
+        let funds = match (payable, nonpayable) {
+            (Some(_), Some(nonpayable)) => {
+                return Err(Error::new(
+                    nonpayable.span(),
+                    "The `payable` and `nonpayable` arguments are mutually exclusive.",
+                ));
+            }
+            (Some((arg, _)), None) | (None, Some(arg))
+                if !matches!(msg_type, MsgType::Exec | MsgType::Instantiate) =>
+            {
+                return Err(Error::new(
+                    arg.span(),
+                    format!("The `{arg}` argument is supported only for `exec` and `instantiate` messages."),
+                ));
+            }
+            (Some((_, denoms)), None) => Some(Funds::Payable(denoms)),
+            (None, Some(_)) => Some(Funds::Nonpayable),
+            (None, None) => None,
+        };
+
         Ok(Self {
             msg_type,
             query_resp_type,
             reply_handlers,
             reply_on: reply_on.unwrap_or_default(),
+            packet,
+            reply_id,
+            migrate_from,
+            paginated: paginated.is_some(),
+            funds,
         })
     }
 }
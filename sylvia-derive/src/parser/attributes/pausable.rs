@@ -0,0 +1,49 @@
+use proc_macro2::{Span, TokenStream};
+use proc_macro_error::emit_error;
+use quote::quote;
+
+use crate::crate_module;
+use crate::parser::variant_descs::VariantDesc;
+use crate::parser::MsgType;
+
+/// Type wrapping data parsed from `sv::pausable` attribute.
+#[derive(Clone, Debug)]
+pub struct PausableAttr {
+    pub span: Span,
+}
+
+impl PausableAttr {
+    /// Emits the check returning `PauseError::Paused` from the dispatch if the contract is paused.
+    /// Expects `ctx` tuple of the `exec` message to be in scope.
+    pub fn emit_check() -> TokenStream {
+        let sylvia = crate_module();
+
+        quote! {
+            #sylvia ::pausable::assert_not_paused(ctx.0.storage)?;
+        }
+    }
+}
+
+/// Verifies that the `sv::pausable` attribute is used only on the `exec` methods of the contract.
+pub fn validate_pausable_attrs<'a>(
+    variants: impl Iterator<Item = VariantDesc<'a>>,
+    in_interface: bool,
+) {
+    for variant in variants {
+        let Some(attr) = variant.pausable_attr() else {
+            continue;
+        };
+
+        if in_interface {
+            emit_error!(
+                attr.span, "The `sv::pausable` attribute is not supported in interfaces.";
+                note = "Use the attribute on the `exec` methods of the contract."
+            );
+        } else if variant.attr_msg().map(|msg_attr| msg_attr.msg_type()) != Some(MsgType::Exec) {
+            emit_error!(
+                attr.span, "The `sv::pausable` attribute can be used only on `exec` methods.";
+                note = "Mark the method with `#[sv::msg(exec)]` attribute."
+            );
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::spanned::Spanned;
+use syn::{Error, Expr, ExprRange, Ident, MetaList, Path, RangeLimits, Result, Token};
+
+use crate::crate_module;
+
+/// Single validation parsed from `sv::validate` attribute.
+#[derive(Clone, Debug)]
+pub enum Validation {
+    /// Field is verified by the user defined function.
+    With(Path),
+    /// Field has to be contained in the range.
+    Range(ExprRange),
+}
+
+impl Validation {
+    /// Emits the validation of the `arg` field returning an error from the dispatch if it fails.
+    pub fn emit(&self, field: &Ident, arg: &Ident) -> TokenStream {
+        let sylvia = crate_module();
+
+        match self {
+            Validation::With(path) => quote! {
+                #path (&#arg)?;
+            },
+            Validation::Range(range) => {
+                let msg = format!(
+                    "Invalid `{}`: value out of the `{}` range.",
+                    field,
+                    range_to_string(range)
+                );
+                quote! {
+                    if !(#range).contains(&#arg) {
+                        return Err(Into::into(#sylvia ::cw_std::StdError::generic_err(#msg)));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn range_to_string(range: &ExprRange) -> String {
+    let bound = |bound: &Option<Box<Expr>>| {
+        bound
+            .as_ref()
+            .map(|bound| bound.to_token_stream().to_string())
+            .unwrap_or_default()
+    };
+    let limits = match range.limits {
+        RangeLimits::HalfOpen(_) => "..",
+        RangeLimits::Closed(_) => "..=",
+    };
+    format!("{}{}{}", bound(&range.start), limits, bound(&range.end))
+}
+
+/// Type wrapping data parsed from `sv::validate` attribute.
+#[derive(Clone, Debug)]
+pub struct ValidateAttr {
+    /// The field is an address validated with the `Api::addr_validate`.
+    /// Addresses are validated even without it.
+    pub addr: bool,
+    pub validations: Vec<Validation>,
+}
+
+impl ValidateAttr {
+    pub fn new(attr: &MetaList) -> Result<Self> {
+        let validations = Validations::parse
+            .parse2(attr.tokens.clone())
+            .map_err(|err| {
+                emit_error!(err.span(), err);
+                err
+            })?;
+
+        if !validations.addr && validations.validations.is_empty() {
+            emit_error!(
+                attr.span(), "Missing validation.";
+                note = "Expected `addr`, `with = path::to::function` or `range = start..end`."
+            );
+        }
+
+        Ok(Self {
+            addr: validations.addr,
+            validations: validations.validations,
+        })
+    }
+}
+
+struct Validations {
+    addr: bool,
+    validations: Vec<Validation>,
+}
+
+impl Parse for Validations {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut addr = false;
+        let mut validations = vec![];
+
+        while !input.is_empty() {
+            let validation: Ident = input.parse()?;
+            match validation.to_string().as_str() {
+                "addr" => addr = true,
+                "with" => {
+                    let _: Token![=] = input.parse()?;
+                    validations.push(Validation::With(input.parse()?));
+                }
+                "range" => {
+                    let _: Token![=] = input.parse()?;
+                    match input.parse()? {
+                        Expr::Range(range) => validations.push(Validation::Range(range)),
+                        expr => {
+                            return Err(Error::new(
+                                expr.span(),
+                                "Expected range, i.e. `range = 1..=100`.",
+                            ))
+                        }
+                    }
+                }
+                _ => {
+                    return Err(Error::new(
+                        validation.span(),
+                        "Invalid validation.\n= note: Expected `addr`, `with = path::to::function` or `range = start..end`.\n",
+                    ))
+                }
+            }
+
+            if !input.is_empty() {
+                let _: Token![,] = input.parse()?;
+            }
+        }
+
+        Ok(Self { addr, validations })
+    }
+}
@@ -0,0 +1,50 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro_error::emit_error;
+use syn::parse::{Error, Parse, ParseStream};
+use syn::{parse2, Ident, LitStr, Result, Token};
+
+/// Parsed arguments for `event` macro
+#[derive(Default)]
+pub struct EventArgs {
+    /// Type of the event. Defaults to the snake cased name of the struct.
+    pub name: Option<LitStr>,
+}
+
+impl EventArgs {
+    pub fn new(attr: &TokenStream2) -> Result<Self> {
+        let args: Self = parse2(attr.clone()).map_err(|err| {
+            emit_error!(err.span(), err);
+            err
+        })?;
+
+        Ok(args)
+    }
+}
+
+impl Parse for EventArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut event_args = Self::default();
+
+        while !input.is_empty() {
+            let arg: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            match arg.to_string().as_str() {
+                "name" => {
+                    let name: LitStr = input.parse()?;
+                    if name.value().is_empty() {
+                        return Err(Error::new(name.span(), "Event name can't be empty."));
+                    }
+                    event_args.name = Some(name);
+                }
+                _ => return Err(Error::new(arg.span(), "Expected `name`.")),
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+
+        Ok(event_args)
+    }
+}
@@ -0,0 +1,57 @@
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro_error::emit_error;
+use syn::parse::{Error, Parse, ParseStream};
+use syn::{parse2, Ident, LitStr, Path, Result, Token};
+
+/// Parsed arguments for `interface` macro
+#[derive(Default)]
+pub struct InterfaceArgs {
+    /// Version of the interface. Defaults to the version of the crate defining the interface.
+    pub version: Option<LitStr>,
+}
+
+impl InterfaceArgs {
+    pub fn new(attr: &TokenStream2) -> Result<Self> {
+        let args: Self = parse2(attr.clone()).map_err(|err| {
+            emit_error!(err.span(), err);
+            err
+        })?;
+
+        Ok(args)
+    }
+}
+
+impl Parse for InterfaceArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut interface_args = Self::default();
+
+        while !input.is_empty() {
+            let arg: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            match arg.to_string().as_str() {
+                "version" => {
+                    let version: LitStr = input.parse()?;
+                    if let Err(err) = semver::Version::parse(&version.value()) {
+                        return Err(Error::new(
+                            version.span(),
+                            format!("Invalid interface version: {err}."),
+                        ));
+                    }
+                    interface_args.version = Some(version);
+                }
+                // Parse the legacy `module` argument to not break semver
+                "module" => {
+                    let _: Path = input.parse()?;
+                }
+                _ => return Err(Error::new(arg.span(), "Expected `version`.")),
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+
+        Ok(interface_args)
+    }
+}
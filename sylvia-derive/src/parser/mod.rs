@@ -4,14 +4,19 @@
 pub mod attributes;
 pub mod check_generics;
 pub mod entry_point;
+pub mod event_args;
+pub mod interface_args;
 pub mod variant_descs;
 
+use attributes::extract::is_extracted;
 pub use attributes::{
     ContractErrorAttr, ContractMessageAttr, Custom, Customs, FilteredOverrideEntryPoints, MsgAttr,
     MsgType, OverrideEntryPoint, ParsedSylviaAttributes, SylviaAttribute,
 };
 use check_generics::{CheckGenerics, GetPath};
 pub use entry_point::EntryPointArgs;
+pub use event_args::EventArgs;
+pub use interface_args::InterfaceArgs;
 
 use proc_macro_error::emit_error;
 use syn::punctuated::Punctuated;
@@ -62,6 +67,7 @@ pub fn assert_new_method_defined(item: &ItemImpl) {
 /// Parses method signature and returns a vector of [`MsgField`].
 pub fn process_fields<'s, Generic>(
     sig: &'s Signature,
+    msg_type: MsgType,
     generics_checker: &mut CheckGenerics<Generic>,
 ) -> Vec<MsgField<'s>>
 where
@@ -78,7 +84,9 @@ where
                 None
             }
 
-            FnArg::Typed(item) => MsgField::new(item, generics_checker),
+            // Extracted parameters are filled by the dispatch instead of the message.
+            FnArg::Typed(item) if is_extracted(&item.attrs) => None,
+            FnArg::Typed(item) => MsgField::new(item, msg_type, generics_checker),
         })
         .collect()
 }
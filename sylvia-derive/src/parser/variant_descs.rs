@@ -1,3 +1,5 @@
+use crate::parser::attributes::access::AccessAttr;
+use crate::parser::attributes::pausable::PausableAttr;
 use crate::parser::attributes::VariantAttrForwarding;
 use crate::parser::{MsgAttr, ParsedSylviaAttributes};
 use syn::{Attribute, ImplItem, ItemImpl, ItemTrait, Signature, TraitItem};
@@ -6,6 +8,8 @@ use syn::{Attribute, ImplItem, ItemImpl, ItemTrait, Signature, TraitItem};
 pub struct VariantDesc<'a> {
     msg_attr: Option<MsgAttr>,
     attrs_to_forward: Vec<VariantAttrForwarding>,
+    access_attrs: Vec<AccessAttr>,
+    pausable_attr: Option<PausableAttr>,
     sig: &'a Signature,
 }
 
@@ -14,9 +18,13 @@ impl<'a> VariantDesc<'a> {
         let sylvia_params = ParsedSylviaAttributes::new(attrs.iter());
         let attrs_to_forward = sylvia_params.variant_attrs_forward;
         let msg_attr = sylvia_params.msg_attr;
+        let access_attrs = sylvia_params.access_attrs;
+        let pausable_attr = sylvia_params.pausable_attr;
         Self {
             msg_attr,
             attrs_to_forward,
+            access_attrs,
+            pausable_attr,
             sig,
         }
     }
@@ -32,6 +40,14 @@ impl<'a> VariantDesc<'a> {
     pub fn attrs_to_forward(&self) -> Vec<VariantAttrForwarding> {
         self.attrs_to_forward.clone()
     }
+
+    pub fn access_attrs(&self) -> &[AccessAttr] {
+        &self.access_attrs
+    }
+
+    pub fn pausable_attr(&self) -> Option<&PausableAttr> {
+        self.pausable_attr.as_ref()
+    }
 }
 
 pub type VariantDescs<'a> = Box<dyn Iterator<Item = VariantDesc<'a>> + 'a>;
@@ -1,5 +1,5 @@
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
 use syn::{ItemImpl, Path, Type};
 
@@ -15,7 +15,12 @@ pub struct Interfaces {
 
 impl Interfaces {
     pub fn new(source: &ItemImpl) -> Self {
-        let interfaces = ParsedSylviaAttributes::new(source.attrs.iter()).messages_attrs;
+        let parsed_attrs = ParsedSylviaAttributes::new(source.attrs.iter());
+        let mut interfaces = parsed_attrs.messages_attrs;
+        if parsed_attrs.sv_features.introspection {
+            let custom = parsed_attrs.custom_attr.unwrap_or_default();
+            interfaces.push(ContractMessageAttr::introspection(&custom));
+        }
         Self { interfaces }
     }
 
@@ -135,6 +140,286 @@ impl Interfaces {
         }).collect()
     }
 
+    /// Emits bounds required to generate the schemas of the interface messages.
+    pub fn emit_schema_predicates(&self, contract: &Type) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
+        self.interfaces
+            .iter()
+            .flat_map(|interface| {
+                let ContractMessageAttr { module, .. } = interface;
+                let api = quote! { < #contract as #module ::sv::InterfaceMessagesApi> };
+
+                [
+                    quote! { #api ::Exec: #sylvia ::schemars::JsonSchema },
+                    quote! { #api ::Query: #sylvia ::cw_schema::QueryResponses },
+                    quote! { #api ::Sudo: #sylvia ::schemars::JsonSchema },
+                ]
+            })
+            .collect()
+    }
+
+    /// Emits `(name, api)` pairs describing every interface implemented on the contract.
+    pub fn emit_interface_apis(&self, contract: &Type) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
+        self.interfaces
+            .iter()
+            .map(|interface| {
+                let ContractMessageAttr {
+                    module, variant, ..
+                } = interface;
+                let api = quote! { < #contract as #module ::sv::InterfaceMessagesApi> };
+                let name = variant.to_string();
+
+                quote! {
+                    (
+                        #name .to_owned(),
+                        #sylvia ::schema::interface_api::< #api ::Exec, #api ::Query, #api ::Sudo>(#name, contract_version),
+                    )
+                }
+            })
+            .collect()
+    }
+
+    /// Emits `sylvia::introspection::InterfaceInfo` describing every interface implemented on the contract.
+    pub fn emit_interface_infos(&self) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
+        self.interfaces
+            .iter()
+            .map(|interface| {
+                let ContractMessageAttr { module, .. } = interface;
+
+                quote! {
+                    #sylvia ::introspection::InterfaceInfo::new(
+                        #module ::sv::INTERFACE_NAME,
+                        #module ::sv::INTERFACE_ID,
+                        #module ::sv::INTERFACE_VERSION,
+                        &#module ::sv::execute_messages(),
+                        &#module ::sv::query_messages(),
+                        &#module ::sv::sudo_messages(),
+                    )
+                }
+            })
+            .collect()
+    }
+
+    /// Emits compile time checks of the interface versions against the requirements
+    /// from the `sv::messages` attributes.
+    pub fn emit_version_checks(&self) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
+        self.interfaces
+            .iter()
+            .filter_map(|interface| {
+                let ContractMessageAttr {
+                    module,
+                    variant,
+                    version,
+                    ..
+                } = interface;
+                let version = version.as_ref()?;
+                let message = format!(
+                    "Version of the `{variant}` interface does not match the `{}` requirement.",
+                    version.value()
+                );
+
+                Some(quote_spanned! { version.span() =>
+                    const _: () = assert!(
+                        #sylvia ::utils::version_matches(#module ::sv::INTERFACE_VERSION, #version),
+                        #message
+                    );
+                })
+            })
+            .collect()
+    }
+
+    /// Checks if any of the interfaces is marked as handling IBC messages.
+    pub fn has_ibc(&self) -> bool {
+        self.interfaces.iter().any(|interface| interface.ibc)
+    }
+
+    /// Emits routing of the IBC message to every interface marked with the `ibc` parameter.
+    /// Interfaces are checked in the order of the `sv::messages` attributes.
+    pub fn emit_ibc_dispatch_attempts(&self, msg_ty: &MsgType) -> Vec<TokenStream> {
+        self.interfaces
+            .iter()
+            .filter(|interface| interface.ibc)
+            .map(|interface| {
+                let ContractMessageAttr {
+                    module, customs, ..
+                } = interface;
+
+                let ep_name = msg_ty.emit_ep_name();
+                let handles_name = Ident::new(&format!("handles_{}", ep_name), module.span());
+                let dispatch_name = Ident::new(&format!("dispatch_{}", ep_name), module.span());
+                let ctx = match customs.has_query {
+                    true => quote! { (deps.into_empty(), env) },
+                    false => quote! { (deps, env) },
+                };
+
+                quote! {
+                    if #module ::sv:: #handles_name (&msg) {
+                        return #module ::sv:: #dispatch_name (&contract, #ctx, msg).map_err(Into::into);
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Checks if any of the interfaces is marked as handling replies.
+    pub fn has_reply(&self) -> bool {
+        self.interfaces.iter().any(|interface| interface.reply)
+    }
+
+    /// Emits forwarding of the reply to every interface marked with the `reply` parameter
+    /// if the reply id belongs to the interface namespace.
+    pub fn emit_reply_dispatch_attempts(&self) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
+        self.interfaces
+            .iter()
+            .filter(|interface| interface.reply)
+            .map(|interface| {
+                let ContractMessageAttr {
+                    module, customs, ..
+                } = interface;
+
+                let deps = match customs.has_query {
+                    true => quote! { deps.into_empty() },
+                    false => quote! { deps },
+                };
+                let dispatch = quote! {
+                    #module ::sv::dispatch_reply(&contract, #deps, env, msg)
+                };
+                let dispatch = match customs.has_msg {
+                    true => quote! {
+                        #sylvia ::into_response::IntoResponse::into_response( #dispatch ?).map_err(Into::into)
+                    },
+                    false => quote! { #dispatch .map_err(Into::into) },
+                };
+
+                quote! {
+                    if #module ::sv::handles_reply(msg.id) {
+                        return #dispatch;
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Emits compile time assertions that the interfaces marked with the `reply` parameter
+    /// have distinct `REPLY_NAMESPACE`s.
+    pub fn emit_reply_namespace_assertions(&self) -> Vec<TokenStream> {
+        let reply_interfaces: Vec<_> = self
+            .interfaces
+            .iter()
+            .filter(|interface| interface.reply)
+            .collect();
+
+        reply_interfaces
+            .iter()
+            .enumerate()
+            .flat_map(|(index, interface)| {
+                reply_interfaces[..index].iter().map(move |previous| {
+                    let module = &interface.module;
+                    let previous_module = &previous.module;
+                    let err_msg = format!(
+                        "The `{}` and `{}` interfaces share the same `REPLY_NAMESPACE`.",
+                        previous.variant, interface.variant
+                    );
+
+                    quote_spanned! { module.span() =>
+                        const _: () = assert!(
+                            #module ::sv::REPLY_NAMESPACE != #previous_module ::sv::REPLY_NAMESPACE,
+                            #err_msg
+                        );
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Emits compile time assertions that every interface marked with the `ibc` parameter
+    /// declares at least one `ibc_*` handler.
+    pub fn emit_ibc_declared_assertions(&self) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+
+        self.interfaces
+            .iter()
+            .filter(|interface| interface.ibc)
+            .map(|interface| {
+                let ContractMessageAttr {
+                    module, variant, ..
+                } = interface;
+
+                let handlers = MsgType::IBC.map(|msg_ty| {
+                    let const_name = msg_ty.emit_ibc_handler_const_name();
+                    quote! { matches!( #module ::sv:: #const_name, #sylvia ::types::IbcHandler::None) }
+                });
+                let err_msg = format!(
+                    "The `{variant}` interface is marked with `ibc`, but doesn't declare any `ibc_*` handler."
+                );
+
+                quote_spanned! { module.span() =>
+                    const _: () = assert!(!( #(#handlers)&&* ), #err_msg);
+                }
+            })
+            .collect()
+    }
+
+    /// Emits compile time assertions that no interface handler accepting every message
+    /// of the `msg_ty` type shadows the handlers of the interfaces listed after it
+    /// or the contract handler.
+    pub fn emit_ibc_shadowing_assertions(
+        &self,
+        msg_ty: &MsgType,
+        contract_handles: bool,
+    ) -> Vec<TokenStream> {
+        let sylvia = crate_module();
+        let const_name = msg_ty.emit_ibc_handler_const_name();
+        let ep_name = msg_ty.emit_ep_name();
+        let ibc_interfaces: Vec<_> = self
+            .interfaces
+            .iter()
+            .filter(|interface| interface.ibc)
+            .collect();
+
+        ibc_interfaces
+            .iter()
+            .enumerate()
+            .filter_map(|(index, interface)| {
+                let ContractMessageAttr {
+                    module, variant, ..
+                } = interface;
+
+                let later_handlers: Vec<_> = ibc_interfaces[index + 1..]
+                    .iter()
+                    .map(|later| {
+                        let later_module = &later.module;
+                        quote! { !matches!( #later_module ::sv:: #const_name, #sylvia ::types::IbcHandler::None) }
+                    })
+                    .chain(contract_handles.then(|| quote! { true }))
+                    .collect();
+                if later_handlers.is_empty() {
+                    return None;
+                }
+
+                let err_msg = format!(
+                    "The `{ep_name}` handler of the `{variant}` interface accepts every message and shadows the handlers declared after it."
+                );
+
+                Some(quote_spanned! { module.span() =>
+                    const _: () = assert!(
+                        !(matches!( #module ::sv:: #const_name, #sylvia ::types::IbcHandler::All) && ( #(#later_handlers)||* )),
+                        #err_msg
+                    );
+                })
+            })
+            .collect()
+    }
+
     pub fn variants_names(&self) -> impl Iterator<Item = &Ident> {
         self.interfaces.iter().map(|interface| &interface.variant)
     }
@@ -1,26 +1,38 @@
+use std::borrow::Cow;
+
+use crate::crate_module;
 use crate::fold::StripSelfPath;
+use crate::parser::attributes::{SylviaAttribute, Validation};
 use crate::parser::check_generics::{CheckGenerics, GetPath};
+use crate::parser::{MsgType, ParsedSylviaAttributes};
 use proc_macro2::TokenStream;
 use proc_macro_error::emit_error;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::fold::Fold;
 use syn::spanned::Spanned;
 use syn::visit::Visit;
-use syn::{Attribute, Ident, Pat, PatType, Type};
+use syn::{
+    parse_quote, Attribute, GenericArgument, Ident, Pat, PatType, PathArguments, Type, TypePath,
+};
 
 /// Representation of single message variant field
 #[derive(Debug)]
 pub struct MsgField<'a> {
-    name: &'a Ident,
-    ty: &'a Type,
+    name: Cow<'a, Ident>,
+    ty: Cow<'a, Type>,
     stripped_ty: Type,
-    attrs: &'a Vec<Attribute>,
+    attrs: Cow<'a, [Attribute]>,
+    /// Kind of the address validated before calling the method.
+    addr: Option<AddrField>,
+    /// Validations applied to the field before calling the method.
+    validations: Vec<Validation>,
 }
 
 impl<'a> MsgField<'a> {
     /// Creates new field from trait method argument
     pub fn new<Generic>(
         item: &'a PatType,
+        msg_type: MsgType,
         generics_checker: &mut CheckGenerics<Generic>,
     ) -> Option<MsgField<'a>>
     where
@@ -50,19 +62,72 @@ impl<'a> MsgField<'a> {
             }
         }?;
 
-        let ty = &item.ty;
-        let stripped_ty = StripSelfPath.fold_type((*item.ty).clone());
+        let validate_attrs = ParsedSylviaAttributes::new(
+            item.attrs
+                .iter()
+                .filter(|attr| SylviaAttribute::new(attr) == Some(SylviaAttribute::Validate)),
+        )
+        .validate_attrs;
+        let explicit_addr = validate_attrs.iter().any(|attr| attr.addr);
+        let validations: Vec<_> = validate_attrs
+            .into_iter()
+            .flat_map(|attr| attr.validations)
+            .collect();
+
+        let addr = match msg_type.validates_fields() {
+            true => AddrField::new(&item.ty),
+            false => None,
+        };
+        if explicit_addr && addr.is_none() {
+            emit_error!(
+                item.ty.span(), "The `addr` validation requires the parameter of the `Addr`, `Option<Addr>` or `Vec<Addr>` type.";
+                note = "The field is sent as `String` and validated into the `Addr`."
+            );
+        }
+
+        // Addresses are sent as `String` and validated before calling the method.
+        let ty = match addr {
+            Some(addr) => Cow::Owned(addr.wire_type()),
+            None => Cow::Borrowed(&*item.ty),
+        };
+        let stripped_ty = StripSelfPath.fold_type(ty.clone().into_owned());
         let attrs = &item.attrs;
         generics_checker.visit_type(&stripped_ty);
 
         Some(Self {
-            name,
+            name: Cow::Borrowed(name),
             ty,
             stripped_ty,
-            attrs,
+            attrs: Cow::Borrowed(attrs),
+            addr,
+            validations,
         })
     }
 
+    /// Creates the `start_after` and `limit` fields added to the paginated queries.
+    pub fn pagination() -> [MsgField<'static>; 2] {
+        let start_after: Type = parse_quote! { Option<String> };
+        let limit: Type = parse_quote! { Option<u32> };
+        [
+            MsgField {
+                name: Cow::Owned(format_ident!("start_after")),
+                ty: Cow::Owned(start_after.clone()),
+                stripped_ty: start_after,
+                attrs: Cow::Owned(vec![]),
+                addr: None,
+                validations: vec![],
+            },
+            MsgField {
+                name: Cow::Owned(format_ident!("limit")),
+                ty: Cow::Owned(limit.clone()),
+                stripped_ty: limit,
+                attrs: Cow::Owned(vec![]),
+                addr: None,
+                validations: vec![],
+            },
+        ]
+    }
+
     /// Emits message field
     pub fn emit(&self) -> TokenStream {
         let Self {
@@ -71,6 +136,9 @@ impl<'a> MsgField<'a> {
             attrs,
             ..
         } = self;
+        let attrs = attrs
+            .iter()
+            .filter(|attr| SylviaAttribute::new(attr).is_none());
 
         quote! {
             #(#attrs)*
@@ -86,6 +154,9 @@ impl<'a> MsgField<'a> {
             attrs,
             ..
         } = self;
+        let attrs = attrs
+            .iter()
+            .filter(|attr| SylviaAttribute::new(attr).is_none());
 
         quote! {
             #(#attrs)*
@@ -104,23 +175,180 @@ impl<'a> MsgField<'a> {
         }
     }
 
+    /// Emits parameter of the generated helpers. Addresses are accepted as `&Addr`.
     pub fn emit_method_field_folded(&self) -> TokenStream {
-        let Self { name, ty, .. } = self;
+        let Self { name, ty, addr, .. } = self;
+
+        match addr {
+            Some(addr) => {
+                let param_type = addr.param_type();
+                quote! { #name: #param_type }
+            }
+            None => quote! { #name: #ty },
+        }
+    }
+
+    /// Emits parameter of the generated helpers with the `Self` stripped from the type.
+    /// Addresses are accepted as `&Addr`.
+    pub fn emit_helper_field(&self) -> TokenStream {
+        match self.addr {
+            Some(_) => self.emit_method_field_folded(),
+            None => self.emit_method_field(),
+        }
+    }
+
+    /// Emits conversion of the helper parameter into the message field.
+    pub fn emit_into_field(&self) -> TokenStream {
+        let name = &self.name;
+
+        match self.addr {
+            Some(AddrField::Addr) => quote! { #name .to_string() },
+            Some(AddrField::Option) => quote! { #name .map(ToString::to_string) },
+            Some(AddrField::Vec) => quote! { #name .iter().map(ToString::to_string).collect() },
+            None => quote! { #name },
+        }
+    }
+
+    /// Emits binding of the helper parameter to the owned value, so it can be captured by
+    /// the closure outliving the parameter.
+    pub fn emit_to_owned(&self) -> TokenStream {
+        let name = &self.name;
+
+        match self.addr {
+            Some(AddrField::Addr) => quote! { let #name = #name .clone(); },
+            Some(AddrField::Option) => quote! { let #name = #name .cloned(); },
+            Some(AddrField::Vec) => quote! { let #name = #name .to_vec(); },
+            None => quote! {},
+        }
+    }
+
+    /// Emits the helper argument from the value bound by [MsgField::emit_to_owned].
+    pub fn emit_owned_arg(&self) -> TokenStream {
+        let name = &self.name;
+
+        match self.addr {
+            Some(AddrField::Addr) | Some(AddrField::Vec) => quote! { &#name },
+            Some(AddrField::Option) => quote! { #name .as_ref() },
+            None => quote! { Clone::clone(&#name) },
+        }
+    }
+
+    /// Emits validations of the field bound to the `arg` variable.
+    /// Addresses are validated first, so the other validations receive the `Addr`.
+    pub fn emit_validations(&self, arg: &Ident) -> TokenStream {
+        let addr_validation = self.addr.map(|addr| addr.emit_validation(arg));
+        let validations = self
+            .validations
+            .iter()
+            .map(|validation| validation.emit(&self.name, arg));
 
         quote! {
-            #name: #ty
+            #addr_validation
+            #(#validations)*
+        }
+    }
+
+    pub fn validations(&self) -> &[Validation] {
+        &self.validations
+    }
+
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+
+    pub fn attrs(&self) -> &[Attribute] {
+        &self.attrs
+    }
+}
+
+/// Kind of the address field sent as `String` and validated into the `Addr`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum AddrField {
+    Addr,
+    Option,
+    Vec,
+}
+
+impl AddrField {
+    /// Detects `Addr`, `Option<Addr>` and `Vec<Addr>` types.
+    ///
+    /// Only the `Addr` of the `cosmwasm_std` is recognized, either imported or referred to as
+    /// `cosmwasm_std::Addr` or `sylvia::cw_std::Addr`. Other types named `Addr` are sent as is.
+    fn new(ty: &Type) -> Option<Self> {
+        let Type::Path(TypePath { qself: None, path }) = ty else {
+            return None;
+        };
+        let idents: Vec<_> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        let idents: Vec<_> = idents.iter().map(String::as_str).collect();
+        let segment = path.segments.last()?;
+
+        match &segment.arguments {
+            PathArguments::None => match idents.as_slice() {
+                ["Addr"] | ["cosmwasm_std", "Addr"] | ["sylvia", "cw_std", "Addr"] => {
+                    Some(Self::Addr)
+                }
+                _ => None,
+            },
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                let Some(GenericArgument::Type(inner)) = args.args.first() else {
+                    return None;
+                };
+                match (Self::new(inner), idents.as_slice()) {
+                    (Some(Self::Addr), ["Option"] | ["std", "option", "Option"]) => {
+                        Some(Self::Option)
+                    }
+                    (Some(Self::Addr), ["Vec"] | ["std", "vec", "Vec"]) => Some(Self::Vec),
+                    _ => None,
+                }
+            }
+            _ => None,
         }
     }
 
-    pub fn name(&self) -> &'a Ident {
-        self.name
+    /// Type of the field in the message.
+    fn wire_type(self) -> Type {
+        match self {
+            Self::Addr => parse_quote! { String },
+            Self::Option => parse_quote! { Option<String> },
+            Self::Vec => parse_quote! { Vec<String> },
+        }
     }
 
-    pub fn ty(&self) -> &'a Type {
-        self.ty
+    /// Type of the parameter of the generated helpers.
+    fn param_type(self) -> TokenStream {
+        let sylvia = crate_module();
+
+        match self {
+            Self::Addr => quote! { &#sylvia ::cw_std::Addr },
+            Self::Option => quote! { Option<&#sylvia ::cw_std::Addr> },
+            Self::Vec => quote! { &[#sylvia ::cw_std::Addr] },
+        }
     }
 
-    pub fn attrs(&self) -> &'a Vec<Attribute> {
-        self.attrs
+    /// Emits the validation of the `arg` field shadowing it with the validated address.
+    /// Expects the `ctx` tuple with the `deps` as its first element to be in scope.
+    fn emit_validation(self, arg: &Ident) -> TokenStream {
+        match self {
+            Self::Addr => quote! {
+                let #arg = ctx.0.api.addr_validate(&#arg)?;
+            },
+            Self::Option => quote! {
+                let #arg = #arg .map(|addr| ctx.0.api.addr_validate(&addr)).transpose()?;
+            },
+            Self::Vec => quote! {
+                let #arg = #arg
+                    .iter()
+                    .map(|addr| ctx.0.api.addr_validate(addr))
+                    .collect::<Result<Vec<_>, _>>()?;
+            },
+        }
     }
 }
@@ -19,13 +19,72 @@ impl MsgType {
             "migrate" => Ok(Self::Migrate),
             "reply" => Ok(Self::Reply ),
             "sudo" => Ok(Self::Sudo),
+            "ibc_channel_open" => Ok(Self::IbcChannelOpen),
+            "ibc_channel_connect" => Ok(Self::IbcChannelConnect),
+            "ibc_channel_close" => Ok(Self::IbcChannelClose),
+            "ibc_packet_receive" => Ok(Self::IbcPacketReceive),
+            "ibc_packet_ack" => Ok(Self::IbcPacketAck),
+            "ibc_packet_timeout" => Ok(Self::IbcPacketTimeout),
             _ => Err(Error::new(
                 msg_type.span(),
-                "Invalid message type, expected one of: `exec`, `query`, `instantiate`, `migrate`, `reply` or `sudo`.",
+                "Invalid message type, expected one of: `exec`, `query`, `instantiate`, `migrate`, `reply`, `sudo` or one of the `ibc_*` types.",
             ))
         }
     }
 
+    /// All the IBC message types in the order of the channel lifecycle.
+    pub const IBC: [MsgType; 6] = [
+        MsgType::IbcChannelOpen,
+        MsgType::IbcChannelConnect,
+        MsgType::IbcChannelClose,
+        MsgType::IbcPacketReceive,
+        MsgType::IbcPacketAck,
+        MsgType::IbcPacketTimeout,
+    ];
+
+    /// Checks if the message type is handled by one of the IBC entry points.
+    pub fn is_ibc(&self) -> bool {
+        Self::IBC.contains(self)
+    }
+
+    /// Checks if the fields of the message are validated in the dispatch.
+    pub fn validates_fields(self) -> bool {
+        matches!(
+            self,
+            Self::Instantiate
+                | Self::Exec
+                | Self::Query
+                | Self::Sudo
+                | Self::Migrate
+                | Self::Cw20Receive
+        )
+    }
+
+    /// Emits check if the IBC packet data of `msg` deserializes into the `packet` type.
+    /// Handlers without the `packet` type accept every message.
+    pub fn emit_ibc_packet_check(&self, packet: Option<&Type>) -> TokenStream {
+        let sylvia = crate_module();
+
+        let data = match self {
+            Self::IbcPacketAck => quote! { msg.original_packet.data },
+            _ => quote! { msg.packet.data },
+        };
+
+        match packet {
+            Some(packet) => quote! { #sylvia ::cw_std::from_json::< #packet >(& #data).is_ok() },
+            None => quote! { true },
+        }
+    }
+
+    /// Name of the interface constant describing its handler of the IBC message type.
+    pub fn emit_ibc_handler_const_name(self) -> Ident {
+        let ep_name = self.emit_ep_name();
+        Ident::new(
+            &format!("{}_HANDLER", ep_name.to_string().to_uppercase()),
+            ep_name.span(),
+        )
+    }
+
     pub fn emit_ctx_type(self, query_type: &Type) -> TokenStream {
         use MsgType::*;
 
@@ -35,12 +94,16 @@ impl MsgType {
             Exec | Instantiate => quote! {
                 (#sylvia ::cw_std::DepsMut< #query_type >, #sylvia ::cw_std::Env, #sylvia ::cw_std::MessageInfo)
             },
-            Migrate | Reply | Sudo => quote! {
+            Migrate | Reply | Sudo | IbcChannelOpen | IbcChannelConnect | IbcChannelClose
+            | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => quote! {
                 (#sylvia ::cw_std::DepsMut< #query_type >, #sylvia ::cw_std::Env)
             },
             Query => quote! {
                 (#sylvia ::cw_std::Deps< #query_type >, #sylvia ::cw_std::Env)
             },
+            Cw20Receive => quote! {
+                (#sylvia ::cw_std::DepsMut< #query_type >, #sylvia ::cw_std::Env, #sylvia ::cw_std::MessageInfo, #sylvia ::cw_std::Addr, #sylvia ::cw_std::Uint128)
+            },
         }
     }
 
@@ -64,10 +127,11 @@ impl MsgType {
         let sylvia = crate_module();
 
         match self {
-            Exec | Instantiate => quote! {
+            Exec | Instantiate | Cw20Receive => quote! {
                 deps: #sylvia ::cw_std::DepsMut< #query_type>, env: #sylvia ::cw_std::Env, info: #sylvia ::cw_std::MessageInfo
             },
-            Migrate | Reply | Sudo => quote! {
+            Migrate | Reply | Sudo | IbcChannelOpen | IbcChannelConnect | IbcChannelClose
+            | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => quote! {
                 deps: #sylvia ::cw_std::DepsMut< #query_type>, env: #sylvia ::cw_std::Env
             },
             Query => quote! {
@@ -84,6 +148,13 @@ impl MsgType {
             Self::Sudo => parse_quote! { sudo },
             Self::Reply => parse_quote! { reply },
             Self::Query => parse_quote! { query },
+            Self::IbcChannelOpen => parse_quote! { ibc_channel_open },
+            Self::IbcChannelConnect => parse_quote! { ibc_channel_connect },
+            Self::IbcChannelClose => parse_quote! { ibc_channel_close },
+            Self::IbcPacketReceive => parse_quote! { ibc_packet_receive },
+            Self::IbcPacketAck => parse_quote! { ibc_packet_ack },
+            Self::IbcPacketTimeout => parse_quote! { ibc_packet_timeout },
+            Self::Cw20Receive => parse_quote! { cw20_receive },
         }
     }
 
@@ -91,8 +162,11 @@ impl MsgType {
         use MsgType::*;
 
         match self {
-            Exec | Instantiate => quote! { deps, env, info },
-            Migrate | Reply | Query | Sudo => quote! { deps, env },
+            Exec | Instantiate | Cw20Receive => quote! { deps, env, info },
+            Migrate | Reply | Query | Sudo | IbcChannelOpen | IbcChannelConnect
+            | IbcChannelClose | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => {
+                quote! { deps, env }
+            }
         }
     }
 
@@ -103,7 +177,7 @@ impl MsgType {
         let sylvia = crate_module();
 
         match self {
-            Exec | Instantiate | Migrate | Reply | Sudo => {
+            Exec | Instantiate | Migrate | Reply | Sudo | Cw20Receive => {
                 quote! {
                     std::result::Result< #sylvia:: cw_std::Response <#msg_type>, #err_type>
                 }
@@ -111,6 +185,15 @@ impl MsgType {
             Query => quote! {
                 std::result::Result<#sylvia ::cw_std::Binary, #err_type>
             },
+            IbcChannelOpen => quote! {
+                std::result::Result< #sylvia ::cw_std::IbcChannelOpenResponse, #err_type>
+            },
+            IbcChannelConnect | IbcChannelClose | IbcPacketAck | IbcPacketTimeout => quote! {
+                std::result::Result< #sylvia ::cw_std::IbcBasicResponse <#msg_type>, #err_type>
+            },
+            IbcPacketReceive => quote! {
+                std::result::Result< #sylvia ::cw_std::IbcReceiveResponse <#msg_type>, #err_type>
+            },
         }
     }
 
@@ -131,6 +214,13 @@ impl MsgType {
             MsgType::Migrate => parse_quote! { MigrateMsg },
             MsgType::Reply => parse_quote! { ReplyMsg },
             MsgType::Sudo => parse_quote! { SudoMsg },
+            MsgType::IbcChannelOpen => parse_quote! { IbcChannelOpenMsg },
+            MsgType::IbcChannelConnect => parse_quote! { IbcChannelConnectMsg },
+            MsgType::IbcChannelClose => parse_quote! { IbcChannelCloseMsg },
+            MsgType::IbcPacketReceive => parse_quote! { IbcPacketReceiveMsg },
+            MsgType::IbcPacketAck => parse_quote! { IbcPacketAckMsg },
+            MsgType::IbcPacketTimeout => parse_quote! { IbcPacketTimeoutMsg },
+            MsgType::Cw20Receive => parse_quote! { ReceiveMsg },
         }
     }
 
@@ -151,6 +241,13 @@ impl MsgType {
             MsgType::Migrate => parse_quote! { Migrate },
             MsgType::Sudo => parse_quote! { Sudo },
             MsgType::Reply => parse_quote! { Reply },
+            MsgType::IbcChannelOpen => parse_quote! { IbcChannelOpen },
+            MsgType::IbcChannelConnect => parse_quote! { IbcChannelConnect },
+            MsgType::IbcChannelClose => parse_quote! { IbcChannelClose },
+            MsgType::IbcPacketReceive => parse_quote! { IbcPacketReceive },
+            MsgType::IbcPacketAck => parse_quote! { IbcPacketAck },
+            MsgType::IbcPacketTimeout => parse_quote! { IbcPacketTimeout },
+            MsgType::Cw20Receive => parse_quote! { Cw20Receive },
         }
     }
 
@@ -195,13 +292,14 @@ impl MsgType {
         let sylvia = crate_module();
 
         match self {
-            Exec | Sudo => quote! {
+            Exec | Sudo | Cw20Receive => quote! {
                 contract.#function_name(Into::into(ctx), #(#args),*).map_err(Into::into)
             },
             Query => quote! {
                 #sylvia ::cw_std::to_json_binary(&contract.#function_name(Into::into(ctx), #(#args),*)?).map_err(Into::into)
             },
-            Instantiate | Migrate | Reply => {
+            Instantiate | Migrate | Reply | IbcChannelOpen | IbcChannelConnect
+            | IbcChannelClose | IbcPacketReceive | IbcPacketAck | IbcPacketTimeout => {
                 emit_error!(function_name.span(), "Internal Error";
                 note = "Dispatch leg should be called only for `Enum` type messages.");
                 quote! {}
@@ -1,14 +1,20 @@
 use crate::crate_module;
 use crate::fold::StripSelfPath;
+use crate::parser::attributes::access::AccessCheck;
+use crate::parser::attributes::extract::extracted_params;
+use crate::parser::attributes::msg::Funds;
+use crate::parser::attributes::pausable::PausableAttr;
 use crate::parser::attributes::VariantAttrForwarding;
 use crate::parser::check_generics::{CheckGenerics, GetPath};
 use crate::parser::variant_descs::VariantDescs;
 use crate::parser::{process_fields, MsgAttr, MsgType};
-use crate::utils::{extract_return_type, filter_wheres, SvCasing};
+use crate::utils::{extract_page_item_type, extract_return_type, filter_wheres, SvCasing};
 use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use proc_macro_error::emit_error;
+use quote::{format_ident, quote, ToTokens};
 use syn::fold::Fold;
+use syn::spanned::Spanned;
 use syn::visit::Visit;
 use syn::{parse_quote, Ident, Signature, Type, WhereClause, WherePredicate};
 
@@ -23,8 +29,19 @@ pub struct MsgVariant<'a> {
     /// Type extracted only in case of `Query` and used in `cosmwasm_schema::QueryResponses`
     /// `returns` attribute.
     return_type: Option<Type>,
+    /// Type of the items returned by the `paginated` query.
+    page_item: Option<Type>,
     msg_attr: MsgAttr,
     attrs_to_forward: Vec<VariantAttrForwarding>,
+    /// Checks of the sender performed before calling the `exec` handler.
+    access_checks: Vec<AccessCheck>,
+    /// Funds accepted by the message.
+    funds: Option<Funds>,
+    /// Types of the parameters filled by the `FromExecCtx` extractors with their positions
+    /// among the method parameters.
+    extracted: Vec<(usize, &'a Type)>,
+    /// Marks if the message is rejected while the contract is paused.
+    pausable: bool,
 }
 
 impl<'a> MsgVariant<'a> {
@@ -34,6 +51,8 @@ impl<'a> MsgVariant<'a> {
         generics_checker: &mut CheckGenerics<Generic>,
         msg_attr: MsgAttr,
         attrs_to_forward: Vec<VariantAttrForwarding>,
+        access_checks: Vec<AccessCheck>,
+        pausable: bool,
     ) -> MsgVariant<'a>
     where
         Generic: GetPath + PartialEq,
@@ -41,7 +60,7 @@ impl<'a> MsgVariant<'a> {
         let function_name = &sig.ident;
 
         let name = function_name.to_case(Case::UpperCamel);
-        let fields = process_fields(sig, generics_checker);
+        let mut fields = process_fields(sig, msg_attr.msg_type(), generics_checker);
 
         let return_type = if msg_attr.msg_type() == MsgType::Query {
             let resp_type = &msg_attr.resp_type();
@@ -62,13 +81,66 @@ impl<'a> MsgVariant<'a> {
             None
         };
 
+        if !msg_attr.msg_type().validates_fields() {
+            fields
+                .iter()
+                .filter(|field| !field.validations().is_empty())
+                .for_each(|field| {
+                    emit_error!(
+                        field.name().span(), "The `sv::validate` attribute is not supported for this message type.";
+                        note = "Validations are supported for the `instantiate`, `exec`, `query`, `sudo` and `migrate` messages."
+                    )
+                });
+        }
+
+        let page_item = match (msg_attr.paginated(), &return_type) {
+            (true, Some(return_type)) => {
+                let pagination_fields = MsgField::pagination();
+                fields
+                    .iter()
+                    .filter(|field| {
+                        pagination_fields
+                            .iter()
+                            .any(|pagination_field| pagination_field.name() == field.name())
+                    })
+                    .for_each(|field| {
+                        emit_error!(
+                            field.name().span(), "The `{}` argument is reserved for the paginated queries.", field.name();
+                            note = "The `start_after` and `limit` fields are added to the paginated queries by Sylvia."
+                        )
+                    });
+                fields.extend(pagination_fields);
+
+                let page_item = extract_page_item_type(return_type).cloned();
+                if page_item.is_none() {
+                    emit_error!(
+                        return_type.span(), "Paginated queries have to return `Page<T>`.";
+                        note = "Use the `sylvia::pagination::Page` as the response of the paginated query."
+                    );
+                }
+                page_item
+            }
+            _ => None,
+        };
+
+        let funds = msg_attr.funds().cloned();
+        let extracted = extracted_params(sig)
+            .into_iter()
+            .map(|(position, param)| (position, &*param.ty))
+            .collect();
+
         Self {
             name,
             function_name,
             fields,
             return_type,
+            page_item,
             msg_attr,
             attrs_to_forward,
+            access_checks,
+            funds,
+            extracted,
+            pausable,
         }
     }
 
@@ -85,9 +157,11 @@ impl<'a> MsgVariant<'a> {
         let fields = fields.iter().map(MsgField::emit);
         let returns_attribute = msg_attr.msg_type().emit_returns_attribute(return_type);
         let attrs_to_forward = attrs_to_forward.iter().map(|attr| &attr.attrs);
+        let funds_doc = self.emit_funds_doc();
 
         quote! {
             #returns_attribute
+            #funds_doc
             #( #[ #attrs_to_forward ] )*
             #name {
                 #(#fields,)*
@@ -119,12 +193,136 @@ impl<'a> MsgVariant<'a> {
             .zip(args.clone())
             .map(|(field, num_field)| quote!(#field : #num_field));
 
-        let method_call = msg_attr.msg_type().emit_dispatch_leg(function_name, &args);
+        let method_call = match msg_attr.msg_type() {
+            MsgType::Migrate => self.emit_versioned_migrate_call(&args),
+            MsgType::Query if self.is_paginated() => self.emit_paginated_query_call(&args),
+            MsgType::Exec if !self.access_checks.is_empty() || !self.extracted.is_empty() => {
+                self.emit_guarded_exec_call(&args)
+            }
+            msg_ty => msg_ty.emit_dispatch_leg(function_name, &args),
+        };
+
+        let pause_check = match self.pausable {
+            true => PausableAttr::emit_check(),
+            false => quote! {},
+        };
+        let funds_check = self.emit_funds_check();
+        let validations = self.emit_validations(&args);
 
         quote! {
             #name {
                 #(#fields,)*
-            } => #method_call
+            } => {
+                #pause_check
+                #funds_check
+                #validations
+                #method_call
+            }
+        }
+    }
+
+    /// Emits the check of the funds sent with the message if the variant has the funds requirement.
+    pub fn emit_funds_check(&self) -> TokenStream {
+        self.funds
+            .as_ref()
+            .map(Funds::emit_check)
+            .unwrap_or_default()
+    }
+
+    /// Emits the doc comment describing the funds requirement of the variant.
+    /// It is visible in the schema and in the documentation of the multitest proxy.
+    pub fn emit_funds_doc(&self) -> TokenStream {
+        match &self.funds {
+            Some(funds) => {
+                let description = funds.description();
+                quote! { #[doc = #description] }
+            }
+            None => quote! {},
+        }
+    }
+
+    /// Emits validations of the fields bound to the `args` variables.
+    /// Validated fields are shadowed with the values passed to the method.
+    pub fn emit_validations<'b>(&self, args: impl IntoIterator<Item = &'b Ident>) -> TokenStream {
+        let validations = self
+            .fields
+            .iter()
+            .zip(args)
+            .map(|(field, arg)| field.emit_validations(arg));
+
+        quote! { #(#validations)* }
+    }
+
+    /// Emits call to the migrate handler guarded by the `cw2` contract version check.
+    ///
+    /// The migration is rejected if the stored version doesn't match the `from` requirement.
+    /// The stored version is updated after the handler succeeds.
+    pub fn emit_versioned_migrate_call<Arg: ToTokens>(&self, args: &[Arg]) -> TokenStream {
+        let sylvia = crate_module();
+        let function_name = self.function_name;
+        let migrate_from = match self.msg_attr.migrate_from() {
+            Some(migrate_from) => quote! { Some(#migrate_from) },
+            None => quote! { None },
+        };
+        let name = quote! { env!("CARGO_PKG_NAME") };
+        let version = quote! { env!("CARGO_PKG_VERSION") };
+
+        quote! {
+            {
+                let (mut deps, env) = ctx;
+                #sylvia ::versioning::ensure_migration_allowed(deps.storage, #name, #version, #migrate_from)?;
+                let resp = contract.#function_name(Into::into((deps.branch(), env)), #(#args),*)?;
+                #sylvia ::versioning::set_contract_version(deps.storage, #name, #version)?;
+                Ok(resp)
+            }
+        }
+    }
+
+    /// Emits call to the exec handler preceded by the `sv::access` checks
+    /// and the `FromExecCtx` extractors of the `sv::extract` parameters.
+    ///
+    /// If any of the checks fails, the `Unauthorized` error is returned without calling the handler.
+    /// Errors of the extractors are converted into the contract error.
+    fn emit_guarded_exec_call(&self, args: &[Ident]) -> TokenStream {
+        let sylvia = crate_module();
+        let function_name = self.function_name;
+        let checks = self
+            .access_checks
+            .iter()
+            .map(|check| check.emit(function_name));
+
+        let mut args: Vec<_> = args.iter().map(|arg| quote! { #arg }).collect();
+        let extractors = self.extracted.iter().map(|(position, ty)| {
+            let extracted = format_ident!("extracted{}", position);
+            args.insert(*position, quote! { #extracted });
+            quote! {
+                let #extracted = <#ty as #sylvia ::extract::FromExecCtx<_, _>>::from_exec_ctx(contract, &ctx)?;
+            }
+        }).collect::<Vec<_>>();
+
+        quote! {
+            {
+                let ctx: #sylvia ::ctx::ExecCtx<_> = Into::into(ctx);
+                #(#checks)*
+                #(#extractors)*
+                contract.#function_name(ctx, #(#args),*).map_err(Into::into)
+            }
+        }
+    }
+
+    /// Emits call to the query handler with the `QueryCtx::pagination` set from the
+    /// `start_after` and `limit` fields of the message.
+    fn emit_paginated_query_call(&self, args: &[Ident]) -> TokenStream {
+        let sylvia = crate_module();
+        let function_name = self.function_name;
+        let (args, pagination_args) = args.split_at(args.len() - 2);
+
+        quote! {
+            {
+                let mut ctx: #sylvia ::ctx::QueryCtx<_> = Into::into(ctx);
+                ctx.pagination = #sylvia ::pagination::Pagination::new(#(#pagination_args),*);
+                #sylvia ::cw_std::to_json_binary(&contract.#function_name(ctx, #(#args),*)?).map_err(Into::into)
+            }
         }
     }
 
@@ -143,17 +341,6 @@ impl<'a> MsgVariant<'a> {
         }
     }
 
-    pub fn as_fields_names(&self) -> Vec<&Ident> {
-        self.fields.iter().map(MsgField::name).collect()
-    }
-
-    pub fn emit_method_field(&self) -> Vec<TokenStream> {
-        self.fields
-            .iter()
-            .map(MsgField::emit_method_field)
-            .collect()
-    }
-
     pub fn name(&self) -> &Ident {
         &self.name
     }
@@ -162,10 +349,28 @@ impl<'a> MsgVariant<'a> {
         self.function_name
     }
 
-    pub fn fields(&self) -> &Vec<MsgField> {
+    pub fn fields(&self) -> &Vec<MsgField<'_>> {
         &self.fields
     }
 
+    /// Fields of the variant without the `start_after` and `limit` fields of the paginated query.
+    pub fn fields_without_pagination(&self) -> &[MsgField<'_>] {
+        match self.is_paginated() {
+            true => &self.fields[..self.fields.len() - 2],
+            false => &self.fields,
+        }
+    }
+
+    /// Checks if the variant is the `paginated` query.
+    pub fn is_paginated(&self) -> bool {
+        self.msg_attr.paginated() && self.return_type.is_some()
+    }
+
+    /// Type of the items returned by the `paginated` query.
+    pub fn page_item(&self) -> Option<&Type> {
+        self.page_item.as_ref()
+    }
+
     pub fn msg_attr(&self) -> &MsgAttr {
         &self.msg_attr
     }
@@ -199,6 +404,12 @@ where
             .filter_map(|variant_desc| {
                 let msg_attr: MsgAttr = variant_desc.attr_msg()?;
                 let attrs_to_forward = variant_desc.attrs_to_forward();
+                let access_checks = variant_desc
+                    .access_attrs()
+                    .iter()
+                    .flat_map(|attr| attr.checks.clone())
+                    .collect();
+                let pausable = variant_desc.pausable_attr().is_some();
 
                 if msg_attr.msg_type() != msg_ty {
                     return None;
@@ -209,6 +420,8 @@ where
                     &mut generics_checker,
                     msg_attr,
                     attrs_to_forward,
+                    access_checks,
+                    pausable,
                 ))
             })
             .collect();
@@ -234,10 +447,21 @@ where
         }
     }
 
-    pub fn variants(&self) -> impl Iterator<Item = &MsgVariant> {
+    pub fn variants(&self) -> impl Iterator<Item = &MsgVariant<'_>> {
         self.variants.iter()
     }
 
+    /// Sets the funds requirement of the variants not marked as `payable` or `nonpayable`.
+    pub fn with_default_funds(mut self, funds: Option<Funds>) -> Self {
+        if let Some(funds) = funds {
+            self.variants
+                .iter_mut()
+                .filter(|variant| variant.funds.is_none())
+                .for_each(|variant| variant.funds = Some(funds.clone()));
+        }
+        self
+    }
+
     pub fn used_generics(&self) -> &Vec<&'a Generic> {
         &self.used_generics
     }
@@ -267,6 +491,26 @@ where
             .map(|variant| variant.emit_dispatch_leg())
     }
 
+    /// Emits match arms returning the snake cased names of the variants.
+    /// Assumes enum variants are imported into the scope.
+    pub fn emit_msg_name_arms(&self) -> Vec<TokenStream> {
+        let mut arms: Vec<_> = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let name = &variant.name;
+                let msg_name = name.to_string().to_case(Case::Snake);
+                quote! { #name { .. } => #msg_name }
+            })
+            .collect();
+
+        if !self.used_generics.is_empty() {
+            arms.push(quote! { _Phantom(_) => unreachable!("Phantom message should not be constructed.") });
+        }
+
+        arms
+    }
+
     pub fn as_names_snake_cased(&self) -> Vec<String> {
         self.variants
             .iter()
@@ -284,7 +528,7 @@ where
         self.variants.iter().map(MsgVariant::emit)
     }
 
-    pub fn get_only_variant(&self) -> Option<&MsgVariant> {
+    pub fn get_only_variant(&self) -> Option<&MsgVariant<'_>> {
         self.variants.first()
     }
 
@@ -72,6 +72,25 @@ pub fn extract_return_type(ret_type: &ReturnType) -> &Path {
     &type_path.path
 }
 
+/// Extracts the item type `T` from the `Page<T>` returned by the paginated query.
+/// Returns [`None`] if the type is not a `Page`.
+pub fn extract_page_item_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Page" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(item)) if args.args.len() == 1 => Some(item),
+        _ => None,
+    }
+}
+
 /// Creates [`Option<WhereClause>`] based on the provided predicates.
 /// Returns [`None`] if predicates array is empty.
 pub fn as_where_clause(where_predicates: &[&WherePredicate]) -> Option<WhereClause> {
@@ -119,3 +138,16 @@ impl SvCasing for Ident {
         Ident::new(new_name, self.span())
     }
 }
+
+/// Computes the 32-bit FNV-1a hash of the `value`.
+///
+/// Used to derive stable identifiers, like the interface reply namespace, which
+/// have to be the same between compilations.
+pub fn fnv1a_hash(value: &str) -> u32 {
+    const OFFSET_BASIS: u32 = 0x811c9dc5;
+    const PRIME: u32 = 0x01000193;
+
+    value.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(PRIME)
+    })
+}
@@ -0,0 +1,65 @@
+//! Module providing utilities used by the `sv::access` attribute.
+//!
+//! Exec methods marked with `#[sv::access(...)]` verify the sender before the method is called.
+//! If the verification fails, the dispatch returns the [Unauthorized] error converted into
+//! the contract error type, so the contract error has to implement `From<Unauthorized>`.
+//! [StdError] already does.
+//!
+//! Supported checks:
+//!     - `owner` - the sender has to be the owner returned by the [Owned] trait implemented on the contract,
+//!     - `check = path::to::function` - the sender is verified by the function with the
+//!       `fn(&Contract, &ExecCtx<Q>) -> Result<bool, E>` signature. `Self::method` paths
+//!       call the method of the contract.
+//!
+//! All the checks have to pass if multiple are provided.
+use std::fmt;
+
+use cosmwasm_std::{Addr, StdError, StdResult, Storage};
+
+/// Provides the owner of the contract for the `#[sv::access(owner)]` check.
+pub trait Owned {
+    /// Returns the current owner of the contract or `None` if the contract has no owner.
+    fn owner(&self, storage: &dyn Storage) -> StdResult<Option<Addr>>;
+}
+
+/// Checks if the `sender` is the owner of the `contract`.
+pub fn is_owner<Contract: Owned + ?Sized>(
+    contract: &Contract,
+    storage: &dyn Storage,
+    sender: &Addr,
+) -> StdResult<bool> {
+    Ok(contract.owner(storage)?.as_ref() == Some(sender))
+}
+
+/// Error returned when the sender failed the `sv::access` checks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unauthorized {
+    /// Name of the method the sender tried to call.
+    pub method: &'static str,
+    /// Address of the sender.
+    pub sender: Addr,
+}
+
+impl Unauthorized {
+    pub fn new(method: &'static str, sender: Addr) -> Self {
+        Self { method, sender }
+    }
+}
+
+impl fmt::Display for Unauthorized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Sender {} is not authorized to call `{}`.",
+            self.sender, self.method
+        )
+    }
+}
+
+impl std::error::Error for Unauthorized {}
+
+impl From<Unauthorized> for StdError {
+    fn from(err: Unauthorized) -> Self {
+        StdError::generic_err(err.to_string())
+    }
+}
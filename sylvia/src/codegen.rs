@@ -0,0 +1,607 @@
+//! Module providing generation of the TypeScript clients from the [ContractSchema].
+//!
+//! The generated module contains:
+//!     - type definitions of every type used in the messages and responses,
+//!     - `<Name>QueryClient` class with a method per query returning the typed response,
+//!     - `<Name>Client` class extending the query client with a method per execute message,
+//!
+//! emitted for the contract itself, covering every message the contract accepts,
+//! and for each interface implemented on the contract.
+//!
+//! Types are emitted under the names of the Rust types, shared between the contract
+//! and its interfaces. Two different types of the same name result in the [DefinitionConflict].
+//!
+//! The clients rely only on the `queryContractSmart` and `execute` methods, so they work
+//! with the `CosmWasmClient` and `SigningCosmWasmClient` from `@cosmjs/cosmwasm-stargate`.
+//!
+//! Typical `src/bin/codegen.rs` of the contract:
+//!
+//! ```rust,ignore
+//! fn main() {
+//!     let schema = my_contract::contract::entry_points::schema();
+//!     sylvia::codegen::write_typescript(&schema);
+//! }
+//! ```
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Write};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use cosmwasm_schema::Api;
+use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec};
+
+use crate::schema::ContractSchema;
+
+const HEADER: &str = r#"// This file was automatically generated by sylvia.
+// DO NOT MODIFY IT BY HAND. Regenerate it from the contract instead.
+
+export type CosmWasmFunds = readonly { denom: string; amount: string }[];
+
+export type CosmWasmFee = number | "auto" | { amount: CosmWasmFunds; gas: string };
+
+export interface CosmWasmQueryClient {
+  queryContractSmart(contractAddress: string, query: unknown): Promise<any>;
+}
+
+export interface CosmWasmSigningClient extends CosmWasmQueryClient {
+  execute(
+    senderAddress: string,
+    contractAddress: string,
+    msg: unknown,
+    fee: CosmWasmFee,
+    memo?: string,
+    funds?: CosmWasmFunds,
+  ): Promise<unknown>;
+}
+"#;
+
+/// Error returned when the schemas define two different types under the same name.
+///
+/// TypeScript types are emitted under the names of the Rust types, so such types have to be
+/// renamed, i.e. with `#[schemars(rename = "...")]`, before generating the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefinitionConflict {
+    /// Name shared by the conflicting types.
+    pub name: String,
+}
+
+impl fmt::Display for DefinitionConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Conflicting definitions of the `{}` type in the contract schema",
+            self.name
+        )
+    }
+}
+
+impl std::error::Error for DefinitionConflict {}
+
+/// Generates the TypeScript module with clients of the contract and its interfaces.
+///
+/// Fails if two different types used in the messages share the same name.
+pub fn typescript(schema: &ContractSchema) -> Result<String, DefinitionConflict> {
+    let mut definitions = BTreeMap::new();
+    let apis = std::iter::once((schema.api.contract_name.as_str(), &schema.api)).chain(
+        schema
+            .interfaces
+            .iter()
+            .map(|(name, api)| (name.as_str(), api)),
+    );
+
+    let clients = apis
+        .map(|(name, api)| {
+            collect_definitions(api, &mut definitions)?;
+            Ok(emit_clients(&pascal_case(name), api))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (name, definition) in schema
+        .cw20_receive
+        .iter()
+        .flat_map(|root| &root.definitions)
+    {
+        insert_definition(&mut definitions, name, definition)?;
+    }
+
+    let mut out = HEADER.to_owned();
+
+    for (name, definition) in &definitions {
+        out.push('\n');
+        emit_doc(&mut out, "", description(definition));
+        let _ = writeln!(
+            out,
+            "export type {} = {};",
+            identifier(name),
+            ts_type(definition)
+        );
+    }
+
+    for root in [
+        &schema.api.instantiate,
+        &schema.api.migrate,
+        &schema.cw20_receive,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        let Some(title) = title(&root.schema) else {
+            continue;
+        };
+        let name = format!("{}{}", pascal_case(&schema.api.contract_name), title);
+        let _ = writeln!(
+            out,
+            "\nexport type {} = {};",
+            identifier(&name),
+            ts_type_object(&root.schema)
+        );
+    }
+
+    for client in clients {
+        out.push('\n');
+        out.push_str(&client);
+    }
+
+    Ok(out)
+}
+
+/// Writes the TypeScript module to `<out_dir>/<contract_name>.ts` and returns its path.
+pub fn write_typescript_to(
+    schema: &ContractSchema,
+    out_dir: impl AsRef<Path>,
+) -> io::Result<PathBuf> {
+    let out_dir = out_dir.as_ref();
+    std::fs::create_dir_all(out_dir)?;
+
+    let path = out_dir.join(format!("{}.ts", schema.api.contract_name));
+    let module =
+        typescript(schema).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    std::fs::write(&path, module)?;
+    Ok(path)
+}
+
+/// Writes the TypeScript module into the `ts` directory in the current working directory.
+///
+/// Meant to be called from the binary target of the contract.
+///
+/// # Panics
+///
+/// Panics if the module could not be written.
+pub fn write_typescript(schema: &ContractSchema) {
+    let out_dir = std::env::current_dir()
+        .expect("Failed to read the current directory")
+        .join("ts");
+
+    let path = write_typescript_to(schema, out_dir).expect("Failed to write the TypeScript client");
+    println!("Exported {}", path.display());
+}
+
+/// Single message variant, i.e. `{ "name": { ...args } }` or `"name"` for the unit variants.
+struct Variant<'a> {
+    name: String,
+    args: Option<&'a SchemaObject>,
+    description: Option<String>,
+}
+
+fn collect_definitions(
+    api: &Api,
+    definitions: &mut BTreeMap<String, Schema>,
+) -> Result<(), DefinitionConflict> {
+    let roots = [
+        &api.instantiate,
+        &api.execute,
+        &api.query,
+        &api.migrate,
+        &api.sudo,
+    ]
+    .into_iter()
+    .flatten()
+    .chain(api.responses.iter().flat_map(BTreeMap::values));
+
+    for root in roots {
+        for (name, definition) in &root.definitions {
+            insert_definition(definitions, name, definition)?;
+        }
+    }
+
+    for response in api.responses.iter().flat_map(BTreeMap::values) {
+        if let Some(name) = response_type_name(response) {
+            insert_definition(definitions, name, &Schema::Object(response.schema.clone()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the definition unless it's already present.
+///
+/// Definitions are compared without their titles, as only the root schemas are titled.
+fn insert_definition(
+    definitions: &mut BTreeMap<String, Schema>,
+    name: &str,
+    definition: &Schema,
+) -> Result<(), DefinitionConflict> {
+    match definitions.get(name) {
+        Some(existing) if untitled(existing) != untitled(definition) => Err(DefinitionConflict {
+            name: name.to_owned(),
+        }),
+        Some(_) => Ok(()),
+        None => {
+            definitions.insert(name.to_owned(), definition.clone());
+            Ok(())
+        }
+    }
+}
+
+fn untitled(schema: &Schema) -> Schema {
+    let mut schema = schema.clone();
+    if let Schema::Object(SchemaObject {
+        metadata: Some(metadata),
+        ..
+    }) = &mut schema
+    {
+        metadata.title = None;
+    }
+    schema
+}
+
+/// Name under which the response type is emitted.
+///
+/// Only the structured responses are named, primitive types like `uint64` are inlined.
+fn response_type_name(response: &RootSchema) -> Option<&str> {
+    let schema = &response.schema;
+    let structured = schema.subschemas.is_some()
+        || schema.enum_values.is_some()
+        || matches!(
+            schema.instance_type,
+            Some(SingleOrVec::Single(ref instance_type)) if **instance_type == InstanceType::Object
+        );
+
+    title(schema).filter(|title| structured && is_identifier(title))
+}
+
+fn response_type(response: &RootSchema) -> String {
+    match response_type_name(response) {
+        Some(name) => identifier(name),
+        None => ts_type_object(&response.schema),
+    }
+}
+
+fn emit_clients(name: &str, api: &Api) -> String {
+    let mut out = String::new();
+    let query_client = format!("{name}QueryClient");
+
+    let _ = writeln!(out, "export class {query_client} {{");
+    let _ = writeln!(
+        out,
+        "  constructor(\n    readonly client: CosmWasmQueryClient,\n    readonly contractAddress: string,\n  ) {{}}"
+    );
+    for variant in api.query.as_ref().map(variants).unwrap_or_default() {
+        let response = api
+            .responses
+            .as_ref()
+            .and_then(|responses| responses.get(&variant.name))
+            .map(response_type)
+            .unwrap_or_else(|| "unknown".to_owned());
+
+        out.push('\n');
+        emit_doc(&mut out, "  ", variant.description.as_deref());
+        let _ = writeln!(
+            out,
+            "  async {}({}): Promise<{}> {{\n    return this.client.queryContractSmart(this.contractAddress, {});\n  }}",
+            camel_case(&variant.name),
+            emit_args(&variant),
+            response,
+            emit_msg(&variant)
+        );
+    }
+    let _ = writeln!(out, "}}\n");
+
+    let _ = writeln!(out, "export class {name}Client extends {query_client} {{");
+    let _ = writeln!(
+        out,
+        "  constructor(\n    readonly client: CosmWasmSigningClient,\n    readonly contractAddress: string,\n  ) {{\n    super(client, contractAddress);\n  }}"
+    );
+    for variant in api.execute.as_ref().map(variants).unwrap_or_default() {
+        let args = match emit_args(&variant) {
+            args if args.is_empty() => String::new(),
+            args => format!("\n    {args},"),
+        };
+
+        out.push('\n');
+        emit_doc(&mut out, "  ", variant.description.as_deref());
+        let _ = writeln!(
+            out,
+            "  async {}(\n    senderAddress: string,{}\n    fee: CosmWasmFee = \"auto\",\n    memo?: string,\n    funds?: CosmWasmFunds,\n  ): Promise<unknown> {{\n    return this.client.execute(senderAddress, this.contractAddress, {}, fee, memo, funds);\n  }}",
+            camel_case(&variant.name),
+            args,
+            emit_msg(&variant)
+        );
+    }
+    let _ = writeln!(out, "}}");
+
+    out
+}
+
+fn emit_args(variant: &Variant) -> String {
+    match variant.args {
+        Some(args) if has_properties(args) => format!("args: {}", ts_type_object(args)),
+        _ => String::new(),
+    }
+}
+
+fn emit_msg(variant: &Variant) -> String {
+    let name = quote(&variant.name);
+    match variant.args {
+        Some(args) if has_properties(args) => format!("{{ {name}: args }}"),
+        Some(_) => format!("{{ {name}: {{}} }}"),
+        None => name,
+    }
+}
+
+fn emit_doc(out: &mut String, indent: &str, description: Option<&str>) {
+    let Some(description) = description else {
+        return;
+    };
+
+    let _ = writeln!(out, "{indent}/**");
+    for line in description.lines() {
+        let line = format!("{indent} * {}", line.replace("*/", "*\\/"));
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    let _ = writeln!(out, "{indent} */");
+}
+
+/// Collects the variants of the message, looking through the references and the untagged
+/// unions of the glue messages.
+fn variants(root: &RootSchema) -> Vec<Variant<'_>> {
+    let mut variants = vec![];
+    let mut visited = BTreeSet::new();
+    collect_variants(&root.schema, root, &mut visited, &mut variants);
+
+    let mut names = BTreeSet::new();
+    variants.retain(|variant| names.insert(variant.name.clone()));
+    variants
+}
+
+fn collect_variants<'a>(
+    schema: &'a SchemaObject,
+    root: &'a RootSchema,
+    visited: &mut BTreeSet<&'a str>,
+    variants: &mut Vec<Variant<'a>>,
+) {
+    if let Some(reference) = &schema.reference {
+        let name = definition_name(reference);
+        if visited.insert(name) {
+            if let Some(Schema::Object(definition)) = root.definitions.get(name) {
+                collect_variants(definition, root, visited, variants);
+            }
+        }
+        return;
+    }
+
+    if let Some(subschemas) = &schema.subschemas {
+        let alternatives = subschemas.one_of.iter().chain(&subschemas.any_of).flatten();
+        for alternative in alternatives {
+            if let Schema::Object(alternative) = alternative {
+                collect_variants(alternative, root, visited, variants);
+            }
+        }
+    }
+
+    for value in schema.enum_values.iter().flatten() {
+        if let Some(name) = value.as_str() {
+            variants.push(Variant {
+                name: name.to_owned(),
+                args: None,
+                description: description_object(schema).map(ToOwned::to_owned),
+            });
+        }
+    }
+
+    let Some(object) = &schema.object else {
+        return;
+    };
+    if object.properties.len() != 1 {
+        return;
+    }
+    let Some((name, Schema::Object(args))) = object.properties.iter().next() else {
+        return;
+    };
+    if object.required.contains(name) {
+        let args = match &args.reference {
+            Some(reference) => match root.definitions.get(definition_name(reference)) {
+                Some(Schema::Object(definition)) => definition,
+                _ => args,
+            },
+            None => args,
+        };
+        variants.push(Variant {
+            name: name.clone(),
+            args: Some(args),
+            description: description_object(schema).map(ToOwned::to_owned),
+        });
+    }
+}
+
+fn ts_type(schema: &Schema) -> String {
+    match schema {
+        Schema::Bool(true) => "unknown".to_owned(),
+        Schema::Bool(false) => "never".to_owned(),
+        Schema::Object(schema) => ts_type_object(schema),
+    }
+}
+
+fn ts_type_object(schema: &SchemaObject) -> String {
+    if let Some(reference) = &schema.reference {
+        return identifier(definition_name(reference));
+    }
+
+    if let Some(value) = &schema.const_value {
+        return value.to_string();
+    }
+
+    if let Some(values) = &schema.enum_values {
+        return match values.is_empty() {
+            true => "never".to_owned(),
+            false => union(values.iter().map(ToString::to_string)),
+        };
+    }
+
+    if let Some(subschemas) = &schema.subschemas {
+        if let Some(alternatives) = subschemas.one_of.as_ref().or(subschemas.any_of.as_ref()) {
+            return union(alternatives.iter().map(ts_type));
+        }
+        if let Some(all_of) = &subschemas.all_of {
+            let types: Vec<_> = all_of.iter().map(ts_type).map(parenthesize).collect();
+            return types.join(" & ");
+        }
+    }
+
+    match &schema.instance_type {
+        Some(SingleOrVec::Single(instance_type)) => ts_instance_type(schema, instance_type),
+        Some(SingleOrVec::Vec(instance_types)) => union(
+            instance_types
+                .iter()
+                .map(|instance_type| ts_instance_type(schema, instance_type)),
+        ),
+        None => "unknown".to_owned(),
+    }
+}
+
+fn ts_instance_type(schema: &SchemaObject, instance_type: &InstanceType) -> String {
+    match instance_type {
+        InstanceType::Null => "null".to_owned(),
+        InstanceType::Boolean => "boolean".to_owned(),
+        InstanceType::Integer | InstanceType::Number => "number".to_owned(),
+        InstanceType::String => "string".to_owned(),
+        InstanceType::Array => match schema.array.as_ref().and_then(|array| array.items.as_ref()) {
+            Some(SingleOrVec::Single(item)) => format!("{}[]", parenthesize(ts_type(item))),
+            Some(SingleOrVec::Vec(items)) => {
+                let items: Vec<_> = items.iter().map(ts_type).collect();
+                format!("[{}]", items.join(", "))
+            }
+            None => "unknown[]".to_owned(),
+        },
+        InstanceType::Object => {
+            let Some(object) = &schema.object else {
+                return "Record<string, never>".to_owned();
+            };
+
+            if object.properties.is_empty() {
+                return match &object.additional_properties {
+                    Some(values) => format!("Record<string, {}>", ts_type(values)),
+                    None => "Record<string, never>".to_owned(),
+                };
+            }
+
+            let properties: Vec<_> = object
+                .properties
+                .iter()
+                .map(|(name, property)| {
+                    let optional = match object.required.contains(name) {
+                        true => "",
+                        false => "?",
+                    };
+                    format!("{}{optional}: {}", property_name(name), ts_type(property))
+                })
+                .collect();
+            format!("{{ {} }}", properties.join("; "))
+        }
+    }
+}
+
+fn union(types: impl Iterator<Item = String>) -> String {
+    let mut unique = vec![];
+    for ty in types {
+        if !unique.contains(&ty) {
+            unique.push(ty);
+        }
+    }
+    unique.join(" | ")
+}
+
+fn parenthesize(ty: String) -> String {
+    match ty.contains(" | ") || ty.contains(" & ") {
+        true => format!("({ty})"),
+        false => ty,
+    }
+}
+
+fn has_properties(schema: &SchemaObject) -> bool {
+    schema
+        .object
+        .as_ref()
+        .is_some_and(|object| !object.properties.is_empty())
+}
+
+fn title(schema: &SchemaObject) -> Option<&str> {
+    schema.metadata.as_ref()?.title.as_deref()
+}
+
+fn description(schema: &Schema) -> Option<&str> {
+    match schema {
+        Schema::Object(schema) => description_object(schema),
+        Schema::Bool(_) => None,
+    }
+}
+
+fn description_object(schema: &SchemaObject) -> Option<&str> {
+    schema.metadata.as_ref()?.description.as_deref()
+}
+
+fn definition_name(reference: &str) -> &str {
+    reference
+        .strip_prefix("#/definitions/")
+        .unwrap_or(reference)
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| match c.is_ascii_alphanumeric() || c == '_' {
+            true => c,
+            false => '_',
+        })
+        .collect()
+}
+
+fn property_name(name: &str) -> String {
+    match is_identifier(name) {
+        true => name.to_owned(),
+        false => quote(name),
+    }
+}
+
+fn quote(name: &str) -> String {
+    format!("{name:?}")
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
@@ -1,6 +1,8 @@
 //! Module for context types used in method signatures.
 
-use cosmwasm_std::{Deps, DepsMut, Empty, Env, Event, MessageInfo, MsgResponse};
+use cosmwasm_std::{Addr, Deps, DepsMut, Empty, Env, Event, MessageInfo, MsgResponse, Uint128};
+
+use crate::pagination::Pagination;
 
 /// Represantation of `reply` context received in entry point.
 #[non_exhaustive]
@@ -27,6 +29,19 @@ pub struct ExecCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub info: MessageInfo,
 }
 
+/// Representation of the cw20 `Receive` message context received in `execute` entry point.
+///
+/// `info.sender` is the address of the cw20 token contract, while `sender` is the account
+/// which sent the `amount` of tokens.
+#[non_exhaustive]
+pub struct Cw20ReceiveCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+    pub info: MessageInfo,
+    pub sender: Addr,
+    pub amount: Uint128,
+}
+
 /// Represantation of `instantiate` context received in entry point.
 #[non_exhaustive]
 pub struct InstantiateCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
@@ -40,6 +55,9 @@ pub struct InstantiateCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
 pub struct QueryCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
     pub deps: Deps<'a, C>,
     pub env: Env,
+    /// Pagination parameters of the `#[sv::msg(query, paginated)]` queries.
+    /// Default for the other queries.
+    pub pagination: Pagination,
 }
 
 /// Represantation of `sudo` context received in entry point.
@@ -112,6 +130,22 @@ impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env, MessageInfo)>
     }
 }
 
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env, MessageInfo, Addr, Uint128)>
+    for Cw20ReceiveCtx<'a, C>
+{
+    fn from(
+        (deps, env, info, sender, amount): (DepsMut<'a, C>, Env, MessageInfo, Addr, Uint128),
+    ) -> Self {
+        Self {
+            deps,
+            env,
+            info,
+            sender,
+            amount,
+        }
+    }
+}
+
 impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env, MessageInfo)>
     for InstantiateCtx<'a, C>
 {
@@ -122,7 +156,11 @@ impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env, MessageInfo)>
 
 impl<'a, C: cosmwasm_std::CustomQuery> From<(Deps<'a, C>, Env)> for QueryCtx<'a, C> {
     fn from((deps, env): (Deps<'a, C>, Env)) -> Self {
-        Self { deps, env }
+        Self {
+            deps,
+            env,
+            pagination: Pagination::default(),
+        }
     }
 }
 
@@ -131,3 +169,99 @@ impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for SudoCtx<'
         Self { deps, env }
     }
 }
+
+/// Representation of `ibc_channel_open` context received in entry point.
+#[cfg_attr(docsrs, doc(cfg(feature = "stargate")))]
+#[cfg(feature = "stargate")]
+#[non_exhaustive]
+pub struct IbcChannelOpenCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Representation of `ibc_channel_connect` context received in entry point.
+#[cfg_attr(docsrs, doc(cfg(feature = "stargate")))]
+#[cfg(feature = "stargate")]
+#[non_exhaustive]
+pub struct IbcChannelConnectCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Representation of `ibc_channel_close` context received in entry point.
+#[cfg_attr(docsrs, doc(cfg(feature = "stargate")))]
+#[cfg(feature = "stargate")]
+#[non_exhaustive]
+pub struct IbcChannelCloseCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Representation of `ibc_packet_receive` context received in entry point.
+#[cfg_attr(docsrs, doc(cfg(feature = "stargate")))]
+#[cfg(feature = "stargate")]
+#[non_exhaustive]
+pub struct IbcPacketReceiveCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Representation of `ibc_packet_ack` context received in entry point.
+#[cfg_attr(docsrs, doc(cfg(feature = "stargate")))]
+#[cfg(feature = "stargate")]
+#[non_exhaustive]
+pub struct IbcPacketAckCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+/// Representation of `ibc_packet_timeout` context received in entry point.
+#[cfg_attr(docsrs, doc(cfg(feature = "stargate")))]
+#[cfg(feature = "stargate")]
+#[non_exhaustive]
+pub struct IbcPacketTimeoutCtx<'a, C: cosmwasm_std::CustomQuery = Empty> {
+    pub deps: DepsMut<'a, C>,
+    pub env: Env,
+}
+
+#[cfg(feature = "stargate")]
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcChannelOpenCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+#[cfg(feature = "stargate")]
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcChannelConnectCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+#[cfg(feature = "stargate")]
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcChannelCloseCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+#[cfg(feature = "stargate")]
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcPacketReceiveCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+#[cfg(feature = "stargate")]
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcPacketAckCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
+
+#[cfg(feature = "stargate")]
+impl<'a, C: cosmwasm_std::CustomQuery> From<(DepsMut<'a, C>, Env)> for IbcPacketTimeoutCtx<'a, C> {
+    fn from((deps, env): (DepsMut<'a, C>, Env)) -> Self {
+        Self { deps, env }
+    }
+}
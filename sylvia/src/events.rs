@@ -0,0 +1,222 @@
+//! Module providing the typed events generated by the [event](crate::event) macro.
+//!
+//! The macro implements [TypedEvent] for the struct, converting it into the [Event] with
+//! the stable type name and an attribute per field. The type name is the snake cased name
+//! of the struct unless provided with `#[event(name = "...")]`.
+//!
+//! Attribute values are the JSON representation of the fields with the strings unquoted,
+//! so addresses, amounts and other string-like values look the same as if they were added
+//! by hand. Fields equal to `None` are omitted.
+//!
+//! ```rust
+//! use sylvia::cw_schema::cw_serde;
+//! use sylvia::cw_std::{Addr, Uint128};
+//! use sylvia::event;
+//! use sylvia::events::TypedEvent;
+//!
+//! #[cw_serde]
+//! #[event]
+//! pub struct Transfer {
+//!     pub recipient: Addr,
+//!     pub amount: Uint128,
+//!     pub memo: Option<String>,
+//! }
+//!
+//! let transfer = Transfer {
+//!     recipient: Addr::unchecked("recipient"),
+//!     amount: Uint128::new(100),
+//!     memo: None,
+//! };
+//! let event = transfer.to_event().unwrap();
+//! assert_eq!(event.ty, "transfer");
+//! assert_eq!(event.attributes[1].value, "100");
+//! assert_eq!(Transfer::from_event(&event).unwrap(), transfer);
+//! ```
+//!
+//! The contract lists its events with the `#[sv::events(...)]` attribute. They are returned by
+//! the generated `sv::events` function and included in the schema of the contract
+//! built with [event_schema].
+use cosmwasm_std::{Event, StdError, StdResult};
+use schemars::schema::{InstanceType, Metadata, RootSchema, Schema, SchemaObject};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Description of the attribute values added to the schemas of the events.
+const ATTRIBUTES_ENCODING: &str = "Attribute values are strings. Values serialized as JSON \
+    strings are stored unquoted and other values as their JSON representation. Attributes \
+    equal to `null` are omitted. The `contentSchema` of the attribute describes the stored value.";
+
+/// Prefix added by the chain to the types of the events emitted by the contracts.
+pub const WASM_EVENT_PREFIX: &str = "wasm-";
+
+/// Struct convertible into the [Event] and back.
+pub trait TypedEvent: Sized {
+    /// Type of the event before the chain prefixes it with `wasm-`.
+    const EVENT_TYPE: &'static str;
+
+    /// Converts the struct into the event with an attribute per field.
+    fn to_event(&self) -> StdResult<Event>;
+
+    /// Parses the event of the [Self::EVENT_TYPE] type. Unknown attributes, like
+    /// the `_contract_address` added by the chain, are ignored.
+    fn from_event(event: &Event) -> StdResult<Self>;
+}
+
+/// Checks if the `event` has the `event_type`, with or without the `wasm-` prefix.
+pub fn is_event_type(event: &Event, event_type: &str) -> bool {
+    event
+        .ty
+        .strip_prefix(WASM_EVENT_PREFIX)
+        .unwrap_or(&event.ty)
+        == event_type
+}
+
+/// Verifies that the `event` has the `event_type`, with or without the `wasm-` prefix.
+pub fn ensure_event_type(event: &Event, event_type: &str) -> StdResult<()> {
+    match is_event_type(event, event_type) {
+        true => Ok(()),
+        false => Err(StdError::generic_err(format!(
+            "Expected `{event_type}` event, received `{}`",
+            event.ty
+        ))),
+    }
+}
+
+/// Converts the `value` into the attribute value. Returns `None` for the values serialized
+/// as `null`, which are not added to the event.
+pub fn attribute_value<T: Serialize>(value: &T) -> StdResult<Option<String>> {
+    let value = serde_json::to_value(value).map_err(|err| StdError::serialize_err("Event", err))?;
+    match value {
+        Value::Null => Ok(None),
+        Value::String(value) => Ok(Some(value)),
+        value => Ok(Some(value.to_string())),
+    }
+}
+
+/// Schema of the event attributes.
+///
+/// Every field of the event is described as the string attribute with the schema of the field
+/// as its `contentSchema`. The encoding of the values is described in the schema description.
+pub fn event_schema<T: JsonSchema>() -> RootSchema {
+    let mut schema = schemars::schema_for!(T);
+
+    let metadata = schema.schema.metadata();
+    metadata.description = Some(match metadata.description.take() {
+        Some(description) => format!("{description}\n\n{ATTRIBUTES_ENCODING}"),
+        None => ATTRIBUTES_ENCODING.to_owned(),
+    });
+
+    for property in schema.schema.object().properties.values_mut() {
+        *property = attribute_schema(property);
+    }
+    schema
+}
+
+/// String schema of the attribute storing the value described by the `value` schema.
+fn attribute_schema(value: &Schema) -> Schema {
+    let description = match value {
+        Schema::Object(value) => value
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.description.clone()),
+        Schema::Bool(_) => None,
+    };
+    let extensions = serde_json::to_value(value)
+        .map(|value| [("contentSchema".to_owned(), value)].into())
+        .unwrap_or_default();
+
+    Schema::Object(SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        metadata: Some(Box::new(Metadata {
+            description,
+            ..Default::default()
+        })),
+        extensions,
+        ..Default::default()
+    })
+}
+
+/// Parses the attribute `key` of the `event`. Missing attributes are parsed from `null`.
+pub fn parse_attribute<T: DeserializeOwned>(event: &Event, key: &str) -> StdResult<T> {
+    let value = event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str());
+    let parse_err = |err: serde_json::Error| StdError::parse_err(key, err);
+
+    let Some(value) = value else {
+        return serde_json::from_value(Value::Null).map_err(parse_err);
+    };
+
+    // String-like values are stored unquoted. Other values are stored as JSON.
+    serde_json::from_value(Value::String(value.to_owned()))
+        .or_else(|_| serde_json::from_str(value))
+        .map_err(parse_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Uint128};
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Kind {
+        Mint,
+        Burn,
+    }
+
+    #[test]
+    fn attribute_values() {
+        let event = Event::new("wasm-transfer")
+            .add_attribute(
+                "addr",
+                attribute_value(&Addr::unchecked("addr")).unwrap().unwrap(),
+            )
+            .add_attribute(
+                "amount",
+                attribute_value(&Uint128::new(10)).unwrap().unwrap(),
+            )
+            .add_attribute("height", attribute_value(&5u64).unwrap().unwrap())
+            .add_attribute("name", attribute_value(&"10").unwrap().unwrap())
+            .add_attribute("kind", attribute_value(&Kind::Burn).unwrap().unwrap())
+            .add_attribute("list", attribute_value(&vec![1, 2]).unwrap().unwrap());
+        assert_eq!(attribute_value(&None::<u64>).unwrap(), None);
+
+        let values: Vec<_> = event.attributes.iter().map(|attr| &attr.value).collect();
+        assert_eq!(values, ["addr", "10", "5", "10", "burn", "[1,2]"]);
+
+        assert_eq!(
+            parse_attribute::<Addr>(&event, "addr").unwrap(),
+            Addr::unchecked("addr")
+        );
+        assert_eq!(
+            parse_attribute::<Uint128>(&event, "amount").unwrap(),
+            Uint128::new(10)
+        );
+        assert_eq!(parse_attribute::<u64>(&event, "height").unwrap(), 5);
+        assert_eq!(
+            parse_attribute::<Option<u64>>(&event, "height").unwrap(),
+            Some(5)
+        );
+        assert_eq!(parse_attribute::<String>(&event, "name").unwrap(), "10");
+        assert_eq!(parse_attribute::<Kind>(&event, "kind").unwrap(), Kind::Burn);
+        assert_eq!(parse_attribute::<Vec<u8>>(&event, "list").unwrap(), [1, 2]);
+        assert_eq!(
+            parse_attribute::<Option<Kind>>(&event, "missing").unwrap(),
+            None
+        );
+        parse_attribute::<Kind>(&event, "missing").unwrap_err();
+        parse_attribute::<Kind>(&event, "name").unwrap_err();
+        assert_ne!(Kind::Mint, Kind::Burn);
+
+        ensure_event_type(&event, "transfer").unwrap();
+        ensure_event_type(&Event::new("transfer"), "transfer").unwrap();
+        ensure_event_type(&event, "mint").unwrap_err();
+    }
+}
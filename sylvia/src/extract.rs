@@ -0,0 +1,74 @@
+//! Module providing the extractors of the `exec` handler parameters.
+//!
+//! Parameters of the `exec` methods marked with `#[sv::extract]` are not a part of the message.
+//! The generated `dispatch` fills them with the [FromExecCtx] implementation of their type
+//! before the method is called. Extraction errors are converted into the contract error.
+//!
+//! ```rust
+//! use cw_storage_plus::Item;
+//! use sylvia::contract;
+//! use sylvia::ctx::{ExecCtx, InstantiateCtx};
+//! use sylvia::cw_schema::cw_serde;
+//! use sylvia::cw_std::{Addr, CustomQuery, StdError, StdResult, Response};
+//! use sylvia::extract::FromExecCtx;
+//!
+//! #[cw_serde]
+//! pub struct Config {
+//!     pub admin: Addr,
+//! }
+//!
+//! impl<Q: CustomQuery> FromExecCtx<Contract, Q> for Config {
+//!     type Error = StdError;
+//!
+//!     fn from_exec_ctx(contract: &Contract, ctx: &ExecCtx<Q>) -> StdResult<Self> {
+//!         contract.config.load(ctx.deps.storage)
+//!     }
+//! }
+//!
+//! pub struct Contract {
+//!     config: Item<Config>,
+//! }
+//!
+//! #[contract]
+//! impl Contract {
+//!     pub const fn new() -> Self {
+//!         Self {
+//!             config: Item::new("config"),
+//!         }
+//!     }
+//!
+//!     #[sv::msg(instantiate)]
+//!     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+//!         self.config.save(ctx.deps.storage, &Config { admin: ctx.info.sender })?;
+//!         Ok(Response::new())
+//!     }
+//!
+//!     #[sv::msg(exec)]
+//!     fn update(
+//!         &self,
+//!         ctx: ExecCtx,
+//!         #[sv::extract] config: Config,
+//!         value: u64,
+//!     ) -> StdResult<Response> {
+//!         Ok(Response::new().add_attribute("admin", config.admin))
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! The generated `ExecMsg::Update` variant contains only the `value` field.
+use cosmwasm_std::{CustomQuery, Empty};
+
+use crate::ctx::ExecCtx;
+
+/// Type filled from the context of the `exec` message by the generated `dispatch`.
+///
+/// `Contract` is the contract handling the message, so the extractors can load
+/// the state from its storage accessors.
+pub trait FromExecCtx<Contract: ?Sized, Q: CustomQuery = Empty>: Sized {
+    /// Error returned if the value can't be extracted.
+    /// The contract error has to implement `From<Self::Error>`.
+    type Error;
+
+    fn from_exec_ctx(contract: &Contract, ctx: &ExecCtx<Q>) -> Result<Self, Self::Error>;
+}
@@ -0,0 +1,56 @@
+//! Module providing utilities used by the `payable` and `nonpayable` arguments
+//! of the `sv::msg` attribute.
+//!
+//! The `exec` and `instantiate` methods can declare the funds they accept:
+//!     - `#[sv::msg(exec, nonpayable)]` - the message rejects any funds,
+//!     - `#[sv::msg(exec, payable)]` - the message accepts funds in any denom,
+//!     - `#[sv::msg(exec, payable = ["uatom", "ujuno"])]` - the message accepts funds only
+//!       in the listed denoms.
+//!
+//! Contracts with the `#[sv::features(nonpayable)]` attribute reject funds sent with
+//! all the `exec` messages not marked as `payable`.
+//!
+//! The requirement is verified by the generated `dispatch` before the method is called.
+//! Checks fail with the [PaymentError], so the contract error has to implement
+//! `From<PaymentError>`, as well as the error of the interfaces declaring the funds.
+//!
+//! The `payable` messages only restrict the denoms of the funds, sending no funds is allowed.
+//! Handlers requiring the payment verify it with the [cw_utils::must_pay].
+use cosmwasm_std::MessageInfo;
+use cw_utils::PaymentError;
+
+/// Verifies that the funds sent with the message are only in the `denoms`.
+pub fn payable_in(info: &MessageInfo, denoms: &[&str]) -> Result<(), PaymentError> {
+    match info
+        .funds
+        .iter()
+        .find(|coin| !denoms.contains(&coin.denom.as_str()))
+    {
+        Some(coin) => Err(PaymentError::ExtraDenom(coin.denom.clone())),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::message_info;
+    use cosmwasm_std::{coin, Addr};
+
+    use super::*;
+
+    #[test]
+    fn payable_in_denoms() {
+        let sender = Addr::unchecked("sender");
+        let no_funds = message_info(&sender, &[]);
+        let atoms = message_info(&sender, &[coin(10, "uatom")]);
+        let mixed = message_info(&sender, &[coin(10, "uatom"), coin(5, "ujuno")]);
+
+        payable_in(&no_funds, &["uatom"]).unwrap();
+        payable_in(&atoms, &["uatom"]).unwrap();
+        payable_in(&mixed, &["uatom", "ujuno"]).unwrap();
+        assert_eq!(
+            payable_in(&mixed, &["uatom"]).unwrap_err(),
+            PaymentError::ExtraDenom("ujuno".to_owned())
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! Module providing the [Introspection] interface.
+//!
+//! The interface answers the question "which interfaces does this contract implement?",
+//! similarly to the ERC-165 standard. The `supported_interfaces` query returns the name,
+//! ID, version and messages of every interface implemented on the contract and the
+//! `supports_interface` query checks a single interface by its ID or name.
+//!
+//! The interface is implemented and registered automatically on contracts with
+//! the `introspection` feature enabled.
+//!
+//! ```rust
+//! use sylvia::contract;
+//! use sylvia::ctx::InstantiateCtx;
+//! use sylvia::cw_std::{Response, StdResult};
+//!
+//! pub struct Contract;
+//!
+//! #[contract]
+//! #[sv::features(introspection)]
+//! impl Contract {
+//!     pub const fn new() -> Self {
+//!         Self
+//!     }
+//!
+//!     #[sv::msg(instantiate)]
+//!     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+//!         Ok(Response::new())
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Other contracts can check if the remote contract supports the interface before calling it
+//! with [Remote::supports_interface](crate::types::Remote::supports_interface).
+use cosmwasm_std::StdError;
+
+use crate::ctx::QueryCtx;
+use crate::cw_schema::cw_serde;
+use crate::interface;
+
+/// Description of the interface implemented on the contract.
+#[cw_serde]
+pub struct InterfaceInfo {
+    /// Name of the interface trait.
+    pub name: String,
+    /// Stable identifier of the interface built from the name of the crate defining it
+    /// and the interface name, e.g. `cw20_minting::Cw20Minting`.
+    pub id: String,
+    /// Version of the interface.
+    pub version: String,
+    /// Names of the exec messages.
+    pub exec: Vec<String>,
+    /// Names of the query messages.
+    pub query: Vec<String>,
+    /// Names of the sudo messages.
+    pub sudo: Vec<String>,
+}
+
+impl InterfaceInfo {
+    pub fn new(
+        name: &str,
+        id: &str,
+        version: &str,
+        exec: &[&str],
+        query: &[&str],
+        sudo: &[&str],
+    ) -> Self {
+        fn to_owned(messages: &[&str]) -> Vec<String> {
+            messages.iter().map(|msg| msg.to_string()).collect()
+        }
+
+        Self {
+            name: name.to_owned(),
+            id: id.to_owned(),
+            version: version.to_owned(),
+            exec: to_owned(exec),
+            query: to_owned(query),
+            sudo: to_owned(sudo),
+        }
+    }
+}
+
+/// Response of the `supported_interfaces` query.
+#[cw_serde]
+pub struct SupportedInterfacesResponse {
+    pub interfaces: Vec<InterfaceInfo>,
+}
+
+/// Queries describing the interfaces implemented on the contract.
+#[interface]
+#[sv::custom(msg=cosmwasm_std::Empty, query=cosmwasm_std::Empty)]
+pub trait Introspection {
+    type Error: From<StdError>;
+
+    /// Interfaces implemented on the contract.
+    /// Generated by the `contract` macro with the `introspection` feature.
+    fn interfaces(&self) -> Vec<InterfaceInfo>;
+
+    /// Returns all the interfaces implemented on the contract.
+    #[sv::msg(query)]
+    fn supported_interfaces(
+        &self,
+        _ctx: QueryCtx,
+    ) -> Result<SupportedInterfacesResponse, Self::Error> {
+        Ok(SupportedInterfacesResponse {
+            interfaces: self.interfaces(),
+        })
+    }
+
+    /// Checks if the interface is implemented on the contract.
+    /// The `id` is either the interface ID or its name.
+    #[sv::msg(query)]
+    fn supports_interface(&self, _ctx: QueryCtx, id: String) -> Result<bool, Self::Error> {
+        Ok(self
+            .interfaces()
+            .iter()
+            .any(|interface| interface.id == id || interface.name == id))
+    }
+}
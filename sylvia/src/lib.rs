@@ -3,23 +3,43 @@
 //!
 //! Most of implementation lies in `sylvia-derive` crate which is reexported here.
 
+// Allows using the `sylvia` macros inside this crate.
+extern crate self as sylvia;
+
+pub mod access;
 pub mod builder;
+pub mod codegen;
 pub mod ctx;
+pub mod events;
+pub mod extract;
+pub mod funds;
 pub mod into_response;
+pub mod introspection;
+pub mod middleware;
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub mod multitest;
+pub mod ownable;
+pub mod pagination;
+pub mod pausable;
+pub mod schema;
 pub mod types;
 pub mod utils;
+#[cfg_attr(docsrs, doc(cfg(feature = "cw2")))]
+#[cfg(feature = "cw2")]
+pub mod versioning;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub use anyhow;
+#[cfg_attr(docsrs, doc(cfg(feature = "cw2")))]
+#[cfg(feature = "cw2")]
+pub use cw2;
 #[cfg_attr(docsrs, doc(cfg(feature = "mt")))]
 #[cfg(feature = "mt")]
 pub use cw_multi_test;
 pub use cw_utils;
-pub use sylvia_derive::{contract, entry_points, interface};
+pub use sylvia_derive::{contract, entry_points, event, interface};
 pub use {
     cosmwasm_schema as cw_schema, cosmwasm_std as cw_std, schemars, serde,
     serde_cw_value as serde_value,
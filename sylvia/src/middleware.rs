@@ -0,0 +1,120 @@
+//! Module providing the hooks called around the dispatch of the contract messages.
+//!
+//! Types listed in the `#[sv::middleware(...)]` attributes of the contract implement
+//! the [Middleware] trait. The generated `dispatch` of the `exec`, `sudo` and `migrate`
+//! messages calls the `before_*` hooks in the order of the attributes, dispatches
+//! the message, and calls the `after_*` hooks in the reverse order. The hooks wrap both
+//! the contract and the interface messages.
+//!
+//! Errors returned by the hooks abort the message and are converted into the contract error.
+//!
+//! ```rust
+//! use sylvia::contract;
+//! use sylvia::ctx::{ExecCtx, InstantiateCtx};
+//! use sylvia::cw_std::{CustomQuery, Response, StdError, StdResult};
+//! use sylvia::middleware::Middleware;
+//!
+//! pub struct Metrics;
+//!
+//! impl<Contract, Q: CustomQuery> Middleware<Contract, Q> for Metrics {
+//!     type Error = StdError;
+//!
+//!     fn after_exec(
+//!         _contract: &Contract,
+//!         ctx: &mut ExecCtx<Q>,
+//!         resp: &mut Response,
+//!     ) -> StdResult<()> {
+//!         resp.attributes.push(("height", ctx.env.block.height.to_string()).into());
+//!         Ok(())
+//!     }
+//! }
+//!
+//! pub struct Contract;
+//!
+//! #[contract]
+//! #[sv::middleware(Metrics)]
+//! impl Contract {
+//!     pub const fn new() -> Self {
+//!         Self
+//!     }
+//!
+//!     #[sv::msg(instantiate)]
+//!     fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+//!         Ok(Response::new())
+//!     }
+//!
+//!     #[sv::msg(exec)]
+//!     fn update(&self, _ctx: ExecCtx) -> StdResult<Response> {
+//!         Ok(Response::new())
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+use cosmwasm_std::{CustomQuery, Empty, Response};
+
+use crate::ctx::{ExecCtx, MigrateCtx, SudoCtx};
+
+/// Hooks called by the generated `dispatch` around the message handlers.
+///
+/// `Contract` is the contract handling the message, so the hooks can access its storage
+/// accessors. The context is passed mutably, which lets the hooks write to the storage,
+/// e.g. to set and clear a reentrancy guard. Every hook is a no-op by default.
+pub trait Middleware<Contract: ?Sized, Q: CustomQuery = Empty, M = Empty> {
+    /// Error returned by the hooks.
+    /// The contract error has to implement `From<Self::Error>`.
+    type Error;
+
+    /// Called before the `exec` message named `msg_name` is dispatched.
+    fn before_exec(
+        _contract: &Contract,
+        _ctx: &mut ExecCtx<Q>,
+        _msg_name: &str,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called with the response of the successfully handled `exec` message.
+    fn after_exec(
+        _contract: &Contract,
+        _ctx: &mut ExecCtx<Q>,
+        _resp: &mut Response<M>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called before the `sudo` message named `msg_name` is dispatched.
+    fn before_sudo(
+        _contract: &Contract,
+        _ctx: &mut SudoCtx<Q>,
+        _msg_name: &str,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called with the response of the successfully handled `sudo` message.
+    fn after_sudo(
+        _contract: &Contract,
+        _ctx: &mut SudoCtx<Q>,
+        _resp: &mut Response<M>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called before the `migrate` message named `msg_name` is dispatched.
+    fn before_migrate(
+        _contract: &Contract,
+        _ctx: &mut MigrateCtx<Q>,
+        _msg_name: &str,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called with the response of the successfully handled `migrate` message.
+    fn after_migrate(
+        _contract: &Contract,
+        _ctx: &mut MigrateCtx<Q>,
+        _resp: &mut Response<M>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -49,24 +49,27 @@
 //!
 //! # fn main() {}
 //! ```
+#![allow(clippy::test_attr_in_doctest)]
 
 use std::cell::{Ref, RefCell, RefMut};
 use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
 
 use cosmwasm_std::testing::MockApi;
+#[cfg(feature = "cosmwasm_1_2")]
+use cosmwasm_std::CodeInfoResponse;
 use cosmwasm_std::{
-    Addr, Api, BlockInfo, Coin, Empty, Querier, QuerierResult, QuerierWrapper, StdError, Storage,
+    Addr, Api, BlockInfo, Coin, Empty, Querier, QuerierResult, QuerierWrapper, StdError, StdResult,
+    Storage,
 };
-#[cfg(feature = "cosmwasm_1_2")]
-use cosmwasm_std::{CodeInfoResponse, StdResult};
 use cw_multi_test::{
-    Bank, BankKeeper, Distribution, DistributionKeeper, Executor, FailingModule, Gov,
+    AppResponse, Bank, BankKeeper, Distribution, DistributionKeeper, Executor, FailingModule, Gov,
     GovFailingModule, Ibc, IbcFailingModule, Module, Router, StakeKeeper, Staking, Stargate,
     StargateFailing, Wasm, WasmKeeper,
 };
 use serde::Serialize;
 
+use crate::events::{is_event_type, TypedEvent};
 use crate::types::{CustomMsg, CustomQuery};
 
 /// Proxy to interact with a smart contract initialized on the [App].
@@ -315,6 +318,9 @@ where
 
 /// Intermiediate proxy to set additional information
 /// before sending an migrate message.
+///
+/// If the contract defines multiple migrate handlers, every `Proxy` migrate method
+/// creates the `MigrateProxy` for its own migration path.
 #[must_use]
 pub struct MigrateProxy<'a, 'app, Error, Msg, MtApp, ExecC>
 where
@@ -362,6 +368,93 @@ where
     }
 }
 
+/// Extension trait extracting the typed events from the [cw_multi_test::AppResponse].
+///
+/// ```rust,ignore
+/// use sylvia::multitest::TypedEvents;
+///
+/// let resp = contract.transfer(recipient, 100).call(owner).unwrap();
+/// let transfers: Vec<Transfer> = resp.typed_events().unwrap();
+/// ```
+pub trait TypedEvents {
+    /// Parses all the events of the `E::EVENT_TYPE` type, in the order they were emitted.
+    fn typed_events<E: TypedEvent>(&self) -> StdResult<Vec<E>>;
+
+    /// Parses the first event of the `E::EVENT_TYPE` type.
+    /// Returns an error if no such event was emitted.
+    fn typed_event<E: TypedEvent>(&self) -> StdResult<E> {
+        self.typed_events()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| StdError::generic_err(format!("Event `{}` not found", E::EVENT_TYPE)))
+    }
+}
+
+impl TypedEvents for AppResponse {
+    fn typed_events<E: TypedEvent>(&self) -> StdResult<Vec<E>> {
+        self.events
+            .iter()
+            .filter(|event| is_event_type(event, E::EVENT_TYPE))
+            .map(E::from_event)
+            .collect()
+    }
+}
+
+/// IBC entry points of the contract used in MultiTest.
+///
+/// `cw_multi_test::Contract` does not cover the IBC entry points, so this trait is implemented
+/// by the `contract` macro to allow calling the `ibc_*` handlers directly in tests.
+/// Entry points not handled by the contract return an error.
+#[cfg_attr(docsrs, doc(cfg(feature = "stargate")))]
+#[cfg(feature = "stargate")]
+pub trait IbcContract<C, Q = Empty>
+where
+    C: CustomMsg,
+    Q: CustomQuery,
+{
+    fn ibc_channel_open(
+        &self,
+        deps: cosmwasm_std::DepsMut<Q>,
+        env: cosmwasm_std::Env,
+        msg: cosmwasm_std::IbcChannelOpenMsg,
+    ) -> anyhow::Result<cosmwasm_std::IbcChannelOpenResponse>;
+
+    fn ibc_channel_connect(
+        &self,
+        deps: cosmwasm_std::DepsMut<Q>,
+        env: cosmwasm_std::Env,
+        msg: cosmwasm_std::IbcChannelConnectMsg,
+    ) -> anyhow::Result<cosmwasm_std::IbcBasicResponse<C>>;
+
+    fn ibc_channel_close(
+        &self,
+        deps: cosmwasm_std::DepsMut<Q>,
+        env: cosmwasm_std::Env,
+        msg: cosmwasm_std::IbcChannelCloseMsg,
+    ) -> anyhow::Result<cosmwasm_std::IbcBasicResponse<C>>;
+
+    fn ibc_packet_receive(
+        &self,
+        deps: cosmwasm_std::DepsMut<Q>,
+        env: cosmwasm_std::Env,
+        msg: cosmwasm_std::IbcPacketReceiveMsg,
+    ) -> anyhow::Result<cosmwasm_std::IbcReceiveResponse<C>>;
+
+    fn ibc_packet_ack(
+        &self,
+        deps: cosmwasm_std::DepsMut<Q>,
+        env: cosmwasm_std::Env,
+        msg: cosmwasm_std::IbcPacketAckMsg,
+    ) -> anyhow::Result<cosmwasm_std::IbcBasicResponse<C>>;
+
+    fn ibc_packet_timeout(
+        &self,
+        deps: cosmwasm_std::DepsMut<Q>,
+        env: cosmwasm_std::Env,
+        msg: cosmwasm_std::IbcPacketTimeoutMsg,
+    ) -> anyhow::Result<cosmwasm_std::IbcBasicResponse<C>>;
+}
+
 #[cfg(test)]
 mod tests {
     use std::cell::{Ref, RefMut};
@@ -0,0 +1,293 @@
+//! Module providing the ready-made [Ownable] interface.
+//!
+//! The interface implements the two-step ownership transfer known from `cw-ownable`.
+//! The owner proposes the new owner with `transfer_ownership`, which then has to accept it with
+//! `accept_ownership` before the optional expiration. The owner can also give up the ownership
+//! with `renounce_ownership`. The current state is returned by the `ownership` query.
+//!
+//! All the methods are provided by default, so it is enough to implement the trait with
+//! the error type and register the interface on the contract.
+//! Ownership is kept in the contract storage under the `ownership` key and should be
+//! set with [initialize_owner] during the instantiation.
+//!
+//! Every [Ownable] contract implements [Owned](crate::access::Owned),
+//! so the exec methods can be guarded with `#[sv::access(owner)]`.
+//!
+//! ```rust
+//! use sylvia::contract;
+//! use sylvia::ctx::InstantiateCtx;
+//! use sylvia::cw_std::{Response, StdError, StdResult};
+//! use sylvia::ownable::{initialize_owner, Ownable};
+//!
+//! pub struct Contract;
+//!
+//! #[contract]
+//! #[sv::messages(sylvia::ownable)]
+//! impl Contract {
+//!     pub const fn new() -> Self {
+//!         Self
+//!     }
+//!
+//!     #[sv::msg(instantiate)]
+//!     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+//!         let sender = ctx.info.sender.to_string();
+//!         initialize_owner(ctx.deps.storage, ctx.deps.api, Some(&sender))?;
+//!         Ok(Response::new())
+//!     }
+//! }
+//!
+//! impl Ownable for Contract {
+//!     type Error = StdError;
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Contracts using custom messages or queries have to register the interface with
+//! `#[sv::messages(sylvia::ownable: custom(msg, query))]`.
+use std::fmt;
+
+use cosmwasm_std::{
+    from_json, to_json_vec, Addr, Api, Attribute, BlockInfo, Response, StdError, StdResult, Storage,
+};
+use cw_utils::Expiration;
+
+use crate::access::Owned;
+use crate::ctx::{ExecCtx, QueryCtx};
+use crate::cw_schema::cw_serde;
+use crate::interface;
+
+/// Storage key of the [Ownership]. Compatible with the `cw-ownable` storage layout.
+pub const OWNERSHIP_KEY: &[u8] = b"ownership";
+
+/// Ownership state of the contract.
+#[cw_serde]
+#[derive(Default)]
+pub struct Ownership {
+    /// Current owner of the contract. `None` if the ownership was renounced.
+    pub owner: Option<Addr>,
+    /// Account proposed to become the new owner.
+    pub pending_owner: Option<Addr>,
+    /// Deadline for the `pending_owner` to accept the ownership.
+    pub pending_expiry: Option<Expiration>,
+}
+
+impl Ownership {
+    /// Loads the ownership from the storage. Returns the default value if it was never set.
+    pub fn load(storage: &dyn Storage) -> StdResult<Self> {
+        storage
+            .get(OWNERSHIP_KEY)
+            .map(from_json)
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+
+    /// Saves the ownership in the storage.
+    pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        storage.set(OWNERSHIP_KEY, &to_json_vec(self)?);
+        Ok(())
+    }
+
+    /// Verifies that the `sender` is the current owner.
+    pub fn assert_owner(&self, sender: &Addr) -> Result<(), OwnershipError> {
+        match &self.owner {
+            None => Err(OwnershipError::NoOwner),
+            Some(owner) if owner != sender => Err(OwnershipError::NotOwner),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Converts the ownership into the response attributes.
+    pub fn into_attributes(self) -> Vec<Attribute> {
+        fn none_or<T: fmt::Display>(value: Option<T>) -> String {
+            value.map_or_else(|| "none".to_owned(), |value| value.to_string())
+        }
+
+        vec![
+            Attribute::new("owner", none_or(self.owner)),
+            Attribute::new("pending_owner", none_or(self.pending_owner)),
+            Attribute::new("pending_expiry", none_or(self.pending_expiry)),
+        ]
+    }
+
+    fn transfer(
+        mut self,
+        api: &dyn Api,
+        block: &BlockInfo,
+        sender: &Addr,
+        new_owner: &str,
+        expiry: Option<Expiration>,
+    ) -> Result<Self, OwnershipError> {
+        self.assert_owner(sender)?;
+
+        if let Some(expiry) = &expiry {
+            if expiry.is_expired(block) {
+                return Err(OwnershipError::TransferExpired);
+            }
+        }
+
+        self.pending_owner = Some(api.addr_validate(new_owner)?);
+        self.pending_expiry = expiry;
+        Ok(self)
+    }
+
+    fn accept(mut self, block: &BlockInfo, sender: &Addr) -> Result<Self, OwnershipError> {
+        let Some(pending_owner) = self.pending_owner.take() else {
+            return Err(OwnershipError::TransferNotFound);
+        };
+
+        if pending_owner != *sender {
+            return Err(OwnershipError::NotPendingOwner);
+        }
+
+        if let Some(expiry) = self.pending_expiry.take() {
+            if expiry.is_expired(block) {
+                return Err(OwnershipError::TransferExpired);
+            }
+        }
+
+        self.owner = Some(pending_owner);
+        Ok(self)
+    }
+
+    fn renounce(self, sender: &Addr) -> Result<Self, OwnershipError> {
+        self.assert_owner(sender)?;
+        Ok(Self::default())
+    }
+}
+
+/// Sets the initial owner of the contract. Should be called during the instantiation.
+pub fn initialize_owner(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    owner: Option<&str>,
+) -> StdResult<Ownership> {
+    let ownership = Ownership {
+        owner: owner.map(|owner| api.addr_validate(owner)).transpose()?,
+        pending_owner: None,
+        pending_expiry: None,
+    };
+    ownership.save(storage)?;
+    Ok(ownership)
+}
+
+/// Loads the current ownership of the contract.
+pub fn get_ownership(storage: &dyn Storage) -> StdResult<Ownership> {
+    Ownership::load(storage)
+}
+
+/// Verifies that the `sender` is the current owner of the contract.
+pub fn assert_owner(storage: &dyn Storage, sender: &Addr) -> Result<(), OwnershipError> {
+    Ownership::load(storage)?.assert_owner(sender)
+}
+
+/// Errors returned by the [Ownable] interface.
+#[derive(Debug, PartialEq)]
+pub enum OwnershipError {
+    Std(StdError),
+    /// The ownership was renounced or never set.
+    NoOwner,
+    /// The sender is not the owner of the contract.
+    NotOwner,
+    /// There is no pending ownership transfer.
+    TransferNotFound,
+    /// The sender is not the pending owner.
+    NotPendingOwner,
+    /// The ownership transfer expired.
+    TransferExpired,
+}
+
+impl fmt::Display for OwnershipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Std(err) => write!(f, "{err}"),
+            Self::NoOwner => write!(f, "Contract ownership has been renounced."),
+            Self::NotOwner => write!(f, "Caller is not the contract's current owner."),
+            Self::TransferNotFound => write!(f, "Ownership transfer not found."),
+            Self::NotPendingOwner => write!(f, "Caller is not the contract's pending owner."),
+            Self::TransferExpired => write!(f, "Ownership transfer expired."),
+        }
+    }
+}
+
+impl std::error::Error for OwnershipError {}
+
+impl From<StdError> for OwnershipError {
+    fn from(err: StdError) -> Self {
+        Self::Std(err)
+    }
+}
+
+impl From<OwnershipError> for StdError {
+    fn from(err: OwnershipError) -> Self {
+        match err {
+            OwnershipError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+/// Two-step ownership transfer with the default implementation of all the methods.
+#[interface]
+#[sv::custom(msg=cosmwasm_std::Empty, query=cosmwasm_std::Empty)]
+pub trait Ownable {
+    type Error: From<StdError> + From<OwnershipError>;
+
+    /// Proposes the `new_owner` of the contract. Can be called only by the current owner.
+    /// The transfer has to be accepted by the `new_owner` before the `expiry`.
+    #[sv::msg(exec)]
+    fn transfer_ownership(
+        &self,
+        ctx: ExecCtx,
+        new_owner: String,
+        expiry: Option<Expiration>,
+    ) -> Result<Response, Self::Error> {
+        let ownership = Ownership::load(ctx.deps.storage)?.transfer(
+            ctx.deps.api,
+            &ctx.env.block,
+            &ctx.info.sender,
+            &new_owner,
+            expiry,
+        )?;
+        ownership.save(ctx.deps.storage)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "transfer_ownership")
+            .add_attributes(ownership.into_attributes()))
+    }
+
+    /// Accepts the pending ownership transfer. Can be called only by the pending owner.
+    #[sv::msg(exec)]
+    fn accept_ownership(&self, ctx: ExecCtx) -> Result<Response, Self::Error> {
+        let ownership =
+            Ownership::load(ctx.deps.storage)?.accept(&ctx.env.block, &ctx.info.sender)?;
+        ownership.save(ctx.deps.storage)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "accept_ownership")
+            .add_attributes(ownership.into_attributes()))
+    }
+
+    /// Gives up the ownership of the contract and cancels the pending transfer.
+    /// Can be called only by the current owner.
+    #[sv::msg(exec)]
+    fn renounce_ownership(&self, ctx: ExecCtx) -> Result<Response, Self::Error> {
+        let ownership = Ownership::load(ctx.deps.storage)?.renounce(&ctx.info.sender)?;
+        ownership.save(ctx.deps.storage)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "renounce_ownership")
+            .add_attributes(ownership.into_attributes()))
+    }
+
+    /// Returns the current ownership of the contract.
+    #[sv::msg(query)]
+    fn ownership(&self, ctx: QueryCtx) -> Result<Ownership, Self::Error> {
+        Ok(Ownership::load(ctx.deps.storage)?)
+    }
+}
+
+impl<Contract: Ownable> Owned for Contract {
+    fn owner(&self, storage: &dyn Storage) -> StdResult<Option<Addr>> {
+        Ok(Ownership::load(storage)?.owner)
+    }
+}
@@ -0,0 +1,239 @@
+//! Module providing utilities for the paginated queries.
+//!
+//! Queries marked with `#[sv::msg(query, paginated)]` get the `start_after` and `limit` fields
+//! in the generated `QueryMsg` variant. The values are available in the handler as
+//! the [QueryCtx::pagination](crate::ctx::QueryCtx::pagination) and the handler returns
+//! the [Page] of items built with [Pagination::page].
+//!
+//! ```rust
+//! use cw_storage_plus::{Bound, Map};
+//! use sylvia::contract;
+//! use sylvia::ctx::{InstantiateCtx, QueryCtx};
+//! use sylvia::cw_std::{Order, Response, StdResult};
+//! use sylvia::pagination::Page;
+//!
+//! pub struct Contract {
+//!     members: Map<String, u64>,
+//! }
+//!
+//! #[contract]
+//! impl Contract {
+//!     pub const fn new() -> Self {
+//!         Self {
+//!             members: Map::new("members"),
+//!         }
+//!     }
+//!
+//!     #[sv::msg(instantiate)]
+//!     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+//!         Ok(Response::new())
+//!     }
+//!
+//!     #[sv::msg(query, paginated)]
+//!     fn members(&self, ctx: QueryCtx) -> StdResult<Page<(String, u64)>> {
+//!         let start = ctx.pagination.start_after().map(Bound::exclusive);
+//!         let members = self
+//!             .members
+//!             .range(ctx.deps.storage, start, None, Order::Ascending);
+//!         ctx.pagination.page(members, |(member, _)| member.clone())
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! The generated query helpers and multitest proxies get additional `<query>_iter` methods
+//! returning [PageIter] which queries the following pages while iterating.
+use crate::cw_schema::cw_serde;
+
+/// Page of items returned by the paginated queries.
+#[cw_serde]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Key of the last returned item to be passed as the `start_after` of the next query.
+    /// `None` if there are no more items.
+    pub next_key: Option<String>,
+}
+
+/// Pagination parameters of the query.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Pagination {
+    start_after: Option<String>,
+    limit: Option<u32>,
+}
+
+impl Pagination {
+    /// Number of items returned if the `limit` is not provided.
+    pub const DEFAULT_LIMIT: u32 = 10;
+    /// Maximal number of items returned in a single page.
+    pub const MAX_LIMIT: u32 = 30;
+
+    pub fn new(start_after: Option<String>, limit: Option<u32>) -> Self {
+        Self { start_after, limit }
+    }
+
+    /// Key of the item after which the page starts.
+    pub fn start_after(&self) -> Option<&str> {
+        self.start_after.as_deref()
+    }
+
+    /// Number of items in the page capped to the [Pagination::MAX_LIMIT].
+    /// At least one item is returned, so the `next_key` of the page is always known.
+    pub fn limit(&self) -> usize {
+        self.limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .clamp(1, Self::MAX_LIMIT) as usize
+    }
+
+    /// Builds the page from the items following the `start_after` key.
+    ///
+    /// Consumes at most `limit + 1` items to determine if there is a next page.
+    /// The `key` function returns the key of the item used as the `next_key`.
+    pub fn page<T, E>(
+        &self,
+        items: impl IntoIterator<Item = Result<T, E>>,
+        key: impl Fn(&T) -> String,
+    ) -> Result<Page<T>, E> {
+        let limit = self.limit();
+        let mut items = items
+            .into_iter()
+            .take(limit + 1)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let next_key = if items.len() > limit {
+            items.truncate(limit);
+            items.last().map(key)
+        } else {
+            None
+        };
+
+        Ok(Page { items, next_key })
+    }
+}
+
+/// Query of the page starting after the given key.
+type PageQuery<'a, T, E> = Box<dyn FnMut(Option<String>) -> Result<Page<T>, E> + 'a>;
+
+/// Iterator over the items of all the pages returned by the paginated query.
+///
+/// The next page is queried when the items of the current one are consumed.
+/// Iteration stops after the first error.
+pub struct PageIter<'a, T, E> {
+    query: PageQuery<'a, T, E>,
+    items: std::vec::IntoIter<T>,
+    next_key: Option<String>,
+    finished: bool,
+}
+
+impl<'a, T, E> PageIter<'a, T, E> {
+    /// Creates the iterator calling the `query` with the `start_after` key of the next page.
+    pub fn new(query: impl FnMut(Option<String>) -> Result<Page<T>, E> + 'a) -> Self {
+        Self {
+            query: Box::new(query),
+            items: Vec::new().into_iter(),
+            next_key: None,
+            finished: false,
+        }
+    }
+}
+
+impl<T, E> Iterator for PageIter<'_, T, E> {
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+
+            if self.finished {
+                return None;
+            }
+
+            match (self.query)(self.next_key.take()) {
+                Ok(page) => {
+                    self.finished = page.next_key.is_none();
+                    self.next_key = page.next_key;
+                    self.items = page.items.into_iter();
+                }
+                Err(err) => {
+                    self.finished = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(range: std::ops::Range<u32>) -> impl Iterator<Item = Result<u32, String>> {
+        range.map(Ok)
+    }
+
+    #[test]
+    fn page() {
+        let pagination = Pagination::new(None, Some(3));
+        assert_eq!(
+            pagination.page(items(0..10), u32::to_string).unwrap(),
+            Page {
+                items: vec![0, 1, 2],
+                next_key: Some("2".to_owned()),
+            }
+        );
+
+        assert_eq!(
+            pagination.page(items(0..3), u32::to_string).unwrap(),
+            Page {
+                items: vec![0, 1, 2],
+                next_key: None,
+            }
+        );
+
+        let err = pagination
+            .page([Ok(0), Err("error".to_owned())], u32::to_string)
+            .unwrap_err();
+        assert_eq!(err, "error");
+    }
+
+    #[test]
+    fn limit() {
+        assert_eq!(Pagination::default().limit(), 10);
+        assert_eq!(Pagination::new(None, Some(5)).limit(), 5);
+        assert_eq!(Pagination::new(None, Some(100)).limit(), 30);
+        assert_eq!(Pagination::new(None, Some(0)).limit(), 1);
+    }
+
+    #[test]
+    fn zero_limit_page() {
+        let pagination = Pagination::new(None, Some(0));
+        assert_eq!(
+            pagination.page(items(0..10), u32::to_string).unwrap(),
+            Page {
+                items: vec![0],
+                next_key: Some("0".to_owned()),
+            }
+        );
+    }
+
+    #[test]
+    fn page_iter() {
+        let mut queries = vec![];
+        let iter = PageIter::new(|start_after: Option<String>| {
+            queries.push(start_after.clone());
+            let start = start_after.map_or(0, |key| key.parse::<u32>().unwrap() + 1);
+            Pagination::new(None, Some(4)).page(items(start..10), u32::to_string)
+        });
+
+        assert_eq!(
+            iter.collect::<Result<Vec<_>, _>>().unwrap(),
+            (0..10).collect::<Vec<_>>()
+        );
+        assert_eq!(queries, [None, Some("3".to_owned()), Some("7".to_owned())]);
+
+        let mut iter = PageIter::<u32, _>::new(|_| Err("error"));
+        assert_eq!(iter.next(), Some(Err("error")));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,227 @@
+//! Module providing the ready-made [Pausable] interface.
+//!
+//! The interface implements the circuit breaker of the contract. The pauser stops the contract
+//! with `pause` and resumes it with `unpause`. The chain governance can do the same with
+//! the `set_paused` sudo message. The current state is returned by the `pause_state` query.
+//!
+//! The `exec` methods of the contract marked with `#[sv::pausable]` are rejected by
+//! the generated `dispatch` with the [PauseError::Paused] error while the contract is paused.
+//! The other messages, e.g. withdrawals, stay available. The contract error has to implement
+//! `From<PauseError>`. [StdError] already does.
+//!
+//! All the methods are provided by default, so it is enough to implement the trait with
+//! the error type and register the interface on the contract.
+//! The state is kept in the contract storage under the `pause_state` key and the pauser should be
+//! set with [initialize_pauser] during the instantiation.
+//!
+//! ```rust
+//! use sylvia::contract;
+//! use sylvia::ctx::{ExecCtx, InstantiateCtx};
+//! use sylvia::cw_std::{Response, StdError, StdResult};
+//! use sylvia::pausable::{initialize_pauser, Pausable};
+//!
+//! pub struct Contract;
+//!
+//! #[contract]
+//! #[sv::messages(sylvia::pausable)]
+//! impl Contract {
+//!     pub const fn new() -> Self {
+//!         Self
+//!     }
+//!
+//!     #[sv::msg(instantiate)]
+//!     fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+//!         let sender = ctx.info.sender.to_string();
+//!         initialize_pauser(ctx.deps.storage, ctx.deps.api, Some(&sender))?;
+//!         Ok(Response::new())
+//!     }
+//!
+//!     #[sv::msg(exec)]
+//!     #[sv::pausable]
+//!     fn deposit(&self, ctx: ExecCtx) -> StdResult<Response> {
+//!         Ok(Response::new())
+//!     }
+//!
+//!     #[sv::msg(exec)]
+//!     fn withdraw(&self, ctx: ExecCtx) -> StdResult<Response> {
+//!         Ok(Response::new())
+//!     }
+//! }
+//!
+//! impl Pausable for Contract {
+//!     type Error = StdError;
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Contracts using custom messages or queries have to register the interface with
+//! `#[sv::messages(sylvia::pausable: custom(msg, query))]`.
+use std::fmt;
+
+use cosmwasm_std::{from_json, to_json_vec, Addr, Api, Response, StdError, StdResult, Storage};
+
+use crate::ctx::{ExecCtx, QueryCtx, SudoCtx};
+use crate::cw_schema::cw_serde;
+use crate::interface;
+
+/// Storage key of the [PauseState].
+pub const PAUSE_STATE_KEY: &[u8] = b"pause_state";
+
+/// Pause state of the contract.
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseState {
+    /// Marks if the `#[sv::pausable]` messages are rejected.
+    pub paused: bool,
+    /// Account allowed to pause and unpause the contract.
+    pub pauser: Option<Addr>,
+}
+
+impl PauseState {
+    /// Loads the pause state from the storage. Returns the default value if it was never set.
+    pub fn load(storage: &dyn Storage) -> StdResult<Self> {
+        storage
+            .get(PAUSE_STATE_KEY)
+            .map(from_json)
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+
+    /// Saves the pause state in the storage.
+    pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        storage.set(PAUSE_STATE_KEY, &to_json_vec(self)?);
+        Ok(())
+    }
+
+    /// Verifies that the `sender` is the pauser.
+    pub fn assert_pauser(&self, sender: &Addr) -> Result<(), PauseError> {
+        match &self.pauser {
+            Some(pauser) if pauser == sender => Ok(()),
+            _ => Err(PauseError::NotPauser),
+        }
+    }
+
+    fn set_paused(mut self, paused: bool) -> Result<Self, PauseError> {
+        match (self.paused, paused) {
+            (true, true) => Err(PauseError::Paused),
+            (false, false) => Err(PauseError::NotPaused),
+            _ => {
+                self.paused = paused;
+                Ok(self)
+            }
+        }
+    }
+}
+
+/// Sets the initial pauser of the contract. Should be called during the instantiation.
+pub fn initialize_pauser(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    pauser: Option<&str>,
+) -> StdResult<PauseState> {
+    let state = PauseState {
+        paused: false,
+        pauser: pauser.map(|pauser| api.addr_validate(pauser)).transpose()?,
+    };
+    state.save(storage)?;
+    Ok(state)
+}
+
+/// Checks if the contract is paused.
+pub fn is_paused(storage: &dyn Storage) -> StdResult<bool> {
+    Ok(PauseState::load(storage)?.paused)
+}
+
+/// Verifies that the contract is not paused.
+/// Called by the generated `dispatch` of the `#[sv::pausable]` messages.
+pub fn assert_not_paused(storage: &dyn Storage) -> Result<(), PauseError> {
+    match is_paused(storage)? {
+        true => Err(PauseError::Paused),
+        false => Ok(()),
+    }
+}
+
+/// Errors returned by the [Pausable] interface and the `#[sv::pausable]` messages.
+#[derive(Debug, PartialEq)]
+pub enum PauseError {
+    Std(StdError),
+    /// The contract is paused.
+    Paused,
+    /// The contract is not paused.
+    NotPaused,
+    /// The sender is not the pauser of the contract.
+    NotPauser,
+}
+
+impl fmt::Display for PauseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Std(err) => write!(f, "{err}"),
+            Self::Paused => write!(f, "Contract is paused."),
+            Self::NotPaused => write!(f, "Contract is not paused."),
+            Self::NotPauser => write!(f, "Caller is not the contract's pauser."),
+        }
+    }
+}
+
+impl std::error::Error for PauseError {}
+
+impl From<StdError> for PauseError {
+    fn from(err: StdError) -> Self {
+        Self::Std(err)
+    }
+}
+
+impl From<PauseError> for StdError {
+    fn from(err: PauseError) -> Self {
+        match err {
+            PauseError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+/// Circuit breaker of the contract with the default implementation of all the methods.
+#[interface]
+#[sv::custom(msg=cosmwasm_std::Empty, query=cosmwasm_std::Empty)]
+pub trait Pausable {
+    type Error: From<StdError> + From<PauseError>;
+
+    /// Pauses the contract. Can be called only by the pauser.
+    #[sv::msg(exec)]
+    fn pause(&self, ctx: ExecCtx) -> Result<Response, Self::Error> {
+        let state = PauseState::load(ctx.deps.storage)?;
+        state.assert_pauser(&ctx.info.sender)?;
+        state.set_paused(true)?.save(ctx.deps.storage)?;
+
+        Ok(Response::new().add_attribute("action", "pause"))
+    }
+
+    /// Unpauses the contract. Can be called only by the pauser.
+    #[sv::msg(exec)]
+    fn unpause(&self, ctx: ExecCtx) -> Result<Response, Self::Error> {
+        let state = PauseState::load(ctx.deps.storage)?;
+        state.assert_pauser(&ctx.info.sender)?;
+        state.set_paused(false)?.save(ctx.deps.storage)?;
+
+        Ok(Response::new().add_attribute("action", "unpause"))
+    }
+
+    /// Pauses or unpauses the contract on behalf of the chain governance.
+    #[sv::msg(sudo)]
+    fn set_paused(&self, ctx: SudoCtx, paused: bool) -> Result<Response, Self::Error> {
+        let mut state = PauseState::load(ctx.deps.storage)?;
+        state.paused = paused;
+        state.save(ctx.deps.storage)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "set_paused")
+            .add_attribute("paused", paused.to_string()))
+    }
+
+    /// Returns the current pause state of the contract.
+    #[sv::msg(query)]
+    fn pause_state(&self, ctx: QueryCtx) -> Result<PauseState, Self::Error> {
+        Ok(PauseState::load(ctx.deps.storage)?)
+    }
+}
@@ -0,0 +1,143 @@
+//! Module providing utilities to generate the JSON schema of the contract.
+//!
+//! The [contract](crate::contract) macro generates the `sv::generate_schema` function returning
+//! the [ContractSchema] built from the same types the macro generates, so the schema can't drift
+//! from the messages the contract accepts.
+//! The [entry_points](crate::entry_points) macro generates the `entry_points::schema` function
+//! calling it with the concrete types of a generic contract.
+//!
+//! Typical `src/bin/schema.rs` of the contract:
+//!
+//! ```rust,ignore
+//! fn main() {
+//!     sylvia::schema::write_api(my_contract::contract::entry_points::schema());
+//! }
+//! ```
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use cosmwasm_schema::{remove_schemas, Api, QueryResponses};
+use schemars::schema::RootSchema;
+use schemars::{schema_for, JsonSchema};
+
+/// Full API description of the contract.
+pub struct ContractSchema {
+    /// The CosmWasm IDL of the contract built from the glue messages.
+    pub api: Api,
+    /// Sub-schemas of the interfaces implemented on the contract, indexed by the interface name.
+    pub interfaces: BTreeMap<String, Api>,
+    /// Schema of the message wrapped in the cw20 `Receive` message
+    /// if the contract handles it.
+    pub cw20_receive: Option<RootSchema>,
+    /// Schemas of the events emitted by the contract, indexed by the event type.
+    pub events: BTreeMap<String, RootSchema>,
+}
+
+impl ContractSchema {
+    /// Writes the schema into the `out_dir` directory and returns paths of the written files.
+    ///
+    /// Produces the same layout as `cosmwasm_schema::write_api`, i.e. the full IDL in
+    /// `<contract_name>.json` and the separate message schemas in the `raw` directory.
+    /// Interface sub-schemas are written in the IDL format to the `interfaces` directory.
+    /// Schema of the cw20 `ReceiveMsg` is written to `raw/receive.json`.
+    /// Event schemas are written to the `events` directory.
+    pub fn write(self, out_dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        let out_dir = out_dir.as_ref();
+        let mut written = vec![];
+
+        std::fs::create_dir_all(out_dir)?;
+        remove_schemas(out_dir)?;
+
+        let path = out_dir.join(format!("{}.json", self.api.contract_name));
+        let api = self.api.render();
+        std::fs::write(&path, api.to_string().map_err(io::Error::other)?)?;
+        written.push(path);
+
+        let raw_dir = out_dir.join("raw");
+        std::fs::create_dir_all(&raw_dir)?;
+        remove_schemas(&raw_dir)?;
+        for (filename, json) in api.to_schema_files().map_err(io::Error::other)? {
+            let path = raw_dir.join(filename);
+            std::fs::write(&path, json)?;
+            written.push(path);
+        }
+        if let Some(cw20_receive) = &self.cw20_receive {
+            let path = raw_dir.join("receive.json");
+            let json = serde_json::to_string_pretty(cw20_receive).map_err(io::Error::other)?;
+            std::fs::write(&path, json)?;
+            written.push(path);
+        }
+
+        let interfaces_dir = out_dir.join("interfaces");
+        if interfaces_dir.is_dir() {
+            remove_schemas(&interfaces_dir)?;
+        }
+        if !self.interfaces.is_empty() {
+            std::fs::create_dir_all(&interfaces_dir)?;
+        }
+        for (name, api) in self.interfaces {
+            let path = interfaces_dir.join(format!("{name}.json"));
+            std::fs::write(&path, api.render().to_string().map_err(io::Error::other)?)?;
+            written.push(path);
+        }
+
+        let events_dir = out_dir.join("events");
+        if events_dir.is_dir() {
+            remove_schemas(&events_dir)?;
+        }
+        if !self.events.is_empty() {
+            std::fs::create_dir_all(&events_dir)?;
+        }
+        for (event_type, schema) in self.events {
+            let path = events_dir.join(format!("{event_type}.json"));
+            let json = serde_json::to_string_pretty(&schema).map_err(io::Error::other)?;
+            std::fs::write(&path, json)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+}
+
+/// Builds the IDL of the interface from its messages.
+///
+/// Used by the `sv::generate_schema` function generated by the [contract](crate::contract) macro.
+pub fn interface_api<Exec, Query, Sudo>(interface_name: &str, contract_version: &str) -> Api
+where
+    Exec: JsonSchema,
+    Query: QueryResponses,
+    Sudo: JsonSchema,
+{
+    Api {
+        contract_name: interface_name.to_owned(),
+        contract_version: contract_version.to_owned(),
+        instantiate: None,
+        execute: Some(schema_for!(Exec)),
+        query: Some(schema_for!(Query)),
+        migrate: None,
+        sudo: Some(schema_for!(Sudo)),
+        responses: Some(Query::response_schemas_impl()),
+    }
+}
+
+/// Writes the schema into the `schema` directory in the current working directory.
+///
+/// Equivalent of the `cosmwasm_schema::write_api` macro meant to be called from
+/// the `src/bin/schema.rs` of the contract.
+///
+/// # Panics
+///
+/// Panics if the schema could not be written.
+pub fn write_api(schema: ContractSchema) {
+    let out_dir = std::env::current_dir()
+        .expect("Failed to read the current directory")
+        .join("schema");
+
+    let written = schema
+        .write(out_dir)
+        .expect("Failed to write the contract schema");
+    for path in written {
+        println!("Exported {}", path.display());
+    }
+}
@@ -1,6 +1,12 @@
 //! Module providing utilities to build and use sylvia contracts.
 
-use cosmwasm_std::{Binary, Coin, WasmMsg};
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    from_json, to_json_vec, Binary, Coin, ContractResult, Empty, QuerierWrapper, QueryRequest,
+    ReplyOn, StdError, StdResult, SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -10,6 +16,10 @@ use serde::{Deserialize, Serialize};
 /// [contract](crate::contract) and [interface](crate::interface) macros will generate the required implementation
 /// for each `query` message.
 ///
+/// The query methods return the [RemoteQueryError], which tells apart the queries not supported
+/// by the contract from the failed ones and the responses of an unexpected type.
+/// It converts into the [StdError], so it can be propagated with the `?` operator.
+///
 /// # Example
 ///
 /// Call a query method on a remote contract.
@@ -50,8 +60,9 @@ use serde::{Deserialize, Serialize};
 ///
 ///     fn is_admin(deps: Deps, addr: Addr) -> StdResult<bool> {
 ///         let admin_addr = ADMIN_CONTRACT.load(deps.storage)?;
-///         BoundQuerier::borrowed(&admin_addr, &deps.querier)
-///             .is_admin(addr)
+///         let is_admin = BoundQuerier::borrowed(&admin_addr, &deps.querier)
+///             .is_admin(&addr)?;
+///         Ok(is_admin)
 ///     }
 /// }
 ///
@@ -115,8 +126,9 @@ use serde::{Deserialize, Serialize};
 ///
 ///     fn is_admin(deps: Deps, addr: Addr) -> StdResult<bool> {
 ///         let admin_addr = ADMIN_CONTRACT.load(deps.storage)?;
-///         BoundQuerier::<_, AdminContract>::borrowed(&admin_addr, &deps.querier)
-///             .is_admin(addr)
+///         let is_admin = BoundQuerier::<_, AdminContract>::borrowed(&admin_addr, &deps.querier)
+///             .is_admin(&addr)?;
+///         Ok(is_admin)
 ///     }
 /// }
 ///
@@ -152,6 +164,81 @@ impl<'a, C: cosmwasm_std::CustomQuery, Contract: ?Sized> BoundQuerier<'a, C, Con
     }
 }
 
+/// Beginning of the error returned by the contracts when the received message is not supported
+/// by the contract or any of its interfaces.
+pub const UNSUPPORTED_MESSAGE: &str = "Unsupported message received";
+
+/// Error returned by the query methods of the [BoundQuerier].
+#[derive(Debug, PartialEq)]
+pub enum RemoteQueryError {
+    /// The contract doesn't support the query, i.e. it doesn't implement the queried interface.
+    UnsupportedMessage(String),
+    /// The query failed on the contract or couldn't be performed.
+    QueryFailed(String),
+    /// The response couldn't be deserialized into the expected type.
+    InvalidResponse(StdError),
+}
+
+impl RemoteQueryError {
+    /// Creates the error from the error message returned for the query.
+    /// Recognizes the [UNSUPPORTED_MESSAGE] errors of the sylvia contracts, either returned
+    /// directly or wrapped in the parsing error of the glue message.
+    pub fn from_message(msg: String) -> Self {
+        let prefix = format!("{UNSUPPORTED_MESSAGE}: ");
+        if msg.starts_with(&prefix) || msg.contains(&format!(": {prefix}")) {
+            Self::UnsupportedMessage(msg)
+        } else {
+            Self::QueryFailed(msg)
+        }
+    }
+}
+
+impl fmt::Display for RemoteQueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedMessage(msg) => write!(f, "Unsupported query: {msg}"),
+            Self::QueryFailed(msg) => write!(f, "Query failed: {msg}"),
+            Self::InvalidResponse(err) => write!(f, "Invalid query response: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RemoteQueryError {}
+
+impl From<RemoteQueryError> for StdError {
+    fn from(err: RemoteQueryError) -> Self {
+        match err {
+            RemoteQueryError::InvalidResponse(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+/// Queries the `contract` with the `msg` and deserializes the response.
+///
+/// Used by the query methods of the [BoundQuerier] generated by the `contract` and `interface` macros.
+pub fn query_wasm_smart<C: cosmwasm_std::CustomQuery, T: DeserializeOwned>(
+    querier: &QuerierWrapper<C>,
+    contract: &cosmwasm_std::Addr,
+    msg: &impl Serialize,
+) -> Result<T, RemoteQueryError> {
+    let request = QueryRequest::<Empty>::Wasm(WasmQuery::Smart {
+        contract_addr: contract.to_string(),
+        msg: cosmwasm_std::to_json_binary(msg)
+            .map_err(|err| RemoteQueryError::QueryFailed(err.to_string()))?,
+    });
+    let request =
+        to_json_vec(&request).map_err(|err| RemoteQueryError::QueryFailed(err.to_string()))?;
+
+    match querier.raw_query(&request) {
+        SystemResult::Err(err) => Err(RemoteQueryError::from_message(err.to_string())),
+        SystemResult::Ok(ContractResult::Err(err)) => Err(RemoteQueryError::from_message(err)),
+        SystemResult::Ok(ContractResult::Ok(resp)) => {
+            from_json(resp).map_err(RemoteQueryError::InvalidResponse)
+        }
+    }
+}
+
 impl<'a, C: cosmwasm_std::CustomQuery, Contract> From<&'a BoundQuerier<'a, C, Contract>>
     for BoundQuerier<'a, C, Contract>
 {
@@ -315,6 +402,49 @@ impl ExecutorBuilder<ReadyExecutorBuilderState> {
             funds: self.funds,
         }
     }
+
+    /// Builds the message and wraps it in the [SubMsg] handled by the `Handler`.
+    ///
+    /// The reply id and [ReplyOn] are taken from the `Handler` and the `payload` is serialized
+    /// the way the handler expects it, so passing a payload of the wrong type fails to compile.
+    pub fn with_reply<Handler: ReplyHandler>(
+        self,
+        payload: Handler::Payload,
+    ) -> StdResult<SubMsg<Handler::CustomMsg>> {
+        Ok(SubMsg {
+            id: Handler::REPLY_ID,
+            payload: Handler::payload(payload)?,
+            msg: self.build().into(),
+            gas_limit: None,
+            reply_on: Handler::REPLY_ON,
+        })
+    }
+}
+
+/// Reply handler of the contract or interface.
+///
+/// The `contract` and `interface` macros generate a type implementing this trait
+/// for every reply id in the `sv::replies` module. The type is named after the handler
+/// in the `UpperCamelCase`, e.g. the `remote_instantiated` handler is represented by
+/// the `sv::replies::RemoteInstantiated` type.
+///
+/// Used with [ExecutorBuilder::with_reply] to create the [SubMsg] dispatched to the handler.
+pub trait ReplyHandler {
+    /// Id of the reply.
+    const REPLY_ID: u64;
+
+    /// Results of the submessage on which the handler is called.
+    const REPLY_ON: ReplyOn;
+
+    /// Custom message of the [SubMsg].
+    type CustomMsg;
+
+    /// Payload expected by the handler.
+    /// Tuple of the payload parameters if the handler expects more than one.
+    type Payload;
+
+    /// Serializes the `payload` the way the handler deserializes it.
+    fn payload(payload: Self::Payload) -> StdResult<Binary>;
 }
 
 /// Represents a contract on the chain and acts as a gateway to communicate with it.
@@ -360,8 +490,9 @@ impl ExecutorBuilder<ReadyExecutorBuilderState> {
 ///
 ///     fn is_admin(deps: Deps, addr: Addr) -> StdResult<bool> {
 ///         let admin_contract = ADMIN_CONTRACT.load(deps.storage)?;
-///         admin_contract.querier(&deps.querier)
-///             .is_admin(addr)
+///         let is_admin = admin_contract.querier(&deps.querier)
+///             .is_admin(&addr)?;
+///         Ok(is_admin)
 ///     }
 /// }
 ///
@@ -429,6 +560,25 @@ impl<'a, Contract: ?Sized> Remote<'a, Contract> {
         }
     }
 
+    /// Checks if the contract supports the interface identified by its ID or name with
+    /// the `supports_interface` query of the [Introspection](crate::introspection::Introspection)
+    /// interface.
+    ///
+    /// Sylvia contracts not implementing the [Introspection](crate::introspection::Introspection)
+    /// are considered not supporting the interface.
+    pub fn supports_interface<C: cosmwasm_std::CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<C>,
+        id: &str,
+    ) -> Result<bool, RemoteQueryError> {
+        let msg = crate::introspection::sv::QueryMsg::supports_interface(id.to_owned());
+
+        match query_wasm_smart(querier, &self.addr, &msg) {
+            Err(RemoteQueryError::UnsupportedMessage(_)) => Ok(false),
+            result => result,
+        }
+    }
+
     /// Creates a new instance of [ExecutorBuilder] from underlying contract address.
     pub fn executor(&self) -> ExecutorBuilder<(EmptyExecutorBuilderState, Contract)> {
         ExecutorBuilder::<(EmptyExecutorBuilderState, Contract)>::new(&self.addr)
@@ -462,6 +612,35 @@ pub trait CustomMsg: cosmwasm_std::CustomMsg + DeserializeOwned + JsonSchema {}
 
 impl<T> CustomMsg for T where T: cosmwasm_std::CustomMsg + DeserializeOwned + JsonSchema {}
 
+/// Message sent by the cw20 token contract to the recipient of the tokens.
+///
+/// Contracts handling it with the `#[sv::msg(exec, cw20_receive)]` methods get the
+/// `ExecMsg::Receive` variant wrapping it. The `msg` is deserialized into the generated
+/// `ReceiveMsg` and dispatched to the matching method.
+#[cw_serde]
+pub struct Cw20ReceiveMsg {
+    /// Address of the account which sent the tokens.
+    pub sender: String,
+    /// Amount of the received tokens.
+    pub amount: Uint128,
+    /// Serialized `ReceiveMsg` of the recipient.
+    pub msg: Binary,
+}
+
+/// Kind of the `ibc_*` handler declared by the interface for a single IBC message type.
+///
+/// Contracts implementing the interface with `#[sv::messages(interface: ibc)]` use it
+/// to verify at compile time that the handler doesn't shadow the handlers declared after it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IbcHandler {
+    /// The interface doesn't handle the message type.
+    None,
+    /// The handler accepts only the packets deserializing into its `packet` type.
+    Packet,
+    /// The handler accepts every message of the type.
+    All,
+}
+
 /// Set of trait bounds for custom queries.
 pub trait CustomQuery: cosmwasm_std::CustomQuery + DeserializeOwned + JsonSchema {}
 
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum State {
     // Ongoing arrays can be compared to other arrays.
@@ -119,6 +121,491 @@ const fn should_end<const N: usize>(states: &[State; N]) -> bool {
     true
 }
 
+/// # Examples
+///
+/// Derives the `REPLY_NAMESPACE` of the interface from its `INTERFACE_ID`.
+/// It is the 32-bit FNV-1a hash of the id, never `0`, as the namespace `0`
+/// is used by the reply ids of the contract.
+/// ```
+///     const NAMESPACE: u64 = sylvia::utils::reply_namespace("my_crate::MyInterface");
+///     const _: () = assert!(NAMESPACE != 0 && NAMESPACE <= u32::MAX as u64);
+/// ```
+pub const fn reply_namespace(interface_id: &str) -> u64 {
+    const OFFSET_BASIS: u32 = 0x811c9dc5;
+    const PRIME: u32 = 0x01000193;
+
+    let bytes = interface_id.as_bytes();
+    let mut hash = OFFSET_BASIS;
+    let mut index = 0;
+    while index < bytes.len() {
+        hash = (hash ^ bytes[index] as u32).wrapping_mul(PRIME);
+        index += 1;
+    }
+
+    match hash {
+        0 => 1,
+        hash => hash as u64,
+    }
+}
+
+/// # Examples
+///
+/// Compile time check of the version against the
+/// [semver requirement](https://docs.rs/semver/latest/semver/struct.VersionReq.html).
+/// Follows the matching rules of the `semver` crate used by Cargo.
+/// Will panic! in case the version or the requirement is invalid.
+/// ```
+///     const _: () = assert!(sylvia::utils::version_matches("1.2.3", ">=1.2, <2.0"));
+///     const _: () = assert!(!sylvia::utils::version_matches("2.0.0-rc.1", "^1.2"));
+/// ```
+pub const fn version_matches(version: &str, req: &str) -> bool {
+    let version = parse_version(version.as_bytes());
+    let req = req.as_bytes();
+
+    let mut pos = skip_whitespace(req, 0);
+    if pos == req.len() {
+        // Empty requirement is equivalent to `*`.
+        return version.pre.is_empty();
+    }
+
+    let mut matches = true;
+    let mut pre_compatible = false;
+    loop {
+        let (comparator, next) = parse_comparator(req, pos);
+        matches = matches && matches_comparator(&comparator, &version);
+        pre_compatible = pre_compatible || pre_is_compatible(&comparator, &version);
+
+        pos = skip_whitespace(req, next);
+        if pos == req.len() {
+            break;
+        }
+        if req[pos] != b',' {
+            panic!("Invalid version requirement.");
+        }
+        pos = skip_whitespace(req, pos + 1);
+    }
+
+    // Pre-release versions match only the comparators explicitly mentioning
+    // a pre-release of the same version.
+    matches && (version.pre.is_empty() || pre_compatible)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Tilde,
+    Caret,
+    Wildcard,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Version<'a> {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: &'a [u8],
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Comparator<'a> {
+    op: Op,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: &'a [u8],
+}
+
+const fn parse_version(bytes: &[u8]) -> Version<'_> {
+    let (major, pos) = parse_number(bytes, 0);
+    let pos = expect_dot(bytes, pos);
+    let (minor, pos) = parse_number(bytes, pos);
+    let pos = expect_dot(bytes, pos);
+    let (patch, pos) = parse_number(bytes, pos);
+    let (pre, pos) = parse_identifiers(bytes, pos, b'-');
+    let (_build, pos) = parse_identifiers(bytes, pos, b'+');
+
+    if pos != bytes.len() {
+        panic!("Invalid version.");
+    }
+
+    Version {
+        major,
+        minor,
+        patch,
+        pre,
+    }
+}
+
+const fn parse_comparator(bytes: &[u8], pos: usize) -> (Comparator<'_>, usize) {
+    let (op, pos) = parse_op(bytes, pos);
+    let pos = skip_whitespace(bytes, pos);
+
+    let (major, pos) = parse_part(bytes, pos);
+    let Some(major) = major else {
+        // `*` matches every version.
+        let pos = skip_wildcards(bytes, pos);
+        let comparator = Comparator {
+            op: Op::GreaterEq,
+            major: 0,
+            minor: Some(0),
+            patch: Some(0),
+            pre: &[],
+        };
+        return (comparator, pos);
+    };
+
+    let (minor, pos) = parse_optional_part(bytes, pos);
+    let (patch, pos) = match minor {
+        Some(_) => parse_optional_part(bytes, pos),
+        None => (None, skip_wildcards(bytes, pos)),
+    };
+    let (pre, pos) = match patch {
+        Some(_) => parse_identifiers(bytes, pos, b'-'),
+        None => (&[] as &[u8], pos),
+    };
+    let (_build, pos) = parse_identifiers(bytes, pos, b'+');
+
+    let op = match op {
+        Some(op) => op,
+        None if minor.is_none() || patch.is_none() => {
+            if pos > 0 && is_wildcard(bytes[pos - 1]) {
+                Op::Wildcard
+            } else {
+                Op::Caret
+            }
+        }
+        None => Op::Caret,
+    };
+
+    let comparator = Comparator {
+        op,
+        major,
+        minor,
+        patch,
+        pre,
+    };
+    (comparator, pos)
+}
+
+const fn parse_op(bytes: &[u8], pos: usize) -> (Option<Op>, usize) {
+    if pos >= bytes.len() {
+        return (None, pos);
+    }
+
+    let followed_by_eq = pos + 1 < bytes.len() && bytes[pos + 1] == b'=';
+    match bytes[pos] {
+        b'=' => (Some(Op::Exact), pos + 1),
+        b'>' if followed_by_eq => (Some(Op::GreaterEq), pos + 2),
+        b'>' => (Some(Op::Greater), pos + 1),
+        b'<' if followed_by_eq => (Some(Op::LessEq), pos + 2),
+        b'<' => (Some(Op::Less), pos + 1),
+        b'~' => (Some(Op::Tilde), pos + 1),
+        b'^' => (Some(Op::Caret), pos + 1),
+        _ => (None, pos),
+    }
+}
+
+/// Parses the `.` prefixed part of the partial version. Returns `None` if the part is missing
+/// or is a wildcard.
+const fn parse_optional_part(bytes: &[u8], pos: usize) -> (Option<u64>, usize) {
+    if pos < bytes.len() && bytes[pos] == b'.' {
+        parse_part(bytes, pos + 1)
+    } else {
+        (None, pos)
+    }
+}
+
+/// Parses the version number. Returns `None` for the wildcard.
+const fn parse_part(bytes: &[u8], pos: usize) -> (Option<u64>, usize) {
+    if pos < bytes.len() && is_wildcard(bytes[pos]) {
+        return (None, pos + 1);
+    }
+
+    let (number, pos) = parse_number(bytes, pos);
+    (Some(number), pos)
+}
+
+const fn parse_number(bytes: &[u8], mut pos: usize) -> (u64, usize) {
+    let start = pos;
+    let mut number: u64 = 0;
+    while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+        number = number * 10 + (bytes[pos] - b'0') as u64;
+        pos += 1;
+    }
+
+    if pos == start {
+        panic!("Expected version number.");
+    }
+    (number, pos)
+}
+
+/// Parses the pre-release or build identifiers starting with the `prefix`.
+const fn parse_identifiers(bytes: &[u8], pos: usize, prefix: u8) -> (&[u8], usize) {
+    if pos >= bytes.len() || bytes[pos] != prefix {
+        return (&[], pos);
+    }
+
+    let start = pos + 1;
+    let mut end = start;
+    while end < bytes.len()
+        && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'-' || bytes[end] == b'.')
+    {
+        end += 1;
+    }
+
+    if end == start {
+        panic!("Expected version identifiers.");
+    }
+
+    let (_, identifiers) = bytes.split_at(start);
+    let (identifiers, _) = identifiers.split_at(end - start);
+    (identifiers, end)
+}
+
+const fn expect_dot(bytes: &[u8], pos: usize) -> usize {
+    if pos >= bytes.len() || bytes[pos] != b'.' {
+        panic!("Expected `.` in version.");
+    }
+    pos + 1
+}
+
+const fn skip_wildcards(bytes: &[u8], mut pos: usize) -> usize {
+    while pos + 1 < bytes.len() && bytes[pos] == b'.' && is_wildcard(bytes[pos + 1]) {
+        pos += 2;
+    }
+    pos
+}
+
+const fn skip_whitespace(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    pos
+}
+
+const fn is_wildcard(byte: u8) -> bool {
+    matches!(byte, b'*' | b'x' | b'X')
+}
+
+const fn matches_comparator(cmp: &Comparator, ver: &Version) -> bool {
+    match cmp.op {
+        Op::Exact | Op::Wildcard => matches_exact(cmp, ver),
+        Op::Greater => matches_greater(cmp, ver),
+        Op::GreaterEq => matches_exact(cmp, ver) || matches_greater(cmp, ver),
+        Op::Less => matches_less(cmp, ver),
+        Op::LessEq => matches_exact(cmp, ver) || matches_less(cmp, ver),
+        Op::Tilde => matches_tilde(cmp, ver),
+        Op::Caret => matches_caret(cmp, ver),
+    }
+}
+
+const fn matches_exact(cmp: &Comparator, ver: &Version) -> bool {
+    if ver.major != cmp.major {
+        return false;
+    }
+    if let Some(minor) = cmp.minor {
+        if ver.minor != minor {
+            return false;
+        }
+    }
+    if let Some(patch) = cmp.patch {
+        if ver.patch != patch {
+            return false;
+        }
+    }
+    matches!(cmp_pre(ver.pre, cmp.pre), Ordering::Equal)
+}
+
+const fn matches_greater(cmp: &Comparator, ver: &Version) -> bool {
+    if ver.major != cmp.major {
+        return ver.major > cmp.major;
+    }
+    match cmp.minor {
+        None => return false,
+        Some(minor) if ver.minor != minor => return ver.minor > minor,
+        Some(_) => (),
+    }
+    match cmp.patch {
+        None => return false,
+        Some(patch) if ver.patch != patch => return ver.patch > patch,
+        Some(_) => (),
+    }
+    matches!(cmp_pre(ver.pre, cmp.pre), Ordering::Greater)
+}
+
+const fn matches_less(cmp: &Comparator, ver: &Version) -> bool {
+    if ver.major != cmp.major {
+        return ver.major < cmp.major;
+    }
+    match cmp.minor {
+        None => return false,
+        Some(minor) if ver.minor != minor => return ver.minor < minor,
+        Some(_) => (),
+    }
+    match cmp.patch {
+        None => return false,
+        Some(patch) if ver.patch != patch => return ver.patch < patch,
+        Some(_) => (),
+    }
+    matches!(cmp_pre(ver.pre, cmp.pre), Ordering::Less)
+}
+
+const fn matches_tilde(cmp: &Comparator, ver: &Version) -> bool {
+    if ver.major != cmp.major {
+        return false;
+    }
+    if let Some(minor) = cmp.minor {
+        if ver.minor != minor {
+            return false;
+        }
+    }
+    if let Some(patch) = cmp.patch {
+        if ver.patch != patch {
+            return ver.patch > patch;
+        }
+    }
+    !matches!(cmp_pre(ver.pre, cmp.pre), Ordering::Less)
+}
+
+const fn matches_caret(cmp: &Comparator, ver: &Version) -> bool {
+    if ver.major != cmp.major {
+        return false;
+    }
+
+    let Some(minor) = cmp.minor else {
+        return true;
+    };
+
+    let Some(patch) = cmp.patch else {
+        return if cmp.major > 0 {
+            ver.minor >= minor
+        } else {
+            ver.minor == minor
+        };
+    };
+
+    if cmp.major > 0 {
+        if ver.minor != minor {
+            return ver.minor > minor;
+        } else if ver.patch != patch {
+            return ver.patch > patch;
+        }
+    } else if minor > 0 {
+        if ver.minor != minor {
+            return false;
+        } else if ver.patch != patch {
+            return ver.patch > patch;
+        }
+    } else if ver.minor != minor || ver.patch != patch {
+        return false;
+    }
+
+    !matches!(cmp_pre(ver.pre, cmp.pre), Ordering::Less)
+}
+
+const fn pre_is_compatible(cmp: &Comparator, ver: &Version) -> bool {
+    let same_minor = matches!(cmp.minor, Some(minor) if minor == ver.minor);
+    let same_patch = matches!(cmp.patch, Some(patch) if patch == ver.patch);
+    cmp.major == ver.major && same_minor && same_patch && !cmp.pre.is_empty()
+}
+
+/// Compares the pre-release identifiers. Version without the pre-release is greater
+/// than any of its pre-releases.
+const fn cmp_pre(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    match (lhs.is_empty(), rhs.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => (),
+    }
+
+    let mut lhs_pos = 0;
+    let mut rhs_pos = 0;
+    loop {
+        match (lhs_pos > lhs.len(), rhs_pos > rhs.len()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => (),
+        }
+
+        let lhs_end = identifier_end(lhs, lhs_pos);
+        let rhs_end = identifier_end(rhs, rhs_pos);
+        match cmp_identifier(lhs, lhs_pos, lhs_end, rhs, rhs_pos, rhs_end) {
+            Ordering::Equal => (),
+            ordering => return ordering,
+        }
+
+        lhs_pos = lhs_end + 1;
+        rhs_pos = rhs_end + 1;
+    }
+}
+
+const fn identifier_end(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && bytes[pos] != b'.' {
+        pos += 1;
+    }
+    pos
+}
+
+/// Numeric identifiers are compared numerically and are lower than the alphanumeric ones.
+/// Alphanumeric identifiers are compared lexically.
+const fn cmp_identifier(
+    lhs: &[u8],
+    lhs_start: usize,
+    lhs_end: usize,
+    rhs: &[u8],
+    rhs_start: usize,
+    rhs_end: usize,
+) -> Ordering {
+    let lhs_len = lhs_end - lhs_start;
+    let rhs_len = rhs_end - rhs_start;
+
+    match (
+        is_numeric(lhs, lhs_start, lhs_end),
+        is_numeric(rhs, rhs_start, rhs_end),
+    ) {
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        // Numeric identifiers have no leading zeros, so the longer is the greater.
+        (true, true) if lhs_len != rhs_len => return cmp_usize(lhs_len, rhs_len),
+        _ => (),
+    }
+
+    let mut i = 0;
+    while i < lhs_len && i < rhs_len {
+        if lhs[lhs_start + i] != rhs[rhs_start + i] {
+            return cmp_usize(lhs[lhs_start + i] as usize, rhs[rhs_start + i] as usize);
+        }
+        i += 1;
+    }
+    cmp_usize(lhs_len, rhs_len)
+}
+
+const fn is_numeric(bytes: &[u8], mut start: usize, end: usize) -> bool {
+    while start < end {
+        if !bytes[start].is_ascii_digit() {
+            return false;
+        }
+        start += 1;
+    }
+    true
+}
+
+const fn cmp_usize(lhs: usize, rhs: usize) -> Ordering {
+    if lhs < rhs {
+        Ordering::Less
+    } else if lhs > rhs {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,4 +720,171 @@ mod tests {
 
         assert_no_intersection(msgs);
     }
+
+    #[test]
+    fn version_matches_operators() {
+        assert!(version_matches("1.2.3", "=1.2.3"));
+        assert!(!version_matches("1.2.4", "=1.2.3"));
+        assert!(version_matches("1.2.9", "=1.2"));
+        assert!(!version_matches("1.3.0", "=1.2"));
+
+        assert!(version_matches("1.2.4", ">1.2.3"));
+        assert!(!version_matches("1.2.9", ">1.2"));
+        assert!(version_matches("1.3.0", ">1.2"));
+        assert!(version_matches("1.2.3", ">=1.2.3"));
+        assert!(!version_matches("1.2.2", ">=1.2.3"));
+
+        assert!(version_matches("1.9.9", "<2"));
+        assert!(!version_matches("2.0.0", "<2"));
+        assert!(version_matches("1.2.9", "<=1.2"));
+        assert!(!version_matches("1.3.0", "<=1.2"));
+
+        assert!(version_matches("1.2.9", "~1.2.3"));
+        assert!(!version_matches("1.3.0", "~1.2.3"));
+        assert!(!version_matches("1.2.2", "~1.2.3"));
+    }
+
+    #[test]
+    fn version_matches_caret() {
+        assert!(version_matches("1.9.0", "1.2.3"));
+        assert!(version_matches("1.9.0", "^1.2.3"));
+        assert!(!version_matches("2.0.0", "^1.2.3"));
+        assert!(!version_matches("1.2.2", "^1.2.3"));
+        assert!(version_matches("0.2.9", "^0.2.3"));
+        assert!(!version_matches("0.3.0", "^0.2.3"));
+        assert!(version_matches("0.0.3", "^0.0.3"));
+        assert!(!version_matches("0.0.4", "^0.0.3"));
+        assert!(version_matches("1.0.0", "^1"));
+        assert!(version_matches("0.0.9", "^0.0"));
+    }
+
+    #[test]
+    fn version_matches_wildcards_and_ranges() {
+        assert!(version_matches("5.0.0", "*"));
+        assert!(version_matches("5.0.0", ""));
+        assert!(version_matches("1.2.0", "1.*"));
+        assert!(!version_matches("2.0.0", "1.*"));
+        assert!(version_matches("1.2.7", "1.2.x"));
+        assert!(!version_matches("1.3.0", "1.2.x"));
+
+        assert!(version_matches("1.4.0", ">=1.2, <2.0"));
+        assert!(version_matches("1.4.0", " >= 1.2 ,< 2.0 "));
+        assert!(!version_matches("2.0.0", ">=1.2, <2.0"));
+        assert!(!version_matches("1.1.0", ">=1.2, <2.0"));
+        assert!(version_matches("1.2.3+build.5", "=1.2.3"));
+    }
+
+    #[test]
+    fn version_matches_pre_release() {
+        assert!(!version_matches("2.0.0-rc.1", "*"));
+        assert!(!version_matches("2.0.0-rc.1", ">=1.0"));
+        assert!(version_matches("2.0.0-rc.1", ">=2.0.0-rc.1"));
+        assert!(version_matches("2.0.0-rc.2", "^2.0.0-rc.1"));
+        assert!(!version_matches("2.0.0-beta", "^2.0.0-rc.1"));
+        assert!(version_matches("2.0.0-rc.11", ">2.0.0-rc.2"));
+        assert!(version_matches("2.0.0-rc.1.1", ">2.0.0-rc.1"));
+        assert!(version_matches("2.0.0-rc", ">2.0.0-1"));
+        assert!(version_matches("2.0.0", ">2.0.0-rc.1"));
+        assert!(!version_matches("2.0.1-rc.1", ">2.0.0-rc.1"));
+    }
+
+    #[test]
+    fn version_matches_semver_crate() {
+        let versions = [
+            "0.0.0",
+            "0.0.3",
+            "0.0.4",
+            "0.2.3",
+            "0.2.9",
+            "0.3.0",
+            "1.0.0",
+            "1.2.0",
+            "1.2.2",
+            "1.2.3",
+            "1.2.3+build.5",
+            "1.2.4",
+            "1.3.0",
+            "1.9.9",
+            "2.0.0-0",
+            "2.0.0-alpha",
+            "2.0.0-alpha.1",
+            "2.0.0-beta",
+            "2.0.0-rc.1",
+            "2.0.0-rc.1.1",
+            "2.0.0-rc.2",
+            "2.0.0-rc.11",
+            "2.0.0",
+            "2.0.1-rc.1",
+            "2.0.1",
+            "10.20.30",
+        ];
+        let reqs = [
+            "*",
+            "1.*",
+            "1.2.x",
+            "1.2.*",
+            "=1",
+            "=1.2",
+            "=1.2.3",
+            "=2.0.0-rc.1",
+            ">1",
+            ">1.2",
+            ">1.2.3",
+            ">2.0.0-rc.1",
+            ">2.0.0-1",
+            ">=0",
+            ">=1.2",
+            ">=1.2.3",
+            ">=2.0.0-rc.1",
+            "<2",
+            "<2.0.0-0",
+            "<2.0.0-rc.2",
+            "<=1.2",
+            "<=2.0.0-rc.1",
+            "~1",
+            "~1.2",
+            "~1.2.3",
+            "~2.0.0-rc.1",
+            "^0",
+            "^0.0",
+            "^0.0.3",
+            "^0.2",
+            "^0.2.3",
+            "^1",
+            "^1.2",
+            "^1.2.3",
+            "^2.0.0-rc.1",
+            "1.2.3",
+            "0.2",
+            ">=1.2, <2.0",
+            " >= 1.2 ,< 2.0 ",
+            ">=1.0, <2.0.0-0",
+            ">=2.0.0-0, <3.0.0-0",
+            ">=2.0.0-alpha, <2.0.0-rc.2",
+            ">1.2.3, <=2.0.1, ~2",
+        ];
+
+        for req in reqs {
+            let expected = semver::VersionReq::parse(req).unwrap();
+            for version in versions {
+                assert_eq!(
+                    version_matches(version, req),
+                    expected.matches(&semver::Version::parse(version).unwrap()),
+                    "`{version}` matching `{req}`"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_version() {
+        version_matches("1.2", "*");
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_version_req() {
+        version_matches("1.2.3", ">=1.2 <2.0");
+    }
 }
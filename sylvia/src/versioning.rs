@@ -0,0 +1,63 @@
+//! Module providing utilities to track the contract version with the [cw2] standard.
+//!
+//! Used by the code generated for contracts marked with `#[sv::features(cw2)]`.
+//! The contract version is recorded on instantiation and checked before the migration.
+
+use cosmwasm_std::{StdError, StdResult, Storage};
+use semver::{Version, VersionReq};
+
+/// Stores the contract name and version in the `cw2` format.
+pub fn set_contract_version(storage: &mut dyn Storage, name: &str, version: &str) -> StdResult<()> {
+    cw2::set_contract_version(storage, name, version)
+}
+
+/// Verifies if the contract can be migrated from the stored version to the `version`.
+///
+/// Returns an error if:
+/// - the stored contract name differs from the `name`,
+/// - the stored version is newer than the `version`,
+/// - the stored version doesn't match the `from` requirement, if provided.
+pub fn ensure_migration_allowed(
+    storage: &dyn Storage,
+    name: &str,
+    version: &str,
+    from: Option<&str>,
+) -> StdResult<()> {
+    let stored = cw2::get_contract_version(storage)?;
+
+    if stored.contract != name {
+        return Err(StdError::generic_err(format!(
+            "Cannot migrate from the `{}` contract to the `{}` contract.",
+            stored.contract, name
+        )));
+    }
+
+    let stored_version = parse_version(&stored.version)?;
+    let new_version = parse_version(version)?;
+
+    if stored_version > new_version {
+        return Err(StdError::generic_err(format!(
+            "Cannot migrate from version {} to the older version {}.",
+            stored_version, new_version
+        )));
+    }
+
+    if let Some(from) = from {
+        let requirement = VersionReq::parse(from)
+            .map_err(|err| StdError::generic_err(format!("Invalid version requirement: {err}")))?;
+        if !requirement.matches(&stored_version) {
+            return Err(StdError::generic_err(format!(
+                "Migration from version {} is not allowed. Expected version matching `{}`.",
+                stored_version, from
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_version(version: &str) -> StdResult<Version> {
+    Version::parse(version).map_err(|err| {
+        StdError::generic_err(format!("Invalid contract version `{version}`: {err}"))
+    })
+}
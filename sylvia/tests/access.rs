@@ -0,0 +1,189 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{Addr, StdError, Storage};
+use cw_storage_plus::Item;
+use sylvia::access::{Owned, Unauthorized};
+use sylvia::ctx::{ExecCtx, InstantiateCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::{contract, entry_points};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Unauthorized(#[from] Unauthorized),
+}
+
+pub struct Contract {
+    pub(crate) owner: Item<Addr>,
+    pub(crate) members: Item<Vec<Addr>>,
+    pub(crate) enabled: Item<bool>,
+}
+
+fn is_enabled(contract: &Contract, ctx: &ExecCtx) -> StdResult<bool> {
+    contract.enabled.load(ctx.deps.storage)
+}
+
+#[entry_points]
+#[contract]
+#[sv::error(ContractError)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            owner: Item::new("owner"),
+            members: Item::new("members"),
+            enabled: Item::new("enabled"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx, members: Vec<String>) -> StdResult<Response> {
+        self.owner.save(ctx.deps.storage, &ctx.info.sender)?;
+        let members = members.into_iter().map(Addr::unchecked).collect();
+        self.members.save(ctx.deps.storage, &members)?;
+        self.enabled.save(ctx.deps.storage, &true)?;
+        Ok(Response::new())
+    }
+
+    fn is_member(&self, ctx: &ExecCtx) -> StdResult<bool> {
+        Ok(self
+            .members
+            .load(ctx.deps.storage)?
+            .contains(&ctx.info.sender))
+    }
+
+    #[sv::msg(exec)]
+    #[sv::access(owner)]
+    fn set_enabled(&self, ctx: ExecCtx, enabled: bool) -> Result<Response, ContractError> {
+        self.enabled.save(ctx.deps.storage, &enabled)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::access(check = Self::is_member)]
+    fn members_only(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::access(check = Self::is_member, check = is_enabled)]
+    fn enabled_members_only(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn anyone(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+        Ok(Response::new())
+    }
+}
+
+impl Owned for Contract {
+    fn owner(&self, storage: &dyn Storage) -> StdResult<Option<Addr>> {
+        self.owner.may_load(storage)
+    }
+}
+
+pub mod std_error {
+    use cosmwasm_std::{Addr, Storage};
+    use sylvia::access::Owned;
+    use sylvia::contract;
+    use sylvia::ctx::{ExecCtx, InstantiateCtx};
+    use sylvia::cw_std::{Response, StdResult};
+
+    pub struct Contract;
+
+    #[contract]
+    impl Contract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(exec)]
+        #[sv::access(owner)]
+        fn owner_only(&self, _ctx: ExecCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+
+    impl Owned for Contract {
+        fn owner(&self, _storage: &dyn Storage) -> StdResult<Option<Addr>> {
+            Ok(None)
+        }
+    }
+}
+
+#[test]
+fn access_checks() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = deps.api.addr_make("owner");
+    let member = deps.api.addr_make("member");
+    let stranger = deps.api.addr_make("stranger");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        sv::InstantiateMsg::new(vec![member.to_string()]),
+    )
+    .unwrap();
+
+    let mut execute = |sender: &Addr, msg: sv::ExecMsg| {
+        entry_points::execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(sender, &[]),
+            sv::ContractExecMsg::Contract(msg),
+        )
+    };
+
+    execute(&stranger, sv::ExecMsg::anyone()).unwrap();
+
+    execute(&owner, sv::ExecMsg::set_enabled(true)).unwrap();
+    let err = execute(&member, sv::ExecMsg::set_enabled(false)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized(Unauthorized::new("set_enabled", member.clone()))
+    );
+
+    execute(&member, sv::ExecMsg::members_only()).unwrap();
+    let err = execute(&owner, sv::ExecMsg::members_only()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized(Unauthorized::new("members_only", owner.clone()))
+    );
+
+    execute(&member, sv::ExecMsg::enabled_members_only()).unwrap();
+    execute(&owner, sv::ExecMsg::set_enabled(false)).unwrap();
+    let err = execute(&member, sv::ExecMsg::enabled_members_only()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized(Unauthorized::new("enabled_members_only", member.clone()))
+    );
+}
+
+#[test]
+fn unauthorized_std_error() {
+    let mut deps = mock_dependencies();
+    let sender = deps.api.addr_make("sender");
+
+    let err = std_error::sv::ExecMsg::owner_only()
+        .dispatch(
+            &std_error::Contract::new(),
+            (deps.as_mut(), mock_env(), message_info(&sender, &[])),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(format!(
+            "Sender {sender} is not authorized to call `owner_only`."
+        ))
+    );
+}
@@ -0,0 +1,165 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{from_json, Addr, StdError};
+use cw_storage_plus::{Item, Map};
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Empty, Order, Response, StdResult};
+use sylvia::{contract, entry_points};
+
+pub mod delegation {
+    use sylvia::ctx::QueryCtx;
+    use sylvia::cw_std::{Addr, StdError, StdResult};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Delegation {
+        type Error: From<StdError>;
+
+        #[sv::msg(query)]
+        fn delegate(&self, ctx: QueryCtx, member: Addr) -> StdResult<Option<Addr>>;
+    }
+}
+
+pub struct Contract {
+    admin: Item<Addr>,
+    members: Map<&'static Addr, Empty>,
+    delegates: Map<&'static Addr, Addr>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::messages(delegation)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            admin: Item::new("admin"),
+            members: Map::new("members"),
+            delegates: Map::new("delegates"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx, admin: Addr) -> StdResult<Response> {
+        self.admin.save(ctx.deps.storage, &admin)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn add_members(&self, ctx: ExecCtx, members: Vec<Addr>) -> StdResult<Response> {
+        for member in &members {
+            self.members.save(ctx.deps.storage, member, &Empty {})?;
+        }
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn set_delegate(&self, ctx: ExecCtx, delegate: Option<Addr>) -> StdResult<Response> {
+        match delegate {
+            Some(delegate) => self
+                .delegates
+                .save(ctx.deps.storage, &ctx.info.sender, &delegate)?,
+            None => self.delegates.remove(ctx.deps.storage, &ctx.info.sender),
+        }
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn members(&self, ctx: QueryCtx) -> StdResult<Vec<Addr>> {
+        self.members
+            .keys(ctx.deps.storage, None, None, Order::Ascending)
+            .collect()
+    }
+}
+
+impl delegation::Delegation for Contract {
+    type Error = StdError;
+
+    fn delegate(&self, ctx: QueryCtx, member: Addr) -> StdResult<Option<Addr>> {
+        self.delegates.may_load(ctx.deps.storage, &member)
+    }
+}
+
+#[test]
+fn addresses_are_validated_in_dispatch() {
+    let mut deps = mock_dependencies();
+    let admin = deps.api.addr_make("admin");
+    let member = deps.api.addr_make("member");
+
+    let info = message_info(&admin, &[]);
+    let msg = sv::InstantiateMsg::new("admin".to_owned());
+    let err = entry_points::instantiate(deps.as_mut(), mock_env(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, StdError::GenericErr { .. }));
+
+    let msg = sv::InstantiateMsg::new(admin.to_string());
+    entry_points::instantiate(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+    let mut execute = |msg| entry_points::execute(deps.as_mut(), mock_env(), info.clone(), msg);
+
+    let msg = sv::ExecMsg::add_members(vec![member.to_string(), "invalid".to_owned()]);
+    let err = execute(sv::ContractExecMsg::Contract(msg)).unwrap_err();
+    assert!(matches!(err, StdError::GenericErr { .. }));
+
+    let msg = sv::ExecMsg::add_members(vec![member.to_string()]);
+    execute(sv::ContractExecMsg::Contract(msg)).unwrap();
+
+    let msg = sv::ExecMsg::set_delegate(Some("invalid".to_owned()));
+    let err = execute(sv::ContractExecMsg::Contract(msg)).unwrap_err();
+    assert!(matches!(err, StdError::GenericErr { .. }));
+
+    let msg = sv::ExecMsg::set_delegate(None);
+    execute(sv::ContractExecMsg::Contract(msg)).unwrap();
+
+    let msg = sv::ExecMsg::set_delegate(Some(member.to_string()));
+    execute(sv::ContractExecMsg::Contract(msg)).unwrap();
+
+    let query = |msg| entry_points::query(deps.as_ref(), mock_env(), msg);
+
+    let members = query(sv::ContractQueryMsg::Contract(sv::QueryMsg::members())).unwrap();
+    assert_eq!(
+        from_json::<Vec<Addr>>(members).unwrap(),
+        vec![member.clone()]
+    );
+
+    let msg = delegation::sv::QueryMsg::delegate("invalid".to_owned());
+    let err = query(sv::ContractQueryMsg::Delegation(msg)).unwrap_err();
+    assert!(matches!(err, StdError::GenericErr { .. }));
+
+    let msg = delegation::sv::QueryMsg::delegate(admin.to_string());
+    let delegate = query(sv::ContractQueryMsg::Delegation(msg)).unwrap();
+    assert_eq!(from_json::<Option<Addr>>(delegate).unwrap(), Some(member));
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use sylvia::cw_multi_test::IntoAddr;
+    use sylvia::multitest::App;
+    use sylvia::types::BoundQuerier;
+
+    use super::delegation::sv::mt::DelegationProxy;
+    use super::delegation::sv::Querier as DelegationQuerier;
+    use super::sv::mt::{CodeId, ContractProxy};
+    use super::Contract;
+
+    #[test]
+    fn helpers_take_addresses() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let admin = "admin".into_addr();
+        let members = ["alice".into_addr(), "bob".into_addr()];
+
+        let contract = code_id.instantiate(&admin).call(&admin).unwrap();
+        contract.add_members(&members).call(&admin).unwrap();
+        contract
+            .set_delegate(Some(&members[0]))
+            .call(&admin)
+            .unwrap();
+
+        assert_eq!(contract.members().unwrap(), members);
+        assert_eq!(contract.delegate(&admin).unwrap(), Some(members[0].clone()));
+
+        let querier = app.querier();
+        let bound_querier =
+            BoundQuerier::<_, Contract>::borrowed(&contract.contract_addr, &querier);
+        assert_eq!(bound_querier.delegate(&members[1]).unwrap(), None);
+    }
+}
@@ -0,0 +1,152 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{from_json, to_json_binary, Binary, StdError, Uint128};
+use cw_storage_plus::Item;
+use sylvia::ctx::{Cw20ReceiveCtx, ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::{contract, entry_points};
+
+pub struct Contract {
+    pub(crate) deposited: Item<Uint128>,
+}
+
+#[entry_points]
+#[contract]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            deposited: Item::new("deposited"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        self.deposited.save(ctx.deps.storage, &Uint128::zero())?;
+        Ok(Response::new())
+    }
+
+    /// Deposits the received tokens.
+    #[sv::msg(exec, cw20_receive)]
+    fn deposit(&self, ctx: Cw20ReceiveCtx, memo: String) -> StdResult<Response> {
+        self.deposited
+            .update(ctx.deps.storage, |deposited| -> StdResult<_> {
+                Ok(deposited + ctx.amount)
+            })?;
+        Ok(Response::new()
+            .add_attribute("token", ctx.info.sender)
+            .add_attribute("sender", ctx.sender)
+            .add_attribute("memo", memo))
+    }
+
+    #[sv::msg(exec, cw20_receive)]
+    fn reject(&self, _ctx: Cw20ReceiveCtx) -> StdResult<Response> {
+        Err(StdError::generic_err("Tokens rejected"))
+    }
+
+    #[sv::msg(exec)]
+    fn reset(&self, ctx: ExecCtx) -> StdResult<Response> {
+        self.deposited.save(ctx.deps.storage, &Uint128::zero())?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn deposited(&self, ctx: QueryCtx) -> StdResult<Uint128> {
+        self.deposited.load(ctx.deps.storage)
+    }
+}
+
+#[test]
+fn receive_messages() {
+    assert_eq!(sv::execute_messages(), ["receive", "reset"]);
+    assert_eq!(sv::cw20_receive_messages(), ["deposit", "reject"]);
+
+    let msg = sv::ReceiveMsg::deposit("memo".to_owned());
+    assert_eq!(
+        to_json_binary(&msg).unwrap(),
+        Binary::from(br#"{"deposit":{"memo":"memo"}}"#)
+    );
+
+    // The cw20 contract sends the `Receive` envelope.
+    let envelope = br#"{"receive":{"sender":"sender","amount":"10","msg":"eyJyZWplY3QiOnt9fQ=="}}"#;
+    assert_eq!(
+        from_json::<sv::ContractExecMsg>(envelope).unwrap(),
+        sv::ContractExecMsg::Contract(sv::ExecMsg::receive(
+            "sender".to_owned(),
+            Uint128::new(10),
+            to_json_binary(&sv::ReceiveMsg::reject()).unwrap(),
+        ))
+    );
+}
+
+#[test]
+fn receive_dispatch() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let token = deps.api.addr_make("token");
+    let sender = deps.api.addr_make("sender");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&sender, &[]),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    let mut receive = |sender: String, msg: Binary| {
+        entry_points::execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&token, &[]),
+            sv::ContractExecMsg::Contract(sv::ExecMsg::receive(sender, Uint128::new(10), msg)),
+        )
+    };
+
+    let resp = receive(
+        sender.to_string(),
+        to_json_binary(&sv::ReceiveMsg::deposit("memo".to_owned())).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(resp.attributes[0].value, token.as_str());
+    assert_eq!(resp.attributes[1].value, sender.as_str());
+    assert_eq!(resp.attributes[2].value, "memo");
+
+    let err = receive(
+        sender.to_string(),
+        to_json_binary(&sv::ReceiveMsg::reject()).unwrap(),
+    )
+    .unwrap_err();
+    assert_eq!(err, StdError::generic_err("Tokens rejected"));
+
+    receive(
+        sender.to_string(),
+        Binary::from(br#"{"unknown":{}}"#.as_slice()),
+    )
+    .unwrap_err();
+    receive(
+        "invalid".to_owned(),
+        to_json_binary(&sv::ReceiveMsg::deposit("memo".to_owned())).unwrap(),
+    )
+    .unwrap_err();
+
+    let deposited = entry_points::query(
+        deps.as_ref(),
+        env,
+        sv::ContractQueryMsg::Contract(sv::QueryMsg::deposited()),
+    )
+    .unwrap();
+    assert_eq!(from_json::<Uint128>(deposited).unwrap(), Uint128::new(10));
+}
+
+#[test]
+fn receive_schema() {
+    let schema = sv::generate_schema();
+
+    assert_eq!(
+        schema.cw20_receive,
+        Some(sylvia::schemars::schema_for!(sv::ReceiveMsg))
+    );
+
+    let ts = sylvia::codegen::typescript(&schema).unwrap();
+    assert!(ts.contains("export type SylviaReceiveMsg = "));
+    assert!(ts.contains("{ deposit: { memo: string } }"));
+}
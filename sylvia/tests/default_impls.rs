@@ -0,0 +1,157 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{from_json, Addr, Empty, StdError};
+use cw_storage_plus::Map;
+use sylvia::ctx::{ExecCtx, InstantiateCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::{contract, entry_points};
+
+pub mod whitelist {
+    use cosmwasm_std::{Addr, Empty, Order};
+    use cw_storage_plus::Map;
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::interface;
+    use sylvia::types::{CustomMsg, CustomQuery};
+
+    #[interface]
+    pub trait Whitelist {
+        type Error: From<StdError>;
+        type ExecC: CustomMsg;
+        type QueryC: CustomQuery;
+
+        /// Storage of the admins used by the default implementations.
+        fn admins(&self) -> &Map<&'static Addr, Empty>;
+
+        #[sv::msg(exec)]
+        fn add_admin(
+            &self,
+            ctx: ExecCtx<Self::QueryC>,
+            admin: String,
+        ) -> Result<Response<Self::ExecC>, Self::Error> {
+            let admin = ctx.deps.api.addr_validate(&admin)?;
+            self.admins().save(ctx.deps.storage, &admin, &Empty {})?;
+            Ok(Response::new().add_attribute("action", "add_admin"))
+        }
+
+        #[sv::msg(exec)]
+        fn remove_admin(
+            &self,
+            ctx: ExecCtx<Self::QueryC>,
+            admin: String,
+        ) -> Result<Response<Self::ExecC>, Self::Error> {
+            let admin = ctx.deps.api.addr_validate(&admin)?;
+            self.admins().remove(ctx.deps.storage, &admin);
+            Ok(Response::new().add_attribute("action", "remove_admin"))
+        }
+
+        #[sv::msg(query)]
+        fn admins_list(&self, ctx: QueryCtx<Self::QueryC>) -> StdResult<Vec<Addr>> {
+            self.admins()
+                .keys(ctx.deps.storage, None, None, Order::Ascending)
+                .collect()
+        }
+    }
+}
+
+pub struct Contract {
+    admins: Map<&'static Addr, Empty>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::messages(whitelist)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            admins: Map::new("admins"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+impl whitelist::Whitelist for Contract {
+    type Error = StdError;
+    type ExecC = Empty;
+    type QueryC = Empty;
+
+    fn admins(&self) -> &Map<&'static Addr, Empty> {
+        &self.admins
+    }
+
+    // Default implementations can still be overridden.
+    fn remove_admin(&self, _ctx: ExecCtx, _admin: String) -> StdResult<Response> {
+        Err(StdError::generic_err("Admins cannot be removed"))
+    }
+}
+
+#[test]
+fn default_impls() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = deps.api.addr_make("owner");
+    let admin = deps.api.addr_make("admin");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    let mut execute = |msg: whitelist::sv::ExecMsg| {
+        entry_points::execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&owner, &[]),
+            sv::ContractExecMsg::Whitelist(msg),
+        )
+    };
+
+    let resp = execute(whitelist::sv::ExecMsg::add_admin(admin.to_string())).unwrap();
+    assert_eq!(resp.attributes[0].value, "add_admin");
+
+    let err = execute(whitelist::sv::ExecMsg::remove_admin(admin.to_string())).unwrap_err();
+    assert_eq!(err, StdError::generic_err("Admins cannot be removed"));
+
+    let resp = entry_points::query(
+        deps.as_ref(),
+        env,
+        sv::ContractQueryMsg::Whitelist(whitelist::sv::QueryMsg::admins_list()),
+    )
+    .unwrap();
+    assert_eq!(from_json::<Vec<Addr>>(resp).unwrap(), vec![admin]);
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use sylvia::cw_multi_test::IntoAddr;
+    use sylvia::cw_std::StdError;
+    use sylvia::multitest::App;
+
+    use super::sv::mt::CodeId;
+    use super::whitelist::sv::mt::WhitelistProxy;
+
+    #[test]
+    fn default_impls_proxy() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_addr();
+        let admin = "admin".into_addr();
+
+        let contract = code_id.instantiate().call(&owner).unwrap();
+
+        contract.add_admin(admin.to_string()).call(&owner).unwrap();
+        assert_eq!(contract.admins_list().unwrap(), vec![admin.clone()]);
+
+        let err = contract
+            .remove_admin(admin.to_string())
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(err, StdError::generic_err("Admins cannot be removed"));
+    }
+}
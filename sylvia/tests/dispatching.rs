@@ -2,7 +2,7 @@ use cw_multi_test::IntoAddr;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sylvia::cw_std::testing::{message_info, mock_dependencies, mock_env};
-use sylvia::cw_std::{from_json, Addr, Decimal, Response};
+use sylvia::cw_std::{from_json, Decimal, Response};
 
 use crate::contract::Contract;
 
@@ -194,7 +194,7 @@ fn dispatch() {
     assert_eq!(resp, Response::new());
 
     let resp = interface::sv::ExecMsg::ArgumentedExecution {
-        addr: Addr::unchecked("addr1"),
+        addr: "addr1".into_addr().to_string(),
         coef: Decimal::percent(30),
         desc: "True".to_owned(),
     }
@@ -203,7 +203,7 @@ fn dispatch() {
     assert_eq!(resp, Response::new());
 
     let resp = interface::sv::ExecMsg::ArgumentedExecution {
-        addr: Addr::unchecked("addr2"),
+        addr: "addr2".into_addr().to_string(),
         coef: Decimal::percent(70),
         desc: "False".to_owned(),
     }
@@ -218,7 +218,7 @@ fn dispatch() {
     let _resp: EmptyQueryResponse = from_json(resp).unwrap();
 
     let resp = interface::sv::QueryMsg::ArgumentedQuery {
-        user: Addr::unchecked("addr2"),
+        user: "addr2".into_addr().to_string(),
     }
     .dispatch(&contract, (deps.as_ref(), env.clone()))
     .unwrap();
@@ -238,13 +238,13 @@ fn dispatch() {
     assert_eq!(resp, Response::new());
 
     let resp = interface::sv::SudoMsg::ArgumentedSudo {
-        user: Addr::unchecked("addr2"),
+        user: "addr2".into_addr().to_string(),
     }
     .dispatch(&contract, (deps.as_mut(), env.clone()))
     .unwrap();
     assert_eq!(
         resp,
-        Response::new().add_attribute("user", "addr2".to_owned())
+        Response::new().add_attribute("user", "addr2".into_addr())
     );
 
     let resp = contract::sv::SudoMsg::ContractSudo {}
@@ -0,0 +1,232 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{Addr, Event, Uint128};
+use sylvia::ctx::{ExecCtx, InstantiateCtx};
+use sylvia::cw_schema::cw_serde;
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::events::TypedEvent;
+use sylvia::{contract, entry_points, event};
+
+#[cw_serde]
+#[event]
+pub struct Transfer {
+    pub sender: Addr,
+    pub recipient: Addr,
+    pub amount: Uint128,
+    pub memo: Option<String>,
+}
+
+#[cw_serde]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+#[cw_serde]
+#[event(name = "counter_changed")]
+pub struct Changed {
+    pub direction: Direction,
+    pub values: Vec<u32>,
+}
+
+#[cw_serde]
+#[event]
+pub struct Reset {}
+
+pub struct Contract;
+
+#[entry_points]
+#[contract]
+#[sv::events(Transfer, Changed)]
+#[sv::events(Reset)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn transfer(&self, ctx: ExecCtx, recipient: String, amount: Uint128) -> StdResult<Response> {
+        let recipient = ctx.deps.api.addr_validate(&recipient)?;
+        let transfers = [recipient.clone(), recipient].map(|recipient| Transfer {
+            sender: ctx.info.sender.clone(),
+            recipient,
+            amount,
+            memo: None,
+        });
+        let changed = Changed {
+            direction: Direction::Down,
+            values: vec![1, 2],
+        };
+
+        Ok(Response::new()
+            .add_event(transfers[0].to_event()?)
+            .add_event(changed.to_event()?)
+            .add_event(transfers[1].to_event()?))
+    }
+}
+
+#[test]
+fn event_round_trip() {
+    let transfer = Transfer {
+        sender: Addr::unchecked("sender"),
+        recipient: Addr::unchecked("recipient"),
+        amount: Uint128::new(100),
+        memo: Some("memo".to_owned()),
+    };
+
+    let event = transfer.to_event().unwrap();
+    assert_eq!(
+        event,
+        Event::new("transfer")
+            .add_attribute("sender", "sender")
+            .add_attribute("recipient", "recipient")
+            .add_attribute("amount", "100")
+            .add_attribute("memo", "memo")
+    );
+    assert_eq!(Transfer::from_event(&event).unwrap(), transfer);
+
+    let no_memo = Transfer {
+        memo: None,
+        ..transfer
+    };
+    let event = no_memo.to_event().unwrap();
+    assert_eq!(event.attributes.len(), 3);
+    assert_eq!(Transfer::from_event(&event).unwrap(), no_memo);
+
+    let changed = Changed {
+        direction: Direction::Up,
+        values: vec![3],
+    };
+    let event = changed.to_event().unwrap();
+    assert_eq!(event.ty, "counter_changed");
+    assert_eq!(event.attributes[0].value, "up");
+    assert_eq!(event.attributes[1].value, "[3]");
+    assert_eq!(Changed::from_event(&event).unwrap(), changed);
+
+    assert_eq!(Reset {}.to_event().unwrap(), Event::new("reset"));
+    Reset::from_event(&Event::new("wasm-reset")).unwrap();
+}
+
+#[test]
+fn invalid_events_are_rejected() {
+    let err = Reset::from_event(&Event::new("transfer")).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Expected `reset` event, received `transfer`"));
+
+    let missing_amount = Event::new("transfer")
+        .add_attribute("sender", "sender")
+        .add_attribute("recipient", "recipient");
+    Transfer::from_event(&missing_amount).unwrap_err();
+
+    let invalid_amount = missing_amount.add_attribute("amount", "ten");
+    Transfer::from_event(&invalid_amount).unwrap_err();
+}
+
+#[test]
+fn contract_lists_events() {
+    const EVENTS: [&str; 3] = sv::events();
+    assert_eq!(EVENTS, ["transfer", "counter_changed", "reset"]);
+
+    let schema = sv::generate_schema();
+    assert_eq!(
+        schema.events.keys().collect::<Vec<_>>(),
+        ["counter_changed", "reset", "transfer"]
+    );
+    // Attributes are described as strings with the schema of the stored value.
+    let transfer = serde_json::to_value(&schema.events["transfer"]).unwrap();
+    assert!(transfer["description"]
+        .as_str()
+        .unwrap()
+        .contains("Values serialized as JSON strings are stored unquoted"));
+    let amount = &transfer["properties"]["amount"];
+    assert_eq!(amount["type"], "string");
+    assert_eq!(amount["contentSchema"]["$ref"], "#/definitions/Uint128");
+    let changed = serde_json::to_value(&schema.events["counter_changed"]).unwrap();
+    let values = &changed["properties"]["values"];
+    assert_eq!(values["type"], "string");
+    assert_eq!(values["contentSchema"]["type"], "array");
+
+    let out_dir = std::env::temp_dir().join(format!("sylvia-events-{}", std::process::id()));
+    let written = schema.write(&out_dir).unwrap();
+    for event in EVENTS {
+        let path = out_dir.join("events").join(format!("{event}.json"));
+        assert!(written.contains(&path), "{} not written", path.display());
+        assert!(path.is_file(), "{} is missing", path.display());
+    }
+    std::fs::remove_dir_all(out_dir).unwrap();
+}
+
+#[test]
+fn events_emitted_by_contract() {
+    let mut deps = mock_dependencies();
+    let sender = deps.api.addr_make("sender");
+    let recipient = deps.api.addr_make("recipient");
+
+    let resp = entry_points::execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&sender, &[]),
+        sv::ContractExecMsg::Contract(sv::ExecMsg::transfer(
+            recipient.to_string(),
+            Uint128::new(5),
+        )),
+    )
+    .unwrap();
+
+    let transfer = Transfer::from_event(&resp.events[0]).unwrap();
+    assert_eq!(transfer.sender, sender);
+    assert_eq!(transfer.recipient, recipient);
+    assert_eq!(transfer.amount, Uint128::new(5));
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use cosmwasm_std::Uint128;
+    use sylvia::cw_multi_test::IntoAddr;
+    use sylvia::multitest::{App, TypedEvents};
+
+    use super::sv::mt::{CodeId, ContractProxy};
+    use super::{Changed, Direction, Reset, Transfer};
+
+    #[test]
+    fn typed_events_from_app_response() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_addr();
+        let recipient = "recipient".into_addr();
+
+        let contract = code_id.instantiate().call(&owner).unwrap();
+
+        let resp = contract
+            .transfer(recipient.to_string(), Uint128::new(10))
+            .call(&owner)
+            .unwrap();
+        assert!(resp.events.iter().any(|event| event.ty == "wasm-transfer"));
+
+        let transfers: Vec<Transfer> = resp.typed_events().unwrap();
+        assert_eq!(
+            transfers,
+            vec![
+                Transfer {
+                    sender: owner.clone(),
+                    recipient: recipient.clone(),
+                    amount: Uint128::new(10),
+                    memo: None,
+                };
+                2
+            ]
+        );
+
+        let changed: Changed = resp.typed_event().unwrap();
+        assert_eq!(changed.direction, Direction::Down);
+        assert_eq!(changed.values, [1, 2]);
+
+        assert!(resp.typed_events::<Reset>().unwrap().is_empty());
+        resp.typed_event::<Reset>().unwrap_err();
+    }
+}
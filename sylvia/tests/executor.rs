@@ -115,7 +115,6 @@ impl CounterContract {
 #[cfg(test)]
 mod tests {
     use cw_multi_test::IntoBech32;
-    use sylvia::cw_std::Addr;
     use sylvia::multitest::App;
 
     use crate::counter::sv::mt::CounterProxy;
@@ -129,13 +128,13 @@ mod tests {
         let owner = "owner".into_bech32();
 
         let first_contract = code_id
-            .instantiate(Addr::unchecked("remote"))
+            .instantiate(&"remote".into_bech32())
             .with_label("First Counter")
             .call(&owner)
             .unwrap();
 
         let second_contract = code_id
-            .instantiate(first_contract.contract_addr.clone())
+            .instantiate(&first_contract.contract_addr)
             .with_label("Second Counter")
             .call(&owner)
             .unwrap();
@@ -0,0 +1,156 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{coin, from_json, Addr, Coin, DepsMut, StdError, Uint128, Uint256};
+use cw_storage_plus::Item;
+use cw_utils::PaymentError;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{CustomQuery, Response, StdResult};
+use sylvia::extract::FromExecCtx;
+use sylvia::{contract, entry_points};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+    #[error("Unauthorized")]
+    Unauthorized,
+}
+
+/// Address of the contract owner, rejects other senders.
+pub struct Owner(Addr);
+
+impl<Q: CustomQuery> FromExecCtx<Contract, Q> for Owner {
+    type Error = ContractError;
+
+    fn from_exec_ctx(contract: &Contract, ctx: &ExecCtx<Q>) -> Result<Self, ContractError> {
+        let owner = contract.owner.load(ctx.deps.storage)?;
+        match owner == ctx.info.sender {
+            true => Ok(Self(owner)),
+            false => Err(ContractError::Unauthorized),
+        }
+    }
+}
+
+/// Amount of `uatom` sent with the message.
+pub struct PaidFunds(Uint256);
+
+impl<Q: CustomQuery> FromExecCtx<Contract, Q> for PaidFunds {
+    type Error = PaymentError;
+
+    fn from_exec_ctx(_contract: &Contract, ctx: &ExecCtx<Q>) -> Result<Self, PaymentError> {
+        cw_utils::must_pay(&ctx.info, "uatom").map(PaidFunds)
+    }
+}
+
+pub struct Contract {
+    owner: Item<Addr>,
+    counter: Item<u128>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::error(ContractError)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            owner: Item::new("owner"),
+            counter: Item::new("counter"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        self.owner.save(ctx.deps.storage, &ctx.info.sender)?;
+        self.counter.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn set_counter(
+        &self,
+        ctx: ExecCtx,
+        #[sv::extract] owner: Owner,
+        value: u128,
+    ) -> Result<Response, ContractError> {
+        self.counter.save(ctx.deps.storage, &value)?;
+        Ok(Response::new().add_attribute("owner", owner.0))
+    }
+
+    #[sv::msg(exec)]
+    fn deposit(
+        &self,
+        ctx: ExecCtx,
+        multiplier: u128,
+        #[sv::extract] funds: PaidFunds,
+        #[sv::extract] _owner: Owner,
+        offset: u128,
+    ) -> Result<Response, ContractError> {
+        let paid = Uint128::try_from(funds.0).map_err(StdError::from)?;
+        let value = paid.u128() * multiplier + offset;
+        self.counter.save(ctx.deps.storage, &value)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn counter(&self, ctx: QueryCtx) -> StdResult<u128> {
+        self.counter.load(ctx.deps.storage)
+    }
+}
+
+fn execute(
+    deps: DepsMut,
+    sender: &Addr,
+    funds: &[Coin],
+    msg: sv::ExecMsg,
+) -> Result<Response, ContractError> {
+    let msg = sv::ContractExecMsg::Contract(msg);
+    entry_points::execute(deps, mock_env(), message_info(sender, funds), msg)
+}
+
+#[test]
+fn extracted_params() {
+    let mut deps = mock_dependencies();
+    let owner = Addr::unchecked("owner");
+    let other = Addr::unchecked("other");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    let err = execute(deps.as_mut(), &other, &[], sv::ExecMsg::set_counter(5)).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized);
+    let resp = execute(deps.as_mut(), &owner, &[], sv::ExecMsg::set_counter(5)).unwrap();
+    assert_eq!(resp.attributes[0].value, "owner");
+
+    let err = execute(deps.as_mut(), &owner, &[], sv::ExecMsg::deposit(2, 1)).unwrap_err();
+    assert_eq!(err, ContractError::Payment(PaymentError::NoFunds {}));
+    let atoms = [coin(10, "uatom")];
+    let err = execute(deps.as_mut(), &other, &atoms, sv::ExecMsg::deposit(2, 1)).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized);
+    execute(deps.as_mut(), &owner, &atoms, sv::ExecMsg::deposit(2, 1)).unwrap();
+
+    let counter = entry_points::query(
+        deps.as_ref(),
+        mock_env(),
+        sv::ContractQueryMsg::Contract(sv::QueryMsg::counter()),
+    )
+    .unwrap();
+    assert_eq!(from_json::<u128>(counter).unwrap(), 21);
+}
+
+#[test]
+fn extracted_params_not_in_message() {
+    assert_eq!(
+        serde_json::to_value(sv::ExecMsg::deposit(2, 1)).unwrap(),
+        serde_json::json!({ "deposit": { "multiplier": 2, "offset": 1 } })
+    );
+
+    let schema = serde_json::to_string(&sylvia::cw_schema::schema_for!(sv::ExecMsg)).unwrap();
+    assert!(!schema.contains("owner"));
+    assert!(!schema.contains("funds"));
+}
@@ -0,0 +1,217 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{coin, Addr, Coin, DepsMut, StdError};
+use cw_utils::PaymentError;
+use sylvia::ctx::{ExecCtx, InstantiateCtx};
+use sylvia::cw_schema::schema_for;
+use sylvia::cw_std::Response;
+use sylvia::{contract, entry_points};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+}
+
+pub mod donations {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::cw_utils::PaymentError;
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Donations {
+        type Error: From<StdError> + From<PaymentError>;
+
+        #[sv::msg(exec, payable = ["uatom"])]
+        fn donate(&self, ctx: ExecCtx) -> Result<Response, Self::Error>;
+
+        #[sv::msg(exec, nonpayable)]
+        fn thank(&self, ctx: ExecCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct Contract;
+
+#[entry_points]
+#[contract]
+#[sv::error(ContractError)]
+#[sv::features(nonpayable)]
+#[sv::messages(donations)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate, payable = ["uatom", "ujuno"])]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> Result<Response, ContractError> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn update(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec, payable)]
+    fn deposit(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+        Ok(Response::new())
+    }
+}
+
+impl donations::Donations for Contract {
+    type Error = ContractError;
+
+    fn donate(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+        Ok(Response::new())
+    }
+
+    fn thank(&self, _ctx: ExecCtx) -> Result<Response, ContractError> {
+        Ok(Response::new())
+    }
+}
+
+fn execute(
+    deps: DepsMut,
+    funds: &[Coin],
+    msg: sv::ContractExecMsg,
+) -> Result<Response, ContractError> {
+    let sender = Addr::unchecked("sender");
+    entry_points::execute(deps, mock_env(), message_info(&sender, funds), msg)
+}
+
+#[test]
+fn funds_are_checked_in_dispatch() {
+    let mut deps = mock_dependencies();
+    let sender = Addr::unchecked("sender");
+    let atoms = [coin(10, "uatom")];
+    let osmos = [coin(10, "uosmo")];
+
+    let err = entry_points::instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&sender, &osmos),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Payment(PaymentError::ExtraDenom("uosmo".to_owned()))
+    );
+    entry_points::instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&sender, &atoms),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    let update = || sv::ContractExecMsg::Contract(sv::ExecMsg::update());
+    let err = execute(deps.as_mut(), &atoms, update()).unwrap_err();
+    assert_eq!(err, ContractError::Payment(PaymentError::NonPayable {}));
+    execute(deps.as_mut(), &[], update()).unwrap();
+
+    let deposit = || sv::ContractExecMsg::Contract(sv::ExecMsg::deposit());
+    execute(deps.as_mut(), &atoms, deposit()).unwrap();
+    execute(deps.as_mut(), &osmos, deposit()).unwrap();
+
+    let donate = || sv::ContractExecMsg::Donations(donations::sv::ExecMsg::donate());
+    execute(deps.as_mut(), &atoms, donate()).unwrap();
+    let err = execute(deps.as_mut(), &osmos, donate()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Payment(PaymentError::ExtraDenom("uosmo".to_owned()))
+    );
+    // `payable` only restricts the denoms.
+    execute(deps.as_mut(), &[], donate()).unwrap();
+
+    let thank = || sv::ContractExecMsg::Donations(donations::sv::ExecMsg::thank());
+    execute(deps.as_mut(), &[], thank()).unwrap();
+    execute(deps.as_mut(), &atoms, thank()).unwrap_err();
+}
+
+#[test]
+fn funds_requirement_in_schema() {
+    let schema = schema_for!(sv::ExecMsg);
+    let schema = serde_json::to_value(schema).unwrap();
+    let descriptions: Vec<_> = schema["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|variant| variant["description"].as_str())
+        .collect();
+    assert_eq!(
+        descriptions,
+        [
+            Some("Nonpayable: the message rejects any funds."),
+            Some("Payable: the message accepts funds in any denom."),
+        ]
+    );
+
+    let schema = schema_for!(sv::InstantiateMsg);
+    let schema = serde_json::to_value(schema).unwrap();
+    assert_eq!(
+        schema["description"],
+        "Payable: the message accepts funds only in `uatom`, `ujuno`."
+    );
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use sylvia::cw_multi_test::IntoAddr;
+    use sylvia::cw_std::{coin, coins};
+    use sylvia::multitest::App;
+
+    use super::donations::sv::mt::DonationsProxy;
+    use super::sv::mt::{CodeId, ContractProxy};
+
+    #[test]
+    fn proxies_send_funds() {
+        let owner = "owner".into_addr();
+        let app = sylvia::cw_multi_test::App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(storage, &owner, coins(100, "uatom"))
+                .unwrap();
+        });
+        let app = App::new(app);
+        let code_id = CodeId::store_code(&app);
+
+        let contract = code_id
+            .instantiate()
+            .with_funds(&coins(10, "uatom"))
+            .call(&owner)
+            .unwrap();
+
+        contract
+            .update()
+            .with_funds(&coins(10, "uatom"))
+            .call(&owner)
+            .unwrap_err();
+        contract.update().call(&owner).unwrap();
+        contract
+            .deposit()
+            .with_funds(&coins(10, "uatom"))
+            .call(&owner)
+            .unwrap();
+        contract
+            .donate()
+            .with_funds(&coins(10, "uatom"))
+            .call(&owner)
+            .unwrap();
+        contract
+            .thank()
+            .with_funds(&coins(10, "uatom"))
+            .call(&owner)
+            .unwrap_err();
+
+        assert_eq!(
+            app.querier()
+                .query_balance(&contract.contract_addr, "uatom")
+                .unwrap(),
+            coin(30, "uatom")
+        );
+    }
+}
@@ -0,0 +1,428 @@
+#![cfg(feature = "stargate")]
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_ibc_channel_close_init, mock_ibc_channel_connect_ack,
+    mock_ibc_channel_open_init, mock_ibc_packet_ack, mock_ibc_packet_recv, mock_ibc_packet_timeout,
+};
+use cosmwasm_std::{IbcAcknowledgement, IbcOrder};
+use cw_storage_plus::Item;
+use sylvia::contract;
+use sylvia::ctx::{
+    IbcChannelCloseCtx, IbcChannelConnectCtx, IbcChannelOpenCtx, IbcPacketAckCtx,
+    IbcPacketReceiveCtx, IbcPacketTimeoutCtx, InstantiateCtx, QueryCtx,
+};
+use sylvia::cw_std::{
+    Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, Response, StdError, StdResult,
+};
+use sylvia::entry_points;
+
+const IBC_VERSION: &str = "sylvia-1";
+
+pub struct PacketCounter {
+    pub(crate) channel: Item<String>,
+    pub(crate) packets: Item<u32>,
+}
+
+#[entry_points]
+#[contract]
+impl PacketCounter {
+    pub const fn new() -> Self {
+        Self {
+            channel: Item::new("channel"),
+            packets: Item::new("packets"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        self.packets.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn packets(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.packets.load(ctx.deps.storage)
+    }
+
+    #[sv::msg(ibc_channel_open)]
+    fn channel_open(
+        &self,
+        _ctx: IbcChannelOpenCtx,
+        msg: IbcChannelOpenMsg,
+    ) -> StdResult<IbcChannelOpenResponse> {
+        if msg.channel().version != IBC_VERSION {
+            return Err(StdError::generic_err("Invalid channel version"));
+        }
+
+        Ok(Some(Ibc3ChannelOpenResponse {
+            version: IBC_VERSION.to_owned(),
+        }))
+    }
+
+    #[sv::msg(ibc_channel_connect)]
+    fn channel_connect(
+        &self,
+        ctx: IbcChannelConnectCtx,
+        msg: IbcChannelConnectMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        let channel_id = &msg.channel().endpoint.channel_id;
+        self.channel.save(ctx.deps.storage, channel_id)?;
+        Ok(IbcBasicResponse::new().add_attribute("action", "channel_connect"))
+    }
+
+    #[sv::msg(ibc_channel_close)]
+    fn channel_close(
+        &self,
+        ctx: IbcChannelCloseCtx,
+        _msg: IbcChannelCloseMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        self.channel.remove(ctx.deps.storage);
+        Ok(IbcBasicResponse::new().add_attribute("action", "channel_close"))
+    }
+
+    #[sv::msg(ibc_packet_receive)]
+    fn packet_receive(
+        &self,
+        ctx: IbcPacketReceiveCtx,
+        _msg: IbcPacketReceiveMsg,
+    ) -> StdResult<IbcReceiveResponse> {
+        self.packets
+            .update(ctx.deps.storage, |packets| -> StdResult<_> {
+                Ok(packets + 1)
+            })?;
+        Ok(IbcReceiveResponse::new(b"ack"))
+    }
+
+    #[sv::msg(ibc_packet_ack)]
+    fn packet_ack(
+        &self,
+        _ctx: IbcPacketAckCtx,
+        msg: IbcPacketAckMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        Ok(IbcBasicResponse::new()
+            .add_attribute("action", "packet_ack")
+            .add_attribute("ack", msg.acknowledgement.data.to_string()))
+    }
+
+    #[sv::msg(ibc_packet_timeout)]
+    fn packet_timeout(
+        &self,
+        _ctx: IbcPacketTimeoutCtx,
+        _msg: IbcPacketTimeoutMsg,
+    ) -> StdResult<IbcBasicResponse> {
+        Ok(IbcBasicResponse::new().add_attribute("action", "packet_timeout"))
+    }
+}
+
+#[test]
+fn ibc_entry_points() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let contract = PacketCounter::new();
+
+    let msg = mock_ibc_channel_open_init("channel-0", IbcOrder::Unordered, IBC_VERSION);
+    let resp = entry_points::ibc_channel_open(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(
+        resp,
+        Some(Ibc3ChannelOpenResponse {
+            version: IBC_VERSION.to_owned()
+        })
+    );
+
+    let msg = mock_ibc_channel_open_init("channel-0", IbcOrder::Unordered, "invalid");
+    let err = entry_points::ibc_channel_open(deps.as_mut(), env.clone(), msg).unwrap_err();
+    assert_eq!(err, StdError::generic_err("Invalid channel version"));
+
+    let msg = mock_ibc_channel_connect_ack("channel-0", IbcOrder::Unordered, IBC_VERSION);
+    entry_points::ibc_channel_connect(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(contract.channel.load(&deps.storage).unwrap(), "channel-0");
+
+    contract.packets.save(&mut deps.storage, &0).unwrap();
+    let msg = mock_ibc_packet_recv("channel-0", &"packet").unwrap();
+    let resp = entry_points::ibc_packet_receive(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(resp.acknowledgement, Some(b"ack".into()));
+    assert_eq!(contract.packets.load(&deps.storage).unwrap(), 1);
+
+    let ack = IbcAcknowledgement::new(b"ack");
+    let msg = mock_ibc_packet_ack("channel-0", &"packet", ack).unwrap();
+    let resp = entry_points::ibc_packet_ack(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(resp.attributes[0].value, "packet_ack");
+
+    let msg = mock_ibc_packet_timeout("channel-0", &"packet").unwrap();
+    let resp = entry_points::ibc_packet_timeout(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(resp.attributes[0].value, "packet_timeout");
+
+    let msg = mock_ibc_channel_close_init("channel-0", IbcOrder::Unordered, IBC_VERSION);
+    entry_points::ibc_channel_close(deps.as_mut(), env, msg).unwrap();
+    assert!(contract.channel.may_load(&deps.storage).unwrap().is_none());
+}
+
+pub mod transfer {
+    use cosmwasm_schema::cw_serde;
+    use sylvia::ctx::{IbcChannelOpenCtx, IbcPacketReceiveCtx, IbcPacketTimeoutCtx};
+    use sylvia::cw_std::{
+        IbcBasicResponse, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketReceiveMsg,
+        IbcPacketTimeoutMsg, IbcReceiveResponse, StdError,
+    };
+    use sylvia::interface;
+
+    #[cw_serde]
+    pub struct TransferPacket {
+        pub denom: String,
+        pub amount: u128,
+    }
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Transfer {
+        type Error: From<StdError>;
+
+        #[sv::msg(ibc_channel_open)]
+        fn transfer_channel_open(
+            &self,
+            ctx: IbcChannelOpenCtx,
+            msg: IbcChannelOpenMsg,
+        ) -> Result<IbcChannelOpenResponse, Self::Error>;
+
+        #[sv::msg(ibc_packet_receive, packet = TransferPacket)]
+        fn transfer_receive(
+            &self,
+            ctx: IbcPacketReceiveCtx,
+            msg: IbcPacketReceiveMsg,
+        ) -> Result<IbcReceiveResponse, Self::Error>;
+
+        #[sv::msg(ibc_packet_timeout, packet = TransferPacket)]
+        fn transfer_timeout(
+            &self,
+            ctx: IbcPacketTimeoutCtx,
+            msg: IbcPacketTimeoutMsg,
+        ) -> Result<IbcBasicResponse, Self::Error>;
+    }
+}
+
+pub mod nft {
+    use cosmwasm_schema::cw_serde;
+    use sylvia::ctx::IbcPacketReceiveCtx;
+    use sylvia::cw_std::{IbcPacketReceiveMsg, IbcReceiveResponse, StdError};
+    use sylvia::interface;
+
+    #[cw_serde]
+    pub struct NftPacket {
+        pub token_id: String,
+    }
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Nft {
+        type Error: From<StdError>;
+
+        #[sv::msg(ibc_packet_receive, packet = NftPacket)]
+        fn nft_receive(
+            &self,
+            ctx: IbcPacketReceiveCtx,
+            msg: IbcPacketReceiveMsg,
+        ) -> Result<IbcReceiveResponse, Self::Error>;
+    }
+}
+
+pub mod plain {
+    use sylvia::ctx::SudoCtx;
+    use sylvia::cw_std::Response;
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Plain {
+        type Error;
+
+        #[sv::msg(sudo)]
+        fn ping(&self, ctx: SudoCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod ibc_apps {
+    use cosmwasm_std::from_json;
+    use sylvia::contract;
+    use sylvia::ctx::{
+        IbcChannelOpenCtx, IbcPacketReceiveCtx, IbcPacketTimeoutCtx, InstantiateCtx, SudoCtx,
+    };
+    use sylvia::cw_std::{
+        Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelOpenMsg, IbcChannelOpenResponse,
+        IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, Response, StdError,
+        StdResult,
+    };
+
+    use crate::nft::{Nft, NftPacket};
+    use crate::plain::Plain;
+    use crate::transfer::{Transfer, TransferPacket};
+
+    pub struct IbcApps;
+
+    #[contract]
+    #[sv::messages(crate::transfer: ibc)]
+    #[sv::messages(crate::nft: ibc)]
+    #[sv::messages(crate::plain)]
+    impl IbcApps {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(ibc_packet_receive)]
+        fn fallback_receive(
+            &self,
+            _ctx: IbcPacketReceiveCtx,
+            _msg: IbcPacketReceiveMsg,
+        ) -> StdResult<IbcReceiveResponse> {
+            Ok(IbcReceiveResponse::new(b"contract"))
+        }
+    }
+
+    impl Transfer for IbcApps {
+        type Error = StdError;
+
+        fn transfer_channel_open(
+            &self,
+            _ctx: IbcChannelOpenCtx,
+            _msg: IbcChannelOpenMsg,
+        ) -> StdResult<IbcChannelOpenResponse> {
+            Ok(Some(Ibc3ChannelOpenResponse {
+                version: "ics20-1".to_owned(),
+            }))
+        }
+
+        fn transfer_receive(
+            &self,
+            _ctx: IbcPacketReceiveCtx,
+            msg: IbcPacketReceiveMsg,
+        ) -> StdResult<IbcReceiveResponse> {
+            let packet: TransferPacket = from_json(&msg.packet.data)?;
+            Ok(IbcReceiveResponse::new(b"transfer").add_attribute("denom", packet.denom))
+        }
+
+        fn transfer_timeout(
+            &self,
+            _ctx: IbcPacketTimeoutCtx,
+            _msg: IbcPacketTimeoutMsg,
+        ) -> StdResult<IbcBasicResponse> {
+            Ok(IbcBasicResponse::new().add_attribute("action", "transfer_timeout"))
+        }
+    }
+
+    impl Plain for IbcApps {
+        type Error = StdError;
+
+        fn ping(&self, _ctx: SudoCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+
+    impl Nft for IbcApps {
+        type Error = StdError;
+
+        fn nft_receive(
+            &self,
+            _ctx: IbcPacketReceiveCtx,
+            msg: IbcPacketReceiveMsg,
+        ) -> StdResult<IbcReceiveResponse> {
+            let packet: NftPacket = from_json(&msg.packet.data)?;
+            Ok(IbcReceiveResponse::new(b"nft").add_attribute("token_id", packet.token_id))
+        }
+    }
+}
+
+#[test]
+fn ibc_interfaces_routing() {
+    use crate::ibc_apps::sv;
+    use crate::ibc_apps::IbcApps;
+    use crate::nft::NftPacket;
+    use crate::transfer::TransferPacket;
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let msg = mock_ibc_channel_open_init("channel-0", IbcOrder::Unordered, "ics20-1");
+    let resp =
+        sv::dispatch_ibc_channel_open(deps.as_mut(), env.clone(), msg, IbcApps::new()).unwrap();
+    assert_eq!(
+        resp,
+        Some(Ibc3ChannelOpenResponse {
+            version: "ics20-1".to_owned()
+        })
+    );
+
+    let packet = TransferPacket {
+        denom: "uatom".to_owned(),
+        amount: 100,
+    };
+    let msg = mock_ibc_packet_recv("channel-0", &packet).unwrap();
+    let resp =
+        sv::dispatch_ibc_packet_receive(deps.as_mut(), env.clone(), msg, IbcApps::new()).unwrap();
+    assert_eq!(resp.acknowledgement, Some(b"transfer".into()));
+    assert_eq!(resp.attributes[0].value, "uatom");
+
+    let packet = NftPacket {
+        token_id: "token".to_owned(),
+    };
+    let msg = mock_ibc_packet_recv("channel-0", &packet).unwrap();
+    let resp =
+        sv::dispatch_ibc_packet_receive(deps.as_mut(), env.clone(), msg, IbcApps::new()).unwrap();
+    assert_eq!(resp.acknowledgement, Some(b"nft".into()));
+    assert_eq!(resp.attributes[0].value, "token");
+
+    let msg = mock_ibc_packet_recv("channel-0", &"unknown").unwrap();
+    let resp =
+        sv::dispatch_ibc_packet_receive(deps.as_mut(), env.clone(), msg, IbcApps::new()).unwrap();
+    assert_eq!(resp.acknowledgement, Some(b"contract".into()));
+
+    let msg = mock_ibc_packet_timeout("channel-0", &"unknown").unwrap();
+    let err = sv::dispatch_ibc_packet_timeout(deps.as_mut(), env.clone(), msg, IbcApps::new())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Unsupported IBC message received.")
+    );
+
+    let msg = mock_ibc_channel_connect_ack("channel-0", IbcOrder::Unordered, "ics20-1");
+    let err =
+        sv::dispatch_ibc_channel_connect(deps.as_mut(), env, msg, IbcApps::new()).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Unsupported IBC message received.")
+    );
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_ibc_channel_open_init, mock_ibc_packet_recv,
+    };
+    use cosmwasm_std::IbcOrder;
+    use sylvia::multitest::IbcContract as _;
+
+    use crate::{PacketCounter, IBC_VERSION};
+
+    #[test]
+    fn ibc_contract() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let contract = PacketCounter::new();
+
+        let msg = mock_ibc_channel_open_init("channel-0", IbcOrder::Unordered, IBC_VERSION);
+        contract
+            .ibc_channel_open(deps.as_mut(), env.clone(), msg)
+            .unwrap();
+
+        contract.packets.save(&mut deps.storage, &0).unwrap();
+        let msg = mock_ibc_packet_recv("channel-0", &"packet").unwrap();
+        contract
+            .ibc_packet_receive(deps.as_mut(), env, msg)
+            .unwrap();
+        assert_eq!(contract.packets.load(&deps.storage).unwrap(), 1);
+    }
+}
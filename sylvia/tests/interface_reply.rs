@@ -0,0 +1,203 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{to_json_binary, SubMsgResponse, SubMsgResult, WasmMsg};
+use cw_storage_plus::Item;
+use sylvia::ctx::{InstantiateCtx, ReplyCtx};
+use sylvia::cw_std::{CosmosMsg, Reply, ReplyOn, Response, StdError, StdResult, SubMsg};
+use sylvia::{contract, entry_points};
+
+pub mod forwarder {
+    use sylvia::ctx::ReplyCtx;
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Forwarder {
+        type Error: From<StdError>;
+
+        #[sv::msg(reply, handlers=[forward], reply_on=success)]
+        fn forward_succeeded(&self, ctx: ReplyCtx, note: String) -> Result<Response, Self::Error>;
+
+        #[sv::msg(reply, handlers=[forward], reply_on=error)]
+        fn forward_failed(
+            &self,
+            ctx: ReplyCtx,
+            error: String,
+            note: String,
+        ) -> Result<Response, Self::Error>;
+    }
+}
+
+use forwarder::Forwarder;
+
+pub struct Contract {
+    pub(crate) last_reply: Item<String>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::messages(forwarder: reply)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            last_reply: Item::new("last_reply"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(reply, reply_on=success)]
+    fn local(&self, ctx: ReplyCtx, note: String) -> StdResult<Response> {
+        self.last_reply
+            .save(ctx.deps.storage, &format!("local: {note}"))?;
+        Ok(Response::new())
+    }
+}
+
+impl Forwarder for Contract {
+    type Error = StdError;
+
+    fn forward_succeeded(&self, ctx: ReplyCtx, note: String) -> StdResult<Response> {
+        self.last_reply
+            .save(ctx.deps.storage, &format!("forwarded: {note}"))?;
+        Ok(Response::new())
+    }
+
+    fn forward_failed(&self, ctx: ReplyCtx, error: String, note: String) -> StdResult<Response> {
+        self.last_reply
+            .save(ctx.deps.storage, &format!("failed: {note}, {error}"))?;
+        Ok(Response::new())
+    }
+}
+
+fn reply(id: u64, note: &str, result: SubMsgResult) -> Reply {
+    Reply {
+        id,
+        payload: to_json_binary(note).unwrap(),
+        gas_used: 0,
+        result,
+    }
+}
+
+#[allow(deprecated)]
+fn success() -> SubMsgResult {
+    SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: None,
+        msg_responses: vec![],
+    })
+}
+
+#[test]
+fn reply_ids_are_namespaced() {
+    assert_ne!(forwarder::sv::REPLY_NAMESPACE, 0);
+    assert_eq!(
+        forwarder::sv::REPLY_NAMESPACE,
+        sylvia::utils::reply_namespace(forwarder::sv::INTERFACE_ID)
+    );
+    assert_eq!(
+        forwarder::sv::FORWARD_REPLY_ID >> 32,
+        forwarder::sv::REPLY_NAMESPACE
+    );
+    assert_eq!(
+        forwarder::sv::REPLY_IDS,
+        &[("forward", forwarder::sv::FORWARD_REPLY_ID)]
+    );
+
+    assert!(forwarder::sv::handles_reply(
+        forwarder::sv::FORWARD_REPLY_ID
+    ));
+    assert!(!forwarder::sv::handles_reply(sv::LOCAL_REPLY_ID));
+}
+
+#[test]
+fn interface_sub_msg_methods() {
+    use forwarder::sv::SubMsgMethods;
+
+    let msg = WasmMsg::ClearAdmin {
+        contract_addr: "contract".to_owned(),
+    };
+    let sub_msg: SubMsg = msg.clone().forward("note".to_owned()).unwrap();
+    assert_eq!(sub_msg.id, forwarder::sv::FORWARD_REPLY_ID);
+    assert_eq!(sub_msg.reply_on, ReplyOn::Always);
+    assert_eq!(sub_msg.payload, to_json_binary("note").unwrap());
+
+    let sub_msg: SubMsg = SubMsg::new(CosmosMsg::Wasm(msg))
+        .forward("note".to_owned())
+        .unwrap();
+    assert_eq!(sub_msg.id, forwarder::sv::FORWARD_REPLY_ID);
+    assert_eq!(sub_msg.reply_on, ReplyOn::Always);
+}
+
+#[test]
+fn interface_reply_handlers() {
+    use sylvia::types::{ExecutorBuilder, ReadyExecutorBuilderState, ReplyHandler};
+
+    assert_eq!(
+        forwarder::sv::replies::Forward::REPLY_ID,
+        forwarder::sv::FORWARD_REPLY_ID
+    );
+    assert_eq!(forwarder::sv::replies::Forward::REPLY_ON, ReplyOn::Always);
+
+    let sub_msg = ExecutorBuilder::<ReadyExecutorBuilderState>::new(
+        "contract".to_owned(),
+        vec![],
+        to_json_binary("msg").unwrap(),
+    )
+    .with_reply::<forwarder::sv::replies::Forward>("note".to_owned())
+    .unwrap();
+    assert_eq!(sub_msg.id, forwarder::sv::FORWARD_REPLY_ID);
+    assert_eq!(sub_msg.reply_on, ReplyOn::Always);
+    assert_eq!(sub_msg.payload, to_json_binary("note").unwrap());
+    assert_eq!(
+        sub_msg.msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "contract".to_owned(),
+            msg: to_json_binary("msg").unwrap(),
+            funds: vec![],
+        })
+    );
+}
+
+#[test]
+fn replies_routing() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let contract = Contract::new();
+
+    let msg = reply(sv::LOCAL_REPLY_ID, "first", success());
+    entry_points::reply(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(
+        contract.last_reply.load(&deps.storage).unwrap(),
+        "local: first"
+    );
+
+    let msg = reply(forwarder::sv::FORWARD_REPLY_ID, "second", success());
+    entry_points::reply(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(
+        contract.last_reply.load(&deps.storage).unwrap(),
+        "forwarded: second"
+    );
+
+    let msg = reply(
+        forwarder::sv::FORWARD_REPLY_ID,
+        "third",
+        SubMsgResult::Err("remote error".to_owned()),
+    );
+    entry_points::reply(deps.as_mut(), env.clone(), msg).unwrap();
+    assert_eq!(
+        contract.last_reply.load(&deps.storage).unwrap(),
+        "failed: third, remote error"
+    );
+
+    let unknown_id = forwarder::sv::FORWARD_REPLY_ID + 1;
+    let msg = reply(unknown_id, "fourth", success());
+    let err = entry_points::reply(deps.as_mut(), env, msg).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(format!("Unknown reply id: {unknown_id}."))
+    );
+}
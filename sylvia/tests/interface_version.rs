@@ -0,0 +1,112 @@
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use sylvia::ctx::InstantiateCtx;
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::introspection::SupportedInterfacesResponse;
+use sylvia::{contract, entry_points};
+
+pub mod minting {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::interface;
+
+    #[interface(version = "1.2.0")]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Minting {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn mint(&self, ctx: ExecCtx, amount: u128) -> StdResult<Response>;
+    }
+}
+
+pub mod burning {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::interface;
+
+    #[interface(version = "0.3.1-beta.2")]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Burning {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn burn(&self, ctx: ExecCtx, amount: u128) -> StdResult<Response>;
+    }
+}
+
+pub struct Contract;
+
+#[entry_points]
+#[contract]
+#[sv::features(introspection)]
+#[sv::messages(minting: version = ">=1.2, <2.0")]
+#[sv::messages(burning: version = "~0.3.1-beta")]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+impl minting::Minting for Contract {
+    type Error = sylvia::cw_std::StdError;
+
+    fn mint(&self, _ctx: sylvia::ctx::ExecCtx, _amount: u128) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+impl burning::Burning for Contract {
+    type Error = sylvia::cw_std::StdError;
+
+    fn burn(&self, _ctx: sylvia::ctx::ExecCtx, _amount: u128) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+#[test]
+fn interface_version() {
+    assert_eq!(minting::sv::INTERFACE_ID, "interface_version::Minting");
+    assert_eq!(minting::sv::INTERFACE_VERSION, "1.2.0");
+    assert_eq!(burning::sv::INTERFACE_ID, "interface_version::Burning");
+    assert_eq!(burning::sv::INTERFACE_VERSION, "0.3.1-beta.2");
+}
+
+#[test]
+fn introspected_version() {
+    let deps = mock_dependencies();
+    let resp = entry_points::query(
+        deps.as_ref(),
+        mock_env(),
+        sv::ContractQueryMsg::Introspection(
+            sylvia::introspection::sv::QueryMsg::supported_interfaces(),
+        ),
+    )
+    .unwrap();
+
+    let versions: Vec<_> = from_json::<SupportedInterfacesResponse>(resp)
+        .unwrap()
+        .interfaces
+        .into_iter()
+        .map(|interface| (interface.id, interface.version))
+        .collect();
+    assert_eq!(
+        versions,
+        [
+            ("interface_version::Minting".to_owned(), "1.2.0".to_owned()),
+            (
+                "interface_version::Burning".to_owned(),
+                "0.3.1-beta.2".to_owned()
+            ),
+            (
+                "sylvia::Introspection".to_owned(),
+                env!("CARGO_PKG_VERSION").to_owned()
+            ),
+        ]
+    );
+}
@@ -0,0 +1,198 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{
+    from_json, Addr, ContractResult, Empty, QuerierResult, QuerierWrapper, SystemError,
+    SystemResult, WasmQuery,
+};
+use sylvia::ctx::{InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdError, StdResult};
+use sylvia::introspection::sv::QueryMsg as IntrospectionQueryMsg;
+use sylvia::introspection::{InterfaceInfo, SupportedInterfacesResponse};
+use sylvia::types::Remote;
+use sylvia::{contract, entry_points};
+
+pub mod counter {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_std::{Response, StdError, StdResult};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx) -> StdResult<Response>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64>;
+    }
+}
+
+pub mod legacy {
+    use sylvia::ctx::InstantiateCtx;
+    use sylvia::cw_std::{Response, StdResult};
+    use sylvia::{contract, entry_points};
+
+    pub struct LegacyContract;
+
+    #[entry_points]
+    #[contract]
+    impl LegacyContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+}
+
+pub struct Contract;
+
+#[entry_points]
+#[contract]
+#[sv::features(introspection)]
+#[sv::messages(counter)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn value(&self, _ctx: QueryCtx) -> StdResult<u32> {
+        Ok(1)
+    }
+}
+
+impl counter::Counter for Contract {
+    type Error = StdError;
+
+    fn increment(&self, _ctx: sylvia::ctx::ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn count(&self, _ctx: QueryCtx) -> StdResult<u64> {
+        Ok(0)
+    }
+}
+
+fn query(msg: IntrospectionQueryMsg) -> StdResult<sylvia::cw_std::Binary> {
+    let deps = mock_dependencies();
+    entry_points::query(
+        deps.as_ref(),
+        mock_env(),
+        sv::ContractQueryMsg::Introspection(msg),
+    )
+}
+
+/// Mocks the `contract` with introspection and the `legacy` contract without it.
+fn mock_contracts(query: &WasmQuery) -> QuerierResult {
+    let deps = mock_dependencies();
+    let result = match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "contract" => {
+            from_json(msg).and_then(|msg| entry_points::query(deps.as_ref(), mock_env(), msg))
+        }
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "legacy" => from_json(msg)
+            .and_then(|msg| legacy::entry_points::query(deps.as_ref(), mock_env(), msg)),
+        _ => {
+            return SystemResult::Err(SystemError::NoSuchContract {
+                addr: "unknown".to_owned(),
+            })
+        }
+    };
+    SystemResult::Ok(ContractResult::from(result.map_err(|err| err.to_string())))
+}
+
+#[test]
+fn supported_interfaces() {
+    let version = env!("CARGO_PKG_VERSION");
+    assert_eq!(counter::sv::INTERFACE_NAME, "Counter");
+    assert_eq!(counter::sv::INTERFACE_ID, "introspection::Counter");
+    assert_eq!(counter::sv::INTERFACE_VERSION, version);
+
+    let resp = query(IntrospectionQueryMsg::supported_interfaces()).unwrap();
+    assert_eq!(
+        from_json::<SupportedInterfacesResponse>(resp).unwrap(),
+        SupportedInterfacesResponse {
+            interfaces: vec![
+                InterfaceInfo::new(
+                    "Counter",
+                    "introspection::Counter",
+                    version,
+                    &["increment"],
+                    &["count"],
+                    &[],
+                ),
+                InterfaceInfo::new(
+                    "Introspection",
+                    "sylvia::Introspection",
+                    version,
+                    &[],
+                    &["supported_interfaces", "supports_interface"],
+                    &[],
+                ),
+            ]
+        }
+    );
+}
+
+#[test]
+fn supports_interface() {
+    let supports = |id: &str| {
+        let resp = query(IntrospectionQueryMsg::supports_interface(id.to_owned())).unwrap();
+        from_json::<bool>(resp).unwrap()
+    };
+
+    assert!(supports("Counter"));
+    assert!(supports("introspection::Counter"));
+    assert!(supports("Introspection"));
+    assert!(!supports("Ownable"));
+}
+
+#[test]
+fn remote_supports_interface() {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(mock_contracts);
+    let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+
+    let contract = Remote::<Contract>::new(Addr::unchecked("contract"));
+    assert!(contract.supports_interface(&querier, "Counter").unwrap());
+    assert!(!contract.supports_interface(&querier, "Ownable").unwrap());
+
+    // Contracts without the introspection don't support any interface.
+    let legacy = Remote::<legacy::LegacyContract>::new(Addr::unchecked("legacy"));
+    assert!(!legacy.supports_interface(&querier, "Counter").unwrap());
+
+    let unknown = Remote::<Contract>::new(Addr::unchecked("unknown"));
+    unknown.supports_interface(&querier, "Counter").unwrap_err();
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use sylvia::cw_multi_test::IntoAddr;
+    use sylvia::introspection::sv::mt::IntrospectionProxy;
+    use sylvia::multitest::App;
+
+    use super::sv::mt::CodeId;
+
+    #[test]
+    fn introspection_proxy() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_addr();
+
+        let contract = code_id.instantiate().call(&owner).unwrap();
+
+        let resp = contract.supported_interfaces().unwrap();
+        assert_eq!(resp.interfaces.len(), 2);
+        assert!(contract.supports_interface("Counter".to_owned()).unwrap());
+        assert!(!contract.supports_interface("Ownable".to_owned()).unwrap());
+    }
+}
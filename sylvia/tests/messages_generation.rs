@@ -4,7 +4,7 @@ use std::str::FromStr;
 use contract::sv::{ExecMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use sylvia::cw_std::{from_json, Addr, CustomQuery, Decimal};
+use sylvia::cw_std::{from_json, CustomQuery, Decimal};
 
 #[derive(
     serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, schemars::JsonSchema,
@@ -180,17 +180,17 @@ mod contract {
 fn interface_messages_constructible() {
     let no_args_exec = interface::sv::ExecMsg::NoArgsExecution {};
     let _ = interface::sv::ExecMsg::ArgumentedExecution {
-        addr: Addr::unchecked("owner"),
+        addr: "owner".to_owned(),
         coef: Decimal::percent(10),
         desc: "Some description".to_owned(),
     };
     let no_args_query = interface::sv::QueryMsg::NoArgsQuery {};
     let _ = interface::sv::QueryMsg::ArgumentedQuery {
-        user: Addr::unchecked("owner"),
+        user: "owner".to_owned(),
     };
     let no_args_sudo = interface::sv::SudoMsg::NoArgsSudo {};
     let _ = interface::sv::SudoMsg::ArgumentedSudo {
-        user: Addr::unchecked("owner"),
+        user: "owner".to_owned(),
     };
 
     // Ensure no extra variants are generated
@@ -214,18 +214,18 @@ fn interface_messages_constructible() {
 fn contract_messages_constructible() {
     let no_args_exec = contract::sv::ExecMsg::NoArgsExecution {};
     let _argumented_exec = contract::sv::ExecMsg::ArgumentedExecution {
-        _addr: Addr::unchecked("owner"),
+        _addr: "owner".to_owned(),
         _coef: Decimal::percent(10),
         _desc: "Some description".to_owned(),
     };
     let no_args_query = contract::sv::QueryMsg::NoArgsQuery {};
     let _argumented_query = contract::sv::QueryMsg::ArgumentedQuery {
-        _user: Addr::unchecked("owner"),
+        _user: "owner".to_owned(),
         _desc: "".to_string(),
     };
     let no_args_sudo = contract::sv::SudoMsg::NoArgsSudo {};
     let _ = contract::sv::SudoMsg::ArgumentedSudo {
-        _user: Addr::unchecked("owner"),
+        _user: "owner".to_owned(),
         _desc: "".to_string(),
     };
     let _ = contract::sv::InstantiateMsg {
@@ -310,7 +310,7 @@ fn attributes_forwarding_for_message_variants() {
     );
     assert_eq!(
         contract::sv::ExecMsg::ArgumentedExecution {
-            _addr: Addr::unchecked("input"),
+            _addr: "input".to_owned(),
             _coef: Decimal::from_str("0").unwrap(),
             _desc: "".to_owned(),
         }
@@ -323,7 +323,7 @@ fn attributes_forwarding_for_message_variants() {
     );
     assert_eq!(
         contract::sv::QueryMsg::ArgumentedQuery {
-            _user: Addr::unchecked("input"),
+            _user: "input".to_owned(),
             _desc: "".to_string(),
         }
         .to_string(),
@@ -335,7 +335,7 @@ fn attributes_forwarding_for_message_variants() {
     );
     assert_eq!(
         contract::sv::SudoMsg::ArgumentedSudo {
-            _user: Addr::unchecked("input"),
+            _user: "input".to_owned(),
             _desc: "".to_string(),
         }
         .to_string(),
@@ -362,7 +362,7 @@ fn attributes_forwarding_for_message_variants() {
     );
     assert_eq!(
         interface::sv::ExecMsg::ArgumentedExecution {
-            addr: Addr::unchecked("input"),
+            addr: "input".to_owned(),
             coef: Decimal::from_str("0").unwrap(),
             desc: "".to_owned(),
         }
@@ -375,7 +375,7 @@ fn attributes_forwarding_for_message_variants() {
     );
     assert_eq!(
         interface::sv::QueryMsg::ArgumentedQuery {
-            user: Addr::unchecked("input"),
+            user: "input".to_owned(),
         }
         .to_string(),
         "ArgumentedQuery"
@@ -386,7 +386,7 @@ fn attributes_forwarding_for_message_variants() {
     );
     assert_eq!(
         interface::sv::SudoMsg::ArgumentedSudo {
-            user: Addr::unchecked("input"),
+            user: "input".to_owned(),
         }
         .to_string(),
         "ArgumentedSudo"
@@ -411,7 +411,7 @@ fn forward_attribute_to_message_field() {
         }"#;
 
     assert_eq!(
-        QueryMsg::argumented_query(Addr::unchecked("addr"), Default::default()),
+        QueryMsg::argumented_query("addr".to_owned(), Default::default()),
         from_json(json).unwrap()
     );
 
@@ -420,7 +420,7 @@ fn forward_attribute_to_message_field() {
         }"#;
 
     assert_eq!(
-        SudoMsg::argumented_sudo(Addr::unchecked("addr"), Default::default()),
+        SudoMsg::argumented_sudo("addr".to_owned(), Default::default()),
         from_json(json).unwrap()
     );
 
@@ -429,11 +429,7 @@ fn forward_attribute_to_message_field() {
         }"#;
 
     assert_eq!(
-        ExecMsg::argumented_execution(
-            Addr::unchecked("addr"),
-            Default::default(),
-            Default::default()
-        ),
+        ExecMsg::argumented_execution("addr".to_owned(), Default::default(), Default::default()),
         from_json(json).unwrap()
     );
 
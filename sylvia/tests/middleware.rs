@@ -0,0 +1,244 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{Addr, CustomQuery, DepsMut, Response, StdError, StdResult};
+use cw_storage_plus::Item;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, MigrateCtx, SudoCtx};
+use sylvia::middleware::Middleware;
+use sylvia::{contract, entry_points};
+
+pub mod counter {
+    use sylvia::ctx::{ExecCtx, SudoCtx};
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx) -> Result<Response, Self::Error>;
+
+        #[sv::msg(sudo)]
+        fn reset(&self, ctx: SudoCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+/// Rejects the messages while the `locked` flag is set.
+pub struct Lock;
+
+impl<Q: CustomQuery> Middleware<Contract, Q> for Lock {
+    type Error = StdError;
+
+    fn before_exec(contract: &Contract, ctx: &mut ExecCtx<Q>, msg_name: &str) -> StdResult<()> {
+        match contract.locked.load(ctx.deps.storage)? {
+            true => Err(StdError::generic_err(format!("{msg_name} is locked"))),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Records the names of the dispatched messages and the order of the hooks.
+pub struct Audit;
+
+impl<Q: CustomQuery> Middleware<Contract, Q> for Audit {
+    type Error = StdError;
+
+    fn before_exec(contract: &Contract, ctx: &mut ExecCtx<Q>, msg_name: &str) -> StdResult<()> {
+        contract
+            .last_msg
+            .save(ctx.deps.storage, &msg_name.to_owned())
+    }
+
+    fn after_exec(
+        _contract: &Contract,
+        _ctx: &mut ExecCtx<Q>,
+        resp: &mut Response,
+    ) -> StdResult<()> {
+        resp.attributes.push(("hook", "audit").into());
+        Ok(())
+    }
+
+    fn before_sudo(contract: &Contract, ctx: &mut SudoCtx<Q>, msg_name: &str) -> StdResult<()> {
+        contract
+            .last_msg
+            .save(ctx.deps.storage, &msg_name.to_owned())
+    }
+
+    fn after_sudo(
+        _contract: &Contract,
+        _ctx: &mut SudoCtx<Q>,
+        resp: &mut Response,
+    ) -> StdResult<()> {
+        resp.attributes.push(("hook", "audit").into());
+        Ok(())
+    }
+
+    fn before_migrate(
+        contract: &Contract,
+        ctx: &mut MigrateCtx<Q>,
+        msg_name: &str,
+    ) -> StdResult<()> {
+        contract
+            .last_msg
+            .save(ctx.deps.storage, &msg_name.to_owned())
+    }
+
+    fn after_migrate(
+        _contract: &Contract,
+        _ctx: &mut MigrateCtx<Q>,
+        resp: &mut Response,
+    ) -> StdResult<()> {
+        resp.attributes.push(("hook", "audit").into());
+        Ok(())
+    }
+}
+
+/// Reads the counter in the `after_exec` hook, so it sees the changes made by the handler.
+pub struct Metrics;
+
+impl<Q: CustomQuery> Middleware<Contract, Q> for Metrics {
+    type Error = StdError;
+
+    fn after_exec(contract: &Contract, ctx: &mut ExecCtx<Q>, resp: &mut Response) -> StdResult<()> {
+        let count = contract.count.load(ctx.deps.storage)?;
+        resp.attributes.push(("count", count.to_string()).into());
+        Ok(())
+    }
+}
+
+pub struct Contract {
+    locked: Item<bool>,
+    last_msg: Item<String>,
+    count: Item<u64>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::messages(counter)]
+#[sv::middleware(Lock)]
+#[sv::middleware(Audit)]
+#[sv::middleware(Metrics)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            locked: Item::new("locked"),
+            last_msg: Item::new("last_msg"),
+            count: Item::new("count"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        self.locked.save(ctx.deps.storage, &false)?;
+        self.count.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn set_locked(&self, ctx: ExecCtx, locked: bool) -> StdResult<Response> {
+        self.locked.save(ctx.deps.storage, &locked)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(sudo)]
+    fn unlock(&self, ctx: SudoCtx) -> StdResult<Response> {
+        self.locked.save(ctx.deps.storage, &false)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(migrate)]
+    fn migrate(&self, _ctx: MigrateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+impl counter::Counter for Contract {
+    type Error = StdError;
+
+    fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+        self.count
+            .update(ctx.deps.storage, |count| StdResult::Ok(count + 1))?;
+        Ok(Response::new())
+    }
+
+    fn reset(&self, ctx: SudoCtx) -> StdResult<Response> {
+        self.count.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+}
+
+fn execute(deps: DepsMut, msg: impl Into<sv::ContractExecMsg>) -> StdResult<Response> {
+    let sender = Addr::unchecked("sender");
+    entry_points::execute(deps, mock_env(), message_info(&sender, &[]), msg.into())
+}
+
+fn attributes(resp: &Response) -> Vec<(&str, &str)> {
+    resp.attributes
+        .iter()
+        .map(|attr| (attr.key.as_str(), attr.value.as_str()))
+        .collect()
+}
+
+#[test]
+fn msg_names() {
+    assert_eq!(sv::ExecMsg::set_locked(true).msg_name(), "set_locked");
+    assert_eq!(counter::sv::ExecMsg::increment().msg_name(), "increment");
+    assert_eq!(
+        sv::ContractExecMsg::from(counter::sv::ExecMsg::increment()).msg_name(),
+        "increment"
+    );
+    assert_eq!(
+        sv::ContractSudoMsg::from(sv::SudoMsg::unlock()).msg_name(),
+        "unlock"
+    );
+    assert_eq!(sv::MigrateMsg::new().msg_name(), "migrate");
+}
+
+#[test]
+fn hooks_wrap_dispatch() {
+    let mut deps = mock_dependencies();
+    let contract = Contract::new();
+    let sender = Addr::unchecked("sender");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&sender, &[]),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    let resp = execute(deps.as_mut(), counter::sv::ExecMsg::increment()).unwrap();
+    assert_eq!(attributes(&resp), [("count", "1"), ("hook", "audit")]);
+    assert_eq!(contract.last_msg.load(&deps.storage).unwrap(), "increment");
+
+    execute(deps.as_mut(), sv::ExecMsg::set_locked(true)).unwrap();
+    let err = execute(deps.as_mut(), counter::sv::ExecMsg::increment()).unwrap_err();
+    assert_eq!(err, StdError::generic_err("increment is locked"));
+    assert_eq!(contract.last_msg.load(&deps.storage).unwrap(), "set_locked");
+    assert_eq!(contract.count.load(&deps.storage).unwrap(), 1);
+
+    let resp = entry_points::sudo(
+        deps.as_mut(),
+        mock_env(),
+        sv::ContractSudoMsg::from(counter::sv::SudoMsg::reset()),
+    )
+    .unwrap();
+    assert_eq!(attributes(&resp), [("hook", "audit")]);
+    assert_eq!(contract.last_msg.load(&deps.storage).unwrap(), "reset");
+    assert_eq!(contract.count.load(&deps.storage).unwrap(), 0);
+
+    entry_points::sudo(
+        deps.as_mut(),
+        mock_env(),
+        sv::ContractSudoMsg::from(sv::SudoMsg::unlock()),
+    )
+    .unwrap();
+    assert_eq!(contract.last_msg.load(&deps.storage).unwrap(), "unlock");
+
+    let resp = entry_points::migrate(deps.as_mut(), mock_env(), sv::MigrateMsg::new()).unwrap();
+    assert_eq!(attributes(&resp), [("hook", "audit")]);
+    assert_eq!(contract.last_msg.load(&deps.storage).unwrap(), "migrate");
+
+    execute(deps.as_mut(), counter::sv::ExecMsg::increment()).unwrap();
+}
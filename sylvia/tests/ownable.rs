@@ -0,0 +1,265 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{from_json, Addr, StdError};
+use cw_storage_plus::Item;
+use sylvia::access::Unauthorized;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::ownable::{initialize_owner, Ownable, Ownership, OwnershipError};
+use sylvia::{contract, entry_points};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
+    #[error("{0}")]
+    Unauthorized(#[from] Unauthorized),
+}
+
+pub struct Contract {
+    pub(crate) value: Item<u32>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::error(ContractError)]
+#[sv::messages(sylvia::ownable)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            value: Item::new("value"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        let sender = ctx.info.sender.to_string();
+        initialize_owner(ctx.deps.storage, ctx.deps.api, Some(&sender))?;
+        self.value.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::access(owner)]
+    fn set_value(&self, ctx: ExecCtx, value: u32) -> Result<Response, ContractError> {
+        self.value.save(ctx.deps.storage, &value)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn value(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.value.load(ctx.deps.storage)
+    }
+}
+
+impl Ownable for Contract {
+    type Error = ContractError;
+}
+
+#[test]
+fn ownership_transfer() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = deps.api.addr_make("owner");
+    let new_owner = deps.api.addr_make("new_owner");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    let mut execute = |sender: &Addr, msg: sylvia::ownable::sv::ExecMsg| {
+        entry_points::execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(sender, &[]),
+            sv::ContractExecMsg::Ownable(msg),
+        )
+    };
+
+    let err = execute(
+        &new_owner,
+        sylvia::ownable::sv::ExecMsg::transfer_ownership(new_owner.to_string(), None),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Ownership(OwnershipError::NotOwner));
+
+    let err = execute(&new_owner, sylvia::ownable::sv::ExecMsg::accept_ownership()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Ownership(OwnershipError::TransferNotFound)
+    );
+
+    execute(
+        &owner,
+        sylvia::ownable::sv::ExecMsg::transfer_ownership(new_owner.to_string(), None),
+    )
+    .unwrap();
+
+    let err = execute(&owner, sylvia::ownable::sv::ExecMsg::accept_ownership()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Ownership(OwnershipError::NotPendingOwner)
+    );
+
+    let resp = execute(&new_owner, sylvia::ownable::sv::ExecMsg::accept_ownership()).unwrap();
+    assert_eq!(resp.attributes[0].value, "accept_ownership");
+    assert_eq!(resp.attributes[1].value, new_owner.as_str());
+
+    assert_eq!(
+        sylvia::ownable::get_ownership(&deps.storage).unwrap(),
+        Ownership {
+            owner: Some(new_owner),
+            pending_owner: None,
+            pending_expiry: None,
+        }
+    );
+}
+
+#[test]
+fn owner_access() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = deps.api.addr_make("owner");
+    let stranger = deps.api.addr_make("stranger");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&owner, &[]),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    let mut execute = |sender: &Addr, msg: sv::ContractExecMsg| {
+        entry_points::execute(deps.as_mut(), env.clone(), message_info(sender, &[]), msg)
+    };
+
+    let err = execute(
+        &stranger,
+        sv::ContractExecMsg::Contract(sv::ExecMsg::set_value(1)),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized(Unauthorized::new("set_value", stranger.clone()))
+    );
+    execute(
+        &owner,
+        sv::ContractExecMsg::Contract(sv::ExecMsg::set_value(1)),
+    )
+    .unwrap();
+
+    // After renouncing nobody can call the owner only methods.
+    execute(
+        &owner,
+        sv::ContractExecMsg::Ownable(sylvia::ownable::sv::ExecMsg::renounce_ownership()),
+    )
+    .unwrap();
+    let err = execute(
+        &owner,
+        sv::ContractExecMsg::Contract(sv::ExecMsg::set_value(2)),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Unauthorized(Unauthorized::new("set_value", owner.clone()))
+    );
+    let err = execute(
+        &owner,
+        sv::ContractExecMsg::Ownable(sylvia::ownable::sv::ExecMsg::renounce_ownership()),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Ownership(OwnershipError::NoOwner));
+}
+
+#[test]
+fn ownership_query() {
+    let mut deps = mock_dependencies();
+    let owner = deps.api.addr_make("owner");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&owner, &[]),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    let resp = entry_points::query(
+        deps.as_ref(),
+        mock_env(),
+        sv::ContractQueryMsg::Ownable(sylvia::ownable::sv::QueryMsg::ownership()),
+    )
+    .unwrap();
+    assert_eq!(
+        from_json::<Ownership>(resp).unwrap(),
+        Ownership {
+            owner: Some(owner),
+            pending_owner: None,
+            pending_expiry: None,
+        }
+    );
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use sylvia::cw_multi_test::IntoAddr;
+    use sylvia::cw_utils::Expiration;
+    use sylvia::multitest::App;
+    use sylvia::ownable::sv::mt::OwnableProxy;
+    use sylvia::ownable::{Ownership, OwnershipError};
+
+    use super::sv::mt::{CodeId, ContractProxy};
+    use super::ContractError;
+
+    #[test]
+    fn expired_transfer() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_addr();
+        let new_owner = "new_owner".into_addr();
+
+        let contract = code_id.instantiate().call(&owner).unwrap();
+
+        let expiry = Expiration::AtHeight(app.block_info().height + 1);
+        contract
+            .transfer_ownership(new_owner.to_string(), Some(expiry))
+            .call(&owner)
+            .unwrap();
+        assert_eq!(
+            contract.ownership().unwrap(),
+            Ownership {
+                owner: Some(owner.clone()),
+                pending_owner: Some(new_owner.clone()),
+                pending_expiry: Some(expiry),
+            }
+        );
+
+        app.update_block(|block| block.height += 1);
+        let err = contract.accept_ownership().call(&new_owner).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Ownership(OwnershipError::TransferExpired)
+        );
+
+        let err = contract
+            .transfer_ownership(new_owner.to_string(), Some(expiry))
+            .call(&owner)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Ownership(OwnershipError::TransferExpired)
+        );
+
+        contract.set_value(5).call(&owner).unwrap();
+        assert_eq!(contract.value().unwrap(), 5);
+    }
+}
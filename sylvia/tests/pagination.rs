@@ -0,0 +1,200 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{from_json, Addr, Binary, Deps};
+use cw_storage_plus::{Bound, Map};
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Order, Response, StdResult};
+use sylvia::pagination::Page;
+use sylvia::{contract, entry_points};
+
+pub mod tags {
+    use sylvia::ctx::QueryCtx;
+    use sylvia::cw_std::{StdError, StdResult};
+    use sylvia::interface;
+    use sylvia::pagination::Page;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Tags {
+        type Error: From<StdError>;
+
+        #[sv::msg(query, paginated)]
+        fn tags(&self, ctx: QueryCtx) -> StdResult<Page<String>>;
+    }
+}
+
+pub struct Contract {
+    members: Map<String, u64>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::messages(tags)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            members: Map::new("members"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn add_member(&self, ctx: ExecCtx, member: String, weight: u64) -> StdResult<Response> {
+        self.members.save(ctx.deps.storage, member, &weight)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query, paginated)]
+    fn members(&self, ctx: QueryCtx, min_weight: u64) -> StdResult<Page<(String, u64)>> {
+        let start = ctx
+            .pagination
+            .start_after()
+            .map(|key| Bound::exclusive(key.to_owned()));
+        let members = self
+            .members
+            .range(ctx.deps.storage, start, None, Order::Ascending)
+            .filter(|member| !matches!(member, Ok((_, weight)) if *weight < min_weight));
+        ctx.pagination.page(members, |(member, _)| member.clone())
+    }
+
+    #[sv::msg(query)]
+    fn member_count(&self, ctx: QueryCtx) -> StdResult<u64> {
+        Ok(self
+            .members
+            .keys(ctx.deps.storage, None, None, Order::Ascending)
+            .count() as u64)
+    }
+}
+
+impl tags::Tags for Contract {
+    type Error = sylvia::cw_std::StdError;
+
+    fn tags(&self, ctx: QueryCtx) -> StdResult<Page<String>> {
+        let start = ctx
+            .pagination
+            .start_after()
+            .map(|key| Bound::exclusive(key.to_owned()));
+        let tags = self
+            .members
+            .keys(ctx.deps.storage, start, None, Order::Ascending)
+            .map(|member| member.map(|member| format!("#{member}")));
+        ctx.pagination
+            .page(tags, |tag| tag.trim_start_matches('#').to_owned())
+    }
+}
+
+fn query(deps: Deps, msg: sv::ContractQueryMsg) -> Binary {
+    entry_points::query(deps, mock_env(), msg).unwrap()
+}
+
+#[test]
+fn paginated_query() {
+    let mut deps = mock_dependencies();
+    let owner = Addr::unchecked("owner");
+
+    for (member, weight) in [("alice", 3), ("bob", 1), ("carol", 5), ("dave", 2)] {
+        entry_points::execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            sv::ContractExecMsg::Contract(sv::ExecMsg::add_member(member.to_owned(), weight)),
+        )
+        .unwrap();
+    }
+
+    let resp = query(
+        deps.as_ref(),
+        sv::ContractQueryMsg::Contract(sv::QueryMsg::members(2, None, Some(2))),
+    );
+    assert_eq!(
+        from_json::<Page<(String, u64)>>(resp).unwrap(),
+        Page {
+            items: vec![("alice".to_owned(), 3), ("carol".to_owned(), 5)],
+            next_key: Some("carol".to_owned()),
+        }
+    );
+
+    let resp = query(
+        deps.as_ref(),
+        sv::ContractQueryMsg::Contract(sv::QueryMsg::members(2, Some("carol".to_owned()), Some(2))),
+    );
+    assert_eq!(
+        from_json::<Page<(String, u64)>>(resp).unwrap(),
+        Page {
+            items: vec![("dave".to_owned(), 2)],
+            next_key: None,
+        }
+    );
+
+    // Pagination fields are optional in the message.
+    let msg = from_json(r#"{"tags":{}}"#).unwrap();
+    let resp = query(deps.as_ref(), sv::ContractQueryMsg::Tags(msg));
+    assert_eq!(
+        from_json::<Page<String>>(resp).unwrap().items,
+        ["#alice", "#bob", "#carol", "#dave"]
+    );
+
+    // Non paginated queries use the default pagination.
+    let resp = query(
+        deps.as_ref(),
+        sv::ContractQueryMsg::Contract(sv::QueryMsg::member_count()),
+    );
+    assert_eq!(from_json::<u64>(resp).unwrap(), 4);
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use sylvia::cw_multi_test::IntoAddr;
+    use sylvia::multitest::App;
+    use sylvia::types::BoundQuerier;
+
+    use super::sv::mt::{CodeId, ContractProxy};
+    use super::sv::Querier;
+    use super::tags::sv::mt::TagsProxy;
+    use super::tags::sv::Querier as TagsQuerier;
+    use super::Contract;
+
+    #[test]
+    fn page_iter() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_addr();
+
+        let contract = code_id.instantiate().call(&owner).unwrap();
+        for member in 0..25 {
+            contract
+                .add_member(format!("member{member:02}"), member)
+                .call(&owner)
+                .unwrap();
+        }
+
+        let page = contract.members(5, None, None).unwrap();
+        assert_eq!(page.items.len(), 10);
+        assert_eq!(page.next_key.as_deref(), Some("member14"));
+
+        let members: Vec<_> = contract
+            .members_iter(5)
+            .map(|member| member.unwrap().1)
+            .collect();
+        assert_eq!(members, (5..25).collect::<Vec<_>>());
+        assert_eq!(contract.tags_iter().count(), 25);
+
+        let querier = app.querier();
+        let bound_querier =
+            BoundQuerier::<_, Contract>::borrowed(&contract.contract_addr, &querier);
+        let members = bound_querier
+            .members_iter(20)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(members.len(), 5);
+        let tags = bound_querier
+            .tags_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(tags.first().map(String::as_str), Some("#member00"));
+        assert_eq!(tags.len(), 25);
+    }
+}
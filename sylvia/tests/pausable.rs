@@ -0,0 +1,178 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{from_json, Addr, StdError};
+use cw_storage_plus::Item;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::pausable::{initialize_pauser, Pausable, PauseError, PauseState};
+use sylvia::{contract, entry_points};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Pause(#[from] PauseError),
+}
+
+pub struct Contract {
+    pub(crate) balance: Item<u32>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::error(ContractError)]
+#[sv::messages(sylvia::pausable)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            balance: Item::new("balance"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, ctx: InstantiateCtx) -> StdResult<Response> {
+        let sender = ctx.info.sender.to_string();
+        initialize_pauser(ctx.deps.storage, ctx.deps.api, Some(&sender))?;
+        self.balance.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::pausable]
+    fn deposit(&self, ctx: ExecCtx, amount: u32) -> Result<Response, ContractError> {
+        self.balance
+            .update(ctx.deps.storage, |balance| StdResult::Ok(balance + amount))?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn withdraw(&self, ctx: ExecCtx) -> Result<Response, ContractError> {
+        self.balance.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn balance(&self, ctx: QueryCtx) -> StdResult<u32> {
+        self.balance.load(ctx.deps.storage)
+    }
+}
+
+impl Pausable for Contract {
+    type Error = ContractError;
+}
+
+#[test]
+fn pausable_messages_are_rejected_while_paused() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let pauser = deps.api.addr_make("pauser");
+    let stranger = deps.api.addr_make("stranger");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        message_info(&pauser, &[]),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    let mut execute = |sender: &Addr, msg: sv::ContractExecMsg| {
+        entry_points::execute(deps.as_mut(), env.clone(), message_info(sender, &[]), msg)
+    };
+    let pause = || sv::ContractExecMsg::Pausable(sylvia::pausable::sv::ExecMsg::pause());
+    let unpause = || sv::ContractExecMsg::Pausable(sylvia::pausable::sv::ExecMsg::unpause());
+    let deposit = || sv::ContractExecMsg::Contract(sv::ExecMsg::deposit(1));
+    let withdraw = || sv::ContractExecMsg::Contract(sv::ExecMsg::withdraw());
+
+    let err = execute(&stranger, pause()).unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::NotPauser));
+    let err = execute(&pauser, unpause()).unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::NotPaused));
+
+    execute(&stranger, deposit()).unwrap();
+    let resp = execute(&pauser, pause()).unwrap();
+    assert_eq!(resp.attributes[0].value, "pause");
+    let err = execute(&pauser, pause()).unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Paused));
+
+    let err = execute(&stranger, deposit()).unwrap_err();
+    assert_eq!(err, ContractError::Pause(PauseError::Paused));
+    execute(&stranger, withdraw()).unwrap();
+
+    execute(&pauser, unpause()).unwrap();
+    execute(&stranger, deposit()).unwrap();
+    assert!(!sylvia::pausable::is_paused(&deps.storage).unwrap());
+}
+
+#[test]
+fn sudo_pauses_contract() {
+    let mut deps = mock_dependencies();
+    let pauser = deps.api.addr_make("pauser");
+
+    entry_points::instantiate(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&pauser, &[]),
+        sv::InstantiateMsg::new(),
+    )
+    .unwrap();
+
+    entry_points::sudo(
+        deps.as_mut(),
+        mock_env(),
+        sv::ContractSudoMsg::Pausable(sylvia::pausable::sv::SudoMsg::set_paused(true)),
+    )
+    .unwrap();
+
+    let resp = entry_points::query(
+        deps.as_ref(),
+        mock_env(),
+        sv::ContractQueryMsg::Pausable(sylvia::pausable::sv::QueryMsg::pause_state()),
+    )
+    .unwrap();
+    assert_eq!(
+        from_json::<PauseState>(resp).unwrap(),
+        PauseState {
+            paused: true,
+            pauser: Some(pauser),
+        }
+    );
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use sylvia::cw_multi_test::IntoAddr;
+    use sylvia::multitest::App;
+    use sylvia::pausable::sv::mt::PausableProxy;
+    use sylvia::pausable::PauseError;
+
+    use super::sv::mt::{CodeId, ContractProxy};
+    use super::ContractError;
+
+    #[test]
+    fn proxy_pauses_contract() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let pauser = "pauser".into_addr();
+        let user = "user".into_addr();
+
+        let contract = code_id.instantiate().call(&pauser).unwrap();
+
+        contract.pause().call(&pauser).unwrap();
+        assert!(contract.pause_state().unwrap().paused);
+        let err = contract.deposit(5).call(&user).unwrap_err();
+        assert_eq!(err, ContractError::Pause(PauseError::Paused));
+        contract.withdraw().call(&user).unwrap();
+
+        contract.unpause().call(&pauser).unwrap();
+        contract.deposit(5).call(&user).unwrap();
+
+        contract.set_paused(true).unwrap();
+        contract.deposit(5).call(&user).unwrap_err();
+        contract.set_paused(false).unwrap();
+        contract.deposit(5).call(&user).unwrap();
+        assert_eq!(contract.balance().unwrap(), 10);
+    }
+}
@@ -154,13 +154,13 @@ mod tests {
         let owner = "owner".into_bech32();
 
         let first_contract = code_id
-            .instantiate(Addr::unchecked("remote"))
+            .instantiate(&"remote".into_bech32())
             .with_label("First Counter")
             .call(&owner)
             .unwrap();
 
         let second_contract = code_id
-            .instantiate(first_contract.contract_addr.clone())
+            .instantiate(&first_contract.contract_addr)
             .with_label("Second Counter")
             .call(&owner)
             .unwrap();
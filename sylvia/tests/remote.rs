@@ -363,7 +363,7 @@ mod tests {
         let manager_code_id = ManagerCodeId::store_code(app);
 
         let signed_manager_contract = manager_code_id
-            .instantiate(signed_counter_contract.contract_addr.clone())
+            .instantiate(&signed_counter_contract.contract_addr)
             .with_label("Manager contract")
             .call(owner)
             .unwrap();
@@ -381,7 +381,7 @@ mod tests {
         let manager_code_id = ManagerCodeId::store_code(app);
 
         let unsigned_manager_contract = manager_code_id
-            .instantiate(unsigned_counter_contract.contract_addr.clone())
+            .instantiate(&unsigned_counter_contract.contract_addr)
             .with_label("Manager contract")
             .call(owner)
             .unwrap();
@@ -0,0 +1,150 @@
+use cosmwasm_std::testing::mock_dependencies;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, ContractResult, Empty, QuerierResult, QuerierWrapper,
+    StdError, SystemError, SystemResult, WasmQuery,
+};
+use sylvia::ctx::{InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::types::{BoundQuerier, RemoteQueryError, UNSUPPORTED_MESSAGE};
+use sylvia::{contract, entry_points};
+
+pub mod counter {
+    use sylvia::ctx::QueryCtx;
+    use sylvia::cw_std::{StdError, StdResult};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> StdResult<u64>;
+    }
+}
+
+pub struct Contract;
+
+#[entry_points]
+#[contract]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn value(&self, _ctx: QueryCtx) -> StdResult<u32> {
+        Ok(1)
+    }
+}
+
+/// Mocks the contract answering its queries with the `response`.
+/// Messages not supported by the contract fail the same way as in the `query` entry point.
+fn mock_contract(
+    response: Result<&'static str, &'static str>,
+) -> impl Fn(&WasmQuery) -> QuerierResult {
+    move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "contract" => {
+            let result = match (from_json::<sv::ContractQueryMsg>(msg), response) {
+                (Err(err), _) => ContractResult::Err(err.to_string()),
+                (Ok(_), Ok(response)) => ContractResult::Ok(to_json_binary(response).unwrap()),
+                (Ok(_), Err(err)) => ContractResult::Err(err.to_owned()),
+            };
+            SystemResult::Ok(result)
+        }
+        _ => SystemResult::Err(SystemError::NoSuchContract {
+            addr: "unknown".to_owned(),
+        }),
+    }
+}
+
+#[test]
+fn unsupported_interface() {
+    use counter::sv::Querier;
+
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(mock_contract(Ok("1")));
+    let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+    let contract = Addr::unchecked("contract");
+
+    let err =
+        BoundQuerier::<_, dyn counter::Counter<Error = StdError>>::borrowed(&contract, &querier)
+            .count()
+            .unwrap_err();
+    let RemoteQueryError::UnsupportedMessage(msg) = err else {
+        panic!("Expected unsupported message, found {err:?}");
+    };
+    assert!(msg.contains(UNSUPPORTED_MESSAGE));
+}
+
+#[test]
+fn unsupported_message_recognized_by_prefix() {
+    let msg = format!("{UNSUPPORTED_MESSAGE}: {{\"count\":{{}}}}");
+    assert!(matches!(
+        RemoteQueryError::from_message(msg.clone()),
+        RemoteQueryError::UnsupportedMessage(_)
+    ));
+    assert!(matches!(
+        RemoteQueryError::from_message(format!("Generic error: {msg}")),
+        RemoteQueryError::UnsupportedMessage(_)
+    ));
+
+    // Errors of the contract handlers are not mistaken for the unsupported messages.
+    for msg in [
+        "unknown variant `up`, expected `down`".to_owned(),
+        format!("Invalid input: {UNSUPPORTED_MESSAGE}"),
+    ] {
+        assert_eq!(
+            RemoteQueryError::from_message(msg.clone()),
+            RemoteQueryError::QueryFailed(msg)
+        );
+    }
+}
+
+#[test]
+fn failed_query() {
+    use sv::Querier;
+
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(mock_contract(Err("Query failed")));
+    let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+
+    let contract = Addr::unchecked("contract");
+    let err = BoundQuerier::<_, Contract>::borrowed(&contract, &querier)
+        .value()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        RemoteQueryError::QueryFailed("Query failed".to_owned())
+    );
+
+    let unknown = Addr::unchecked("unknown");
+    let err = BoundQuerier::<_, Contract>::borrowed(&unknown, &querier)
+        .value()
+        .unwrap_err();
+    assert!(matches!(err, RemoteQueryError::QueryFailed(_)));
+}
+
+#[test]
+fn invalid_response() {
+    use sv::Querier;
+
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(mock_contract(Ok("not a number")));
+    let querier = QuerierWrapper::<Empty>::new(&deps.querier);
+    let contract = Addr::unchecked("contract");
+
+    let err = BoundQuerier::<_, Contract>::borrowed(&contract, &querier)
+        .value()
+        .unwrap_err();
+    assert!(matches!(err, RemoteQueryError::InvalidResponse(_)));
+
+    // The error can be propagated as `StdError`.
+    let err: StdError = err.into();
+    assert!(matches!(err, StdError::ParseErr { .. }));
+}
@@ -180,6 +180,23 @@ where
         Ok(Response::new().add_submessage(msg))
     }
 
+    #[sv::msg(exec)]
+    pub fn call_remote_with_reply(
+        &self,
+        ctx: ExecCtx<Q>,
+        should_fail: bool,
+    ) -> Result<Response<M>, ContractError> {
+        // Payload type is checked against the handler parameters.
+        let msg = self
+            .remote
+            .load(ctx.deps.storage)?
+            .executor()
+            .noop(should_fail)?
+            .with_reply::<sv::replies::Always<M, Q>>((42_u32, "Hello, world!".to_string()))?;
+
+        Ok(Response::new().add_submessage(msg))
+    }
+
     #[sv::msg(exec)]
     pub fn call_remote_unknown_id(
         &self,
@@ -346,6 +363,16 @@ mod tests {
         let last_reply = contract.last_reply().unwrap();
         assert_eq!(last_reply, ERROR_REPLY_ID);
 
+        // Should dispatch the sub message created with the reply handler
+        contract.call_remote_with_reply(false).call(&owner).unwrap();
+        let last_reply = contract.last_reply().unwrap();
+        assert_eq!(last_reply, ALWAYS_REPLY_ID);
+
+        contract.call_remote_both(false).call(&owner).unwrap();
+        contract.call_remote_with_reply(true).call(&owner).unwrap();
+        let last_reply = contract.last_reply().unwrap();
+        assert_eq!(last_reply, ALWAYS_REPLY_ID);
+
         // Should send the cosmos message
         contract.send_cosmos_messages().call(&owner).unwrap();
         let last_reply = contract.last_reply().unwrap();
@@ -62,6 +62,17 @@ impl Contract {
         Ok(Response::new())
     }
 
+    #[allow(dead_code)]
+    #[sv::msg(reply, id = 42)]
+    fn explicit_id(
+        &self,
+        _ctx: ReplyCtx,
+        _result: SubMsgResult,
+        #[sv::payload(raw)] _payload: Binary,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
     #[allow(dead_code)]
     #[sv::msg(reply, handlers=[two_handlers], reply_on = error)]
     fn both_parameters(
@@ -83,16 +94,91 @@ fn reply_id_generation() {
         sv::HANDLER_TWO_REPLY_ID,
         sv::TWO_HANDLERS_REPLY_ID,
         sv::REPLY_ON_ALWAYS_REPLY_ID,
+        sv::EXPLICIT_ID_REPLY_ID,
     ]
     .iter()
     .unique()
     .count();
 
-    assert_eq!(unique_ids_count, 5);
+    assert_eq!(unique_ids_count, 6);
 
     assert_eq!(sv::CLEAN_REPLY_ID, 0);
     assert_eq!(sv::HANDLER_ONE_REPLY_ID, 1);
     assert_eq!(sv::HANDLER_TWO_REPLY_ID, 2);
     assert_eq!(sv::TWO_HANDLERS_REPLY_ID, 3);
     assert_eq!(sv::REPLY_ON_ALWAYS_REPLY_ID, 4);
+    assert_eq!(sv::EXPLICIT_ID_REPLY_ID, 42);
+    assert_eq!(
+        sv::REPLY_IDS,
+        &[
+            ("clean", sv::CLEAN_REPLY_ID),
+            ("handler_one", sv::HANDLER_ONE_REPLY_ID),
+            ("handler_two", sv::HANDLER_TWO_REPLY_ID),
+            ("two_handlers", sv::TWO_HANDLERS_REPLY_ID),
+            ("reply_on_always", sv::REPLY_ON_ALWAYS_REPLY_ID),
+            ("explicit_id", sv::EXPLICIT_ID_REPLY_ID),
+        ]
+    );
+}
+
+pub mod hashed {
+    use cosmwasm_std::{Binary, SubMsgResult};
+    use sylvia::contract;
+    use sylvia::ctx::{InstantiateCtx, ReplyCtx};
+    use sylvia::cw_std::{Response, StdResult};
+
+    pub struct Contract;
+
+    #[contract]
+    #[sv::features(hashed_reply_ids)]
+    impl Contract {
+        pub fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(reply)]
+        fn clean(
+            &self,
+            _ctx: ReplyCtx,
+            _result: SubMsgResult,
+            #[sv::payload(raw)] _payload: Binary,
+        ) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[allow(dead_code)]
+        #[sv::msg(reply, handlers=[handler_one])]
+        fn custom_handlers(
+            &self,
+            _ctx: ReplyCtx,
+            _result: SubMsgResult,
+            #[sv::payload(raw)] _payload: Binary,
+        ) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[allow(dead_code)]
+        #[sv::msg(reply, id = 42)]
+        fn explicit_id(
+            &self,
+            _ctx: ReplyCtx,
+            _result: SubMsgResult,
+            #[sv::payload(raw)] _payload: Binary,
+        ) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+}
+
+#[test]
+fn hashed_reply_ids() {
+    // Ids derived from the handler names don't depend on the handlers order.
+    assert_eq!(hashed::sv::CLEAN_REPLY_ID, 0x506d_ff3e);
+    assert_eq!(hashed::sv::HANDLER_ONE_REPLY_ID, 0x877e_50b8);
+    assert_eq!(hashed::sv::EXPLICIT_ID_REPLY_ID, 42);
 }
@@ -0,0 +1,257 @@
+use sylvia::ctx::{ExecCtx, InstantiateCtx, MigrateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::{contract, entry_points};
+
+pub mod counter {
+    use sylvia::ctx::{ExecCtx, QueryCtx};
+    use sylvia::cw_schema::cw_serde;
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[cw_serde]
+    pub struct CountResponse {
+        pub count: u64,
+    }
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Counter {
+        type Error: From<StdError>;
+
+        /// Increments the counter.
+        #[sv::msg(exec)]
+        fn increment(&self, ctx: ExecCtx, by: u32) -> Result<Response, Self::Error>;
+
+        #[sv::msg(query)]
+        fn count(&self, ctx: QueryCtx) -> Result<CountResponse, Self::Error>;
+    }
+}
+
+pub struct Contract;
+
+#[entry_points]
+#[contract]
+#[sv::messages(counter)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx, _admin: String) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn reset(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn admin(&self, _ctx: QueryCtx) -> StdResult<String> {
+        Ok(String::new())
+    }
+
+    #[sv::msg(migrate)]
+    fn migrate(&self, _ctx: MigrateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+impl counter::Counter for Contract {
+    type Error = sylvia::cw_std::StdError;
+
+    fn increment(&self, _ctx: ExecCtx, _by: u32) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn count(&self, _ctx: QueryCtx) -> StdResult<counter::CountResponse> {
+        Ok(counter::CountResponse { count: 0 })
+    }
+}
+
+pub mod no_migrate {
+    use sylvia::contract;
+    use sylvia::ctx::InstantiateCtx;
+    use sylvia::cw_std::{Response, StdResult};
+
+    pub struct Contract;
+
+    #[contract]
+    impl Contract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+    }
+}
+
+pub mod conflicting {
+    use sylvia::contract;
+    use sylvia::ctx::{InstantiateCtx, QueryCtx};
+    use sylvia::cw_schema::cw_serde;
+    use sylvia::cw_std::{Response, StdResult};
+
+    #[cw_serde]
+    pub struct CountResponse {
+        pub count: String,
+    }
+
+    pub struct Contract;
+
+    #[contract]
+    impl Contract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(query)]
+        fn count(&self, _ctx: QueryCtx) -> StdResult<crate::counter::CountResponse> {
+            Ok(crate::counter::CountResponse { count: 0 })
+        }
+
+        #[sv::msg(query)]
+        fn legacy_count(&self, _ctx: QueryCtx) -> StdResult<CountResponse> {
+            Ok(CountResponse {
+                count: "0".to_owned(),
+            })
+        }
+    }
+}
+
+#[test]
+fn contract_api() {
+    let schema = entry_points::schema();
+    let api = schema.api;
+
+    assert_eq!(api.contract_name, env!("CARGO_PKG_NAME"));
+    assert_eq!(api.contract_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(
+        api.instantiate,
+        Some(sylvia::schemars::schema_for!(sv::InstantiateMsg))
+    );
+    assert_eq!(
+        api.execute,
+        Some(sylvia::schemars::schema_for!(sv::ContractExecMsg))
+    );
+    assert_eq!(
+        api.query,
+        Some(sylvia::schemars::schema_for!(sv::ContractQueryMsg))
+    );
+    assert_eq!(
+        api.migrate,
+        Some(sylvia::schemars::schema_for!(sv::MigrateMsg))
+    );
+    assert!(api.sudo.is_some());
+
+    // Responses cover both the contract and the interface queries.
+    let responses = api.responses.unwrap();
+    assert_eq!(
+        responses.keys().collect::<Vec<_>>(),
+        ["admin", "count"].iter().collect::<Vec<_>>()
+    );
+
+    assert!(no_migrate::sv::generate_schema().api.migrate.is_none());
+}
+
+#[test]
+fn interface_sub_schemas() {
+    let schema = sv::generate_schema();
+
+    assert_eq!(
+        schema.interfaces.keys().collect::<Vec<_>>(),
+        [&"Counter".to_owned()]
+    );
+
+    let api = &schema.interfaces["Counter"];
+    assert_eq!(api.contract_name, "Counter");
+    assert!(api.instantiate.is_none());
+    assert_eq!(
+        api.execute,
+        Some(sylvia::schemars::schema_for!(counter::sv::ExecMsg))
+    );
+    assert_eq!(
+        api.query,
+        Some(sylvia::schemars::schema_for!(counter::sv::QueryMsg))
+    );
+    assert_eq!(
+        api.responses.as_ref().unwrap().keys().collect::<Vec<_>>(),
+        [&"count".to_owned()]
+    );
+}
+
+#[test]
+fn write_schema() {
+    let out_dir = std::env::temp_dir().join(format!("sylvia-schema-{}", std::process::id()));
+
+    let written = sv::generate_schema().write(&out_dir).unwrap();
+
+    let contract_file = out_dir.join(format!("{}.json", env!("CARGO_PKG_NAME")));
+    for path in [
+        contract_file.clone(),
+        out_dir.join("raw").join("instantiate.json"),
+        out_dir.join("raw").join("execute.json"),
+        out_dir.join("raw").join("query.json"),
+        out_dir.join("raw").join("migrate.json"),
+        out_dir.join("raw").join("response_to_count.json"),
+        out_dir.join("interfaces").join("Counter.json"),
+    ] {
+        assert!(written.contains(&path), "{} not written", path.display());
+        assert!(path.is_file(), "{} is missing", path.display());
+    }
+
+    let idl = std::fs::read_to_string(contract_file).unwrap();
+    assert!(idl.contains("\"idl_version\""));
+    assert!(idl.contains("\"increment\""));
+
+    std::fs::remove_dir_all(out_dir).unwrap();
+}
+
+#[test]
+fn typescript_client() {
+    let ts = sylvia::codegen::typescript(&sv::generate_schema()).unwrap();
+
+    assert!(ts.contains("export type CountResponse = { count: number };"));
+    assert!(ts.contains("export type CounterExecMsg = { increment: { by: number } };"));
+    assert!(ts.contains("export type SylviaInstantiateMsg = { _admin: string };"));
+
+    // Contract clients cover both the contract and the interface messages.
+    assert!(ts.contains("export class SylviaQueryClient {"));
+    assert!(ts.contains("export class SylviaClient extends SylviaQueryClient {"));
+    assert!(ts.contains("  async admin(): Promise<string> {"));
+    assert!(ts.contains(
+        "    return this.client.queryContractSmart(this.contractAddress, { \"admin\": {} });"
+    ));
+    assert!(ts.contains(
+        "    return this.client.execute(senderAddress, this.contractAddress, { \"reset\": {} }, fee, memo, funds);"
+    ));
+
+    // Every interface gets its own clients.
+    let counter = &ts[ts.find("export class CounterQueryClient {").unwrap()..];
+    assert!(counter.contains("  async count(): Promise<CountResponse> {"));
+    assert!(counter.contains("export class CounterClient extends CounterQueryClient {"));
+    assert!(counter.contains("    args: { by: number },"));
+    assert!(counter.contains(
+        "    return this.client.execute(senderAddress, this.contractAddress, { \"increment\": args }, fee, memo, funds);"
+    ));
+    assert!(!counter.contains("reset"));
+}
+
+#[test]
+fn typescript_client_conflicting_definitions() {
+    let err = sylvia::codegen::typescript(&conflicting::sv::generate_schema()).unwrap_err();
+    assert_eq!(err.name, "CountResponse");
+    assert_eq!(
+        err.to_string(),
+        "Conflicting definitions of the `CountResponse` type in the contract schema"
+    );
+}
@@ -0,0 +1,26 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx};
+use sylvia::cw_std::{Response, StdResult};
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    #[sv::access(admin)]
+    pub fn exec(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
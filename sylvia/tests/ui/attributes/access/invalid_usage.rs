@@ -0,0 +1,41 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+
+pub mod interface {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    pub trait Interface {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        #[sv::access(owner)]
+        fn exec(&self, ctx: ExecCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    #[sv::access(owner)]
+    pub fn query(&self, _ctx: QueryCtx) -> StdResult<String> {
+        Ok(String::new())
+    }
+}
+
+fn main() {}
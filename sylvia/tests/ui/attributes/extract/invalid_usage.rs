@@ -0,0 +1,43 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdError, StdResult};
+
+pub mod interface {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+
+    #[sylvia::interface]
+    pub trait Interface {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn update(&self, ctx: ExecCtx, #[sv::extract] value: u64) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn update(&self, _ctx: ExecCtx, #[sv::extract(config)] _value: u64) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn value(&self, _ctx: QueryCtx, #[sv::extract] _value: u64) -> StdResult<u64> {
+        Ok(0)
+    }
+}
+
+fn main() {}
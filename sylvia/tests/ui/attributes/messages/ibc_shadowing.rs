@@ -0,0 +1,135 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{IbcChannelOpenCtx, IbcPacketReceiveCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{
+    IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketReceiveMsg, IbcReceiveResponse, Response,
+    StdError, StdResult,
+};
+
+pub mod catch_all {
+    use sylvia::ctx::{IbcChannelOpenCtx, IbcPacketReceiveCtx};
+    use sylvia::cw_std::{
+        IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketReceiveMsg, IbcReceiveResponse,
+        StdError,
+    };
+
+    #[sylvia::interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait CatchAll {
+        type Error: From<StdError>;
+
+        #[sv::msg(ibc_channel_open)]
+        fn channel_open(
+            &self,
+            ctx: IbcChannelOpenCtx,
+            msg: IbcChannelOpenMsg,
+        ) -> Result<IbcChannelOpenResponse, Self::Error>;
+
+        #[sv::msg(ibc_packet_receive)]
+        fn receive(
+            &self,
+            ctx: IbcPacketReceiveCtx,
+            msg: IbcPacketReceiveMsg,
+        ) -> Result<IbcReceiveResponse, Self::Error>;
+    }
+}
+
+pub mod typed {
+    use sylvia::ctx::IbcPacketReceiveCtx;
+    use sylvia::cw_std::{IbcPacketReceiveMsg, IbcReceiveResponse, StdError};
+
+    #[sylvia::interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Typed {
+        type Error: From<StdError>;
+
+        #[sv::msg(ibc_packet_receive, packet = String)]
+        fn typed_receive(
+            &self,
+            ctx: IbcPacketReceiveCtx,
+            msg: IbcPacketReceiveMsg,
+        ) -> Result<IbcReceiveResponse, Self::Error>;
+    }
+}
+
+pub mod no_ibc {
+    use sylvia::ctx::QueryCtx;
+    use sylvia::cw_std::{StdError, StdResult};
+
+    #[sylvia::interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait NoIbc {
+        type Error: From<StdError>;
+
+        #[sv::msg(query)]
+        fn value(&self, ctx: QueryCtx) -> StdResult<u64>;
+    }
+}
+
+pub struct Contract;
+
+#[contract]
+#[sv::messages(catch_all: ibc)]
+#[sv::messages(typed: ibc)]
+#[sv::messages(no_ibc: ibc)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(ibc_channel_open)]
+    pub fn channel_open(
+        &self,
+        _ctx: IbcChannelOpenCtx,
+        _msg: IbcChannelOpenMsg,
+    ) -> StdResult<IbcChannelOpenResponse> {
+        Ok(None)
+    }
+}
+
+impl catch_all::CatchAll for Contract {
+    type Error = StdError;
+
+    fn channel_open(
+        &self,
+        _ctx: IbcChannelOpenCtx,
+        _msg: IbcChannelOpenMsg,
+    ) -> StdResult<IbcChannelOpenResponse> {
+        Ok(None)
+    }
+
+    fn receive(
+        &self,
+        _ctx: IbcPacketReceiveCtx,
+        _msg: IbcPacketReceiveMsg,
+    ) -> StdResult<IbcReceiveResponse> {
+        Ok(IbcReceiveResponse::without_ack())
+    }
+}
+
+impl typed::Typed for Contract {
+    type Error = StdError;
+
+    fn typed_receive(
+        &self,
+        _ctx: IbcPacketReceiveCtx,
+        _msg: IbcPacketReceiveMsg,
+    ) -> StdResult<IbcReceiveResponse> {
+        Ok(IbcReceiveResponse::without_ack())
+    }
+}
+
+impl no_ibc::NoIbc for Contract {
+    type Error = StdError;
+
+    fn value(&self, _ctx: QueryCtx) -> StdResult<u64> {
+        Ok(0)
+    }
+}
+
+fn main() {}
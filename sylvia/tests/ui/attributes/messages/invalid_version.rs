@@ -0,0 +1,34 @@
+#![allow(unused_imports)]
+use sylvia::ctx::InstantiateCtx;
+use sylvia::cw_std::{Response, StdResult};
+
+mod interface {
+    use sylvia::cw_std::StdError;
+
+    #[sylvia::interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Interface {
+        type Error: From<StdError>;
+    }
+
+    impl Interface for crate::Contract {
+        type Error = StdError;
+    }
+}
+
+pub struct Contract;
+
+#[sylvia::contract]
+#[sv::messages(interface: version = ">=1.2, <2.x.0")]
+impl Contract {
+    pub const fn new() -> Self {
+        Contract
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,66 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{InstantiateCtx, ReplyCtx};
+use sylvia::cw_std::{Response, StdError, StdResult};
+
+pub mod first {
+    use sylvia::ctx::ReplyCtx;
+    use sylvia::cw_std::{Response, StdError};
+
+    #[sylvia::interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Forwarder {
+        type Error: From<StdError>;
+
+        #[sv::msg(reply, reply_on=success)]
+        fn first_reply(&self, ctx: ReplyCtx, note: String) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod second {
+    use sylvia::ctx::ReplyCtx;
+    use sylvia::cw_std::{Response, StdError};
+
+    #[sylvia::interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Forwarder {
+        type Error: From<StdError>;
+
+        #[sv::msg(reply, reply_on=success)]
+        fn second_reply(&self, ctx: ReplyCtx, note: String) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct Contract;
+
+#[contract]
+#[sv::messages(first as First: reply)]
+#[sv::messages(second as Second: reply)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+impl first::Forwarder for Contract {
+    type Error = StdError;
+
+    fn first_reply(&self, _ctx: ReplyCtx, _note: String) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+impl second::Forwarder for Contract {
+    type Error = StdError;
+
+    fn second_reply(&self, _ctx: ReplyCtx, _note: String) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx};
+use sylvia::cw_std::{Response, StdError, StdResult};
+
+pub mod interface {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+
+    pub struct Hook;
+
+    #[sylvia::interface]
+    #[sv::middleware(Hook)]
+    pub trait Interface {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn update(&self, ctx: ExecCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct Contract;
+
+#[contract]
+#[sv::middleware(1 + 2)]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    pub fn update(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,50 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{Cw20ReceiveCtx, ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+
+pub mod interface {
+    use sylvia::ctx::Cw20ReceiveCtx;
+    use sylvia::cw_std::{Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Interface {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec, cw20_receive)]
+        fn deposit(&self, ctx: Cw20ReceiveCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query, cw20_receive)]
+    fn balance(&self, _ctx: QueryCtx) -> StdResult<u64> {
+        Ok(0)
+    }
+
+    #[sv::msg(exec, cw20_receive)]
+    fn deposit(&self, _ctx: Cw20ReceiveCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn receive(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::pagination::Page;
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec, paginated)]
+    fn clear(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query, paginated)]
+    fn members(&self, _ctx: QueryCtx) -> StdResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    #[sv::msg(query, paginated)]
+    fn admins(&self, _ctx: QueryCtx, limit: u32) -> StdResult<Page<String>> {
+        Ok(Page {
+            items: vec![],
+            next_key: None,
+        })
+    }
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{InstantiateCtx, MigrateCtx};
+use sylvia::cw_std::{Response, StdResult};
+
+pub struct Contract;
+
+#[contract]
+#[sv::features(cw2)]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(migrate, from = ">=1.0, <2.0")]
+    fn migrate_legacy(&self, _ctx: MigrateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(migrate)]
+    fn migrate_current(&self, _ctx: MigrateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
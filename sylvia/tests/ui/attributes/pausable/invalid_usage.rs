@@ -0,0 +1,46 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdError, StdResult};
+
+pub mod interface {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+
+    #[sylvia::interface]
+    pub trait Interface {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        #[sv::pausable]
+        fn update(&self, ctx: ExecCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    #[sv::pausable]
+    pub fn value(&self, _ctx: QueryCtx) -> StdResult<u64> {
+        Ok(0)
+    }
+
+    #[sv::msg(exec)]
+    #[sv::pausable(always)]
+    pub fn update(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use sylvia::contract;
+use sylvia::ctx::{InstantiateCtx, ReplyCtx};
+use sylvia::cw_std::{Response, StdResult, SubMsg, WasmMsg};
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        use sv::SubMsgMethods;
+
+        let msg = WasmMsg::ClearAdmin {
+            contract_addr: "contract".to_owned(),
+        };
+        let sub_msg: SubMsg = msg.called("note".to_owned(), 42_u32)?;
+        Ok(Response::new().add_submessage(sub_msg))
+    }
+
+    #[sv::msg(reply, reply_on=success)]
+    fn called(&self, _ctx: ReplyCtx, _first: u32, _second: String) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
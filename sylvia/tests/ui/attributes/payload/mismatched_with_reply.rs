@@ -0,0 +1,41 @@
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, ReplyCtx};
+use sylvia::cw_std::{Addr, Response, StdResult};
+use sylvia::types::Remote;
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    pub fn call(&self, _ctx: ExecCtx, remote: Addr) -> StdResult<Response> {
+        use sv::Executor;
+
+        let msg = Remote::<Contract>::new(remote)
+            .executor()
+            .noop()?
+            .with_reply::<sv::replies::Called>("note".to_owned())?;
+        Ok(Response::new().add_submessage(msg))
+    }
+
+    #[sv::msg(exec)]
+    pub fn noop(&self, _ctx: ExecCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(reply, reply_on=success)]
+    fn called(&self, _ctx: ReplyCtx, _first: u32, _second: String) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+#![allow(unused_imports)]
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx, ReplyCtx};
+use sylvia::cw_std::{Addr, Binary, Response, StdResult};
+
+pub struct Contract;
+
+#[contract]
+impl Contract {
+    pub fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(
+        &self,
+        _ctx: InstantiateCtx,
+        #[sv::validate(email)] _admin: String,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn update(
+        &self,
+        _ctx: ExecCtx,
+        #[sv::validate(addr)] _admin: String,
+        #[sv::validate(range = 100)] _limit: u32,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn is_admin(&self, _ctx: QueryCtx, #[sv::validate] _admin: Addr) -> StdResult<bool> {
+        Ok(false)
+    }
+
+    #[sv::msg(reply, reply_on=success)]
+    fn reply(
+        &self,
+        _ctx: ReplyCtx,
+        _data: Option<Binary>,
+        #[sv::validate(range = 1..10)] _payload: u32,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+}
+
+fn main() {}
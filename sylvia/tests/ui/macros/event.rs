@@ -0,0 +1,41 @@
+#![allow(unused_imports)]
+use sylvia::cw_schema::cw_serde;
+use sylvia::event;
+
+#[cw_serde]
+#[event(name = "")]
+pub struct EmptyName {
+    pub value: u64,
+}
+
+#[cw_serde]
+#[event(kind = "value")]
+pub struct UnknownArgument {
+    pub value: u64,
+}
+
+#[cw_serde]
+#[event]
+pub struct Tuple(u64);
+
+#[cw_serde]
+#[event]
+pub struct Generic<T> {
+    pub value: T,
+}
+
+pub mod interface {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Response, StdError};
+
+    #[sylvia::interface]
+    #[sv::events(super::Tuple)]
+    pub trait Interface {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn update(&self, ctx: ExecCtx) -> Result<Response, Self::Error>;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+#![allow(unused_imports)]
+use sylvia::cw_std::StdError;
+
+pub mod invalid_version {
+    use sylvia::cw_std::StdError;
+
+    #[sylvia::interface(version = "1.2")]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Interface {
+        type Error: From<StdError>;
+    }
+}
+
+pub mod unknown_arg {
+    use sylvia::cw_std::StdError;
+
+    #[sylvia::interface(name = "Interface")]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Interface {
+        type Error: From<StdError>;
+    }
+}
+
+fn main() {}
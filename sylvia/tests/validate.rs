@@ -0,0 +1,215 @@
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::{from_json, Addr, DepsMut, StdError};
+use cw_storage_plus::Item;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+use sylvia::cw_std::{Response, StdResult};
+use sylvia::{contract, entry_points};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+    #[error("Invalid name")]
+    InvalidName,
+}
+
+fn validate_name(name: &str) -> Result<(), ContractError> {
+    match (3..=50).contains(&name.len()) {
+        true => Ok(()),
+        false => Err(ContractError::InvalidName),
+    }
+}
+
+pub mod admin {
+    use sylvia::ctx::ExecCtx;
+    use sylvia::cw_std::{Addr, Response, StdError};
+    use sylvia::interface;
+
+    #[interface]
+    #[sv::custom(msg=sylvia::cw_std::Empty, query=sylvia::cw_std::Empty)]
+    pub trait Admin {
+        type Error: From<StdError>;
+
+        #[sv::msg(exec)]
+        fn set_admin(
+            &self,
+            ctx: ExecCtx,
+            #[sv::validate(addr)] admin: Addr,
+        ) -> Result<Response, Self::Error>;
+    }
+}
+
+pub mod domain {
+    use sylvia::cw_schema::cw_serde;
+
+    /// Type sharing the name with the `cosmwasm_std::Addr`.
+    #[cw_serde]
+    pub struct Addr(pub u32);
+}
+
+pub struct Contract {
+    admin: Item<Addr>,
+    decimals: Item<u8>,
+}
+
+#[entry_points]
+#[contract]
+#[sv::error(ContractError)]
+#[sv::messages(admin)]
+impl Contract {
+    pub const fn new() -> Self {
+        Self {
+            admin: Item::new("admin"),
+            decimals: Item::new("decimals"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(
+        &self,
+        ctx: InstantiateCtx,
+        #[sv::validate(addr)] admin: Addr,
+        #[sv::validate(with = validate_name)] _name: String,
+        #[sv::validate(range = 1..=18)] decimals: u8,
+    ) -> Result<Response, ContractError> {
+        self.admin.save(ctx.deps.storage, &admin)?;
+        self.decimals.save(ctx.deps.storage, &decimals)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    fn set_decimals(
+        &self,
+        ctx: ExecCtx,
+        #[sv::validate(range = 1..=18)] decimals: u8,
+    ) -> Result<Response, ContractError> {
+        self.decimals.save(ctx.deps.storage, &decimals)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn is_admin(&self, ctx: QueryCtx, #[sv::validate(addr)] addr: Addr) -> StdResult<bool> {
+        Ok(self.admin.load(ctx.deps.storage)? == addr)
+    }
+
+    #[sv::msg(query)]
+    fn domain(&self, _ctx: QueryCtx, addr: domain::Addr) -> StdResult<u32> {
+        Ok(addr.0)
+    }
+}
+
+impl admin::Admin for Contract {
+    type Error = ContractError;
+
+    fn set_admin(&self, ctx: ExecCtx, admin: Addr) -> Result<Response, ContractError> {
+        self.admin.save(ctx.deps.storage, &admin)?;
+        Ok(Response::new())
+    }
+}
+
+fn instantiate(deps: DepsMut, msg: sv::InstantiateMsg) -> Result<Response, ContractError> {
+    let sender = Addr::unchecked("sender");
+    entry_points::instantiate(deps, mock_env(), message_info(&sender, &[]), msg)
+}
+
+#[test]
+fn validated_instantiate() {
+    let mut deps = mock_dependencies();
+    let admin = deps.api.addr_make("admin").to_string();
+
+    let err = instantiate(
+        deps.as_mut(),
+        sv::InstantiateMsg::new("invalid".to_owned(), "Token".to_owned(), 6),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Std(StdError::GenericErr { .. })
+    ));
+
+    let err = instantiate(
+        deps.as_mut(),
+        sv::InstantiateMsg::new(admin.clone(), "T".to_owned(), 6),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidName);
+
+    let err = instantiate(
+        deps.as_mut(),
+        sv::InstantiateMsg::new(admin.clone(), "Token".to_owned(), 19),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(
+            "Invalid `decimals`: value out of the `1..=18` range."
+        ))
+    );
+
+    instantiate(
+        deps.as_mut(),
+        sv::InstantiateMsg::new(admin, "Token".to_owned(), 6),
+    )
+    .unwrap();
+}
+
+#[test]
+fn validated_messages() {
+    let mut deps = mock_dependencies();
+    let admin = deps.api.addr_make("admin");
+    let new_admin = deps.api.addr_make("new_admin");
+    instantiate(
+        deps.as_mut(),
+        sv::InstantiateMsg::new(admin.to_string(), "Token".to_owned(), 6),
+    )
+    .unwrap();
+
+    let mut execute =
+        |msg| entry_points::execute(deps.as_mut(), mock_env(), message_info(&admin, &[]), msg);
+
+    let err = execute(sv::ContractExecMsg::Contract(sv::ExecMsg::set_decimals(0))).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(
+            "Invalid `decimals`: value out of the `1..=18` range."
+        ))
+    );
+    execute(sv::ContractExecMsg::Contract(sv::ExecMsg::set_decimals(18))).unwrap();
+
+    let err = execute(sv::ContractExecMsg::Admin(admin::sv::ExecMsg::set_admin(
+        "invalid".to_owned(),
+    )))
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Std(StdError::GenericErr { .. })
+    ));
+    execute(sv::ContractExecMsg::Admin(admin::sv::ExecMsg::set_admin(
+        new_admin.to_string(),
+    )))
+    .unwrap();
+
+    let is_admin = |addr: String| {
+        entry_points::query(
+            deps.as_ref(),
+            mock_env(),
+            sv::ContractQueryMsg::Contract(sv::QueryMsg::is_admin(addr)),
+        )
+    };
+    assert!(from_json::<bool>(is_admin(new_admin.to_string()).unwrap()).unwrap());
+    assert!(is_admin("invalid".to_owned()).is_err());
+}
+
+#[test]
+fn other_addr_types_not_validated() {
+    let deps = mock_dependencies();
+
+    // `domain::Addr` is sent as is instead of the `String`.
+    let resp = entry_points::query(
+        deps.as_ref(),
+        mock_env(),
+        sv::ContractQueryMsg::Contract(sv::QueryMsg::domain(domain::Addr(5))),
+    )
+    .unwrap();
+    assert_eq!(from_json::<u32>(resp).unwrap(), 5);
+}
@@ -0,0 +1,242 @@
+#![cfg(feature = "cw2")]
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::Addr;
+use sylvia::ctx::{InstantiateCtx, MigrateCtx, QueryCtx};
+use sylvia::cw2::{get_contract_version, set_contract_version, ContractVersion};
+use sylvia::cw_std::{Response, StdError, StdResult};
+use sylvia::{contract, entry_points};
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub struct VersionedContract;
+
+#[entry_points]
+#[contract]
+#[sv::features(cw2)]
+impl VersionedContract {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    #[sv::msg(instantiate)]
+    fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    #[sv::msg(query)]
+    fn version(&self, ctx: QueryCtx) -> StdResult<String> {
+        Ok(get_contract_version(ctx.deps.storage)?.version)
+    }
+
+    #[sv::msg(migrate, from = ">=1.2, <3.0.0-0")]
+    fn migrate(&self, ctx: MigrateCtx, fail: bool) -> StdResult<Response> {
+        if fail {
+            return Err(StdError::generic_err("Migration failed"));
+        }
+
+        // The handler observes the version the contract is migrated from.
+        let stored = get_contract_version(ctx.deps.storage)?;
+        Ok(Response::new().add_attribute("from_version", stored.version))
+    }
+}
+
+#[test]
+fn instantiate_records_version() {
+    let mut deps = mock_dependencies();
+    let info = message_info(&Addr::unchecked("owner"), &[]);
+
+    entry_points::instantiate(deps.as_mut(), mock_env(), info, sv::InstantiateMsg {}).unwrap();
+
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap(),
+        ContractVersion {
+            contract: CONTRACT_NAME.to_owned(),
+            version: CONTRACT_VERSION.to_owned(),
+        }
+    );
+}
+
+#[test]
+fn migrate_updates_version() {
+    let mut deps = mock_dependencies();
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, "1.2.0").unwrap();
+
+    let resp =
+        entry_points::migrate(deps.as_mut(), mock_env(), sv::MigrateMsg { fail: false }).unwrap();
+    assert_eq!(resp.attributes[0].value, "1.2.0");
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap().version,
+        CONTRACT_VERSION
+    );
+}
+
+#[test]
+fn illegal_migrations() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let err = entry_points::migrate(deps.as_mut(), env.clone(), sv::MigrateMsg { fail: false })
+        .unwrap_err();
+    assert!(matches!(err, StdError::NotFound { .. }));
+
+    set_contract_version(&mut deps.storage, "other-contract", "1.2.0").unwrap();
+    let err = entry_points::migrate(deps.as_mut(), env.clone(), sv::MigrateMsg { fail: false })
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(format!(
+            "Cannot migrate from the `other-contract` contract to the `{CONTRACT_NAME}` contract."
+        ))
+    );
+
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, "99.0.0").unwrap();
+    let err = entry_points::migrate(deps.as_mut(), env.clone(), sv::MigrateMsg { fail: false })
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(format!(
+            "Cannot migrate from version 99.0.0 to the older version {CONTRACT_VERSION}."
+        ))
+    );
+
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, "1.1.0").unwrap();
+    let err = entry_points::migrate(deps.as_mut(), env.clone(), sv::MigrateMsg { fail: false })
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(
+            "Migration from version 1.1.0 is not allowed. Expected version matching `>=1.2, <3.0.0-0`."
+        )
+    );
+
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, "1.2.0").unwrap();
+    let err = entry_points::migrate(deps.as_mut(), env, sv::MigrateMsg { fail: true }).unwrap_err();
+    assert_eq!(err, StdError::generic_err("Migration failed"));
+}
+
+pub mod multi_path {
+    use sylvia::contract;
+    use sylvia::ctx::{InstantiateCtx, MigrateCtx};
+    use sylvia::cw_std::{Response, StdResult};
+
+    pub struct MultiPathContract;
+
+    #[contract]
+    #[sv::features(cw2)]
+    impl MultiPathContract {
+        pub const fn new() -> Self {
+            Self
+        }
+
+        #[sv::msg(instantiate)]
+        fn instantiate(&self, _ctx: InstantiateCtx) -> StdResult<Response> {
+            Ok(Response::new())
+        }
+
+        #[sv::msg(migrate, from = ">=1.0, <2.0.0-0")]
+        fn migrate_legacy(&self, _ctx: MigrateCtx, admin: String) -> StdResult<Response> {
+            Ok(Response::new()
+                .add_attribute("path", "legacy")
+                .add_attribute("admin", admin))
+        }
+
+        #[sv::msg(migrate, from = ">=2.0.0-0, <3.0.0-0")]
+        fn migrate_current(&self, _ctx: MigrateCtx) -> StdResult<Response> {
+            Ok(Response::new().add_attribute("path", "current"))
+        }
+    }
+}
+
+#[test]
+fn migration_paths() {
+    use multi_path::sv::MigrateMsg;
+    use multi_path::MultiPathContract;
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let contract = MultiPathContract::new();
+
+    set_contract_version(&mut deps.storage, CONTRACT_NAME, "1.4.0").unwrap();
+    let err = MigrateMsg::migrate_current()
+        .dispatch(&contract, (deps.as_mut(), env.clone()))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(
+            "Migration from version 1.4.0 is not allowed. Expected version matching `>=2.0.0-0, <3.0.0-0`."
+        )
+    );
+
+    let resp = MigrateMsg::migrate_legacy("admin".to_owned())
+        .dispatch(&contract, (deps.as_mut(), env.clone()))
+        .unwrap();
+    assert_eq!(resp.attributes[0].value, "legacy");
+    assert_eq!(resp.attributes[1].value, "admin");
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap().version,
+        CONTRACT_VERSION
+    );
+
+    let err = MigrateMsg::migrate_legacy("admin".to_owned())
+        .dispatch(&contract, (deps.as_mut(), env.clone()))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(format!(
+            "Migration from version {CONTRACT_VERSION} is not allowed. Expected version matching `>=1.0, <2.0.0-0`."
+        ))
+    );
+
+    let resp = MigrateMsg::migrate_current()
+        .dispatch(&contract, (deps.as_mut(), env))
+        .unwrap();
+    assert_eq!(resp.attributes[0].value, "current");
+}
+
+#[cfg(feature = "mt")]
+mod mt {
+    use super::sv::mt::{CodeId, VersionedContractProxy};
+    use super::CONTRACT_VERSION;
+    use sylvia::cw_multi_test::IntoAddr;
+    use sylvia::multitest::App;
+
+    #[test]
+    fn version_query() {
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_addr();
+
+        let contract = code_id.instantiate().call(&owner).unwrap();
+        assert_eq!(contract.version().unwrap(), CONTRACT_VERSION);
+    }
+
+    #[test]
+    fn migration_path() {
+        use crate::multi_path::sv::mt::{CodeId, MultiPathContractProxy};
+
+        let app = App::default();
+        let code_id = CodeId::store_code(&app);
+        let owner = "owner".into_addr();
+
+        let contract = code_id
+            .instantiate()
+            .with_admin(owner.as_str())
+            .call(&owner)
+            .unwrap();
+
+        contract
+            .migrate_legacy("admin".to_owned())
+            .call(&owner, code_id.code_id())
+            .unwrap_err();
+
+        let resp = contract
+            .migrate_current()
+            .call(&owner, code_id.code_id())
+            .unwrap();
+        assert!(resp.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "path" && attr.value == "current")));
+    }
+}